@@ -201,6 +201,8 @@ use core::pin::{Pin, PinCoerceUnsized};
 use core::ptr::{self, NonNull, Unique};
 use core::task::{Context, Poll};
 
+use safety::ensures;
+
 #[cfg(not(no_global_oom_handling))]
 use crate::alloc::handle_alloc_error;
 use crate::alloc::{AllocError, Allocator, Global, Layout};
@@ -1041,6 +1043,7 @@ impl<T: ?Sized> Box<T> {
     #[stable(feature = "box_raw", since = "1.4.0")]
     #[inline]
     #[must_use = "call `drop(Box::from_raw(ptr))` if you intend to drop the `Box`"]
+    #[ensures(|b| ptr::eq(&**b, raw))]
     pub unsafe fn from_raw(raw: *mut T) -> Self {
         unsafe { Self::from_raw_in(raw, Global) }
     }
@@ -1149,6 +1152,7 @@ impl<T: ?Sized> Box<T> {
     #[must_use = "losing the pointer will leak memory"]
     #[stable(feature = "box_raw", since = "1.4.0")]
     #[inline]
+    #[ensures(|&raw| ptr::eq(raw, old(&raw const *b)))]
     pub fn into_raw(b: Self) -> *mut T {
         // Avoid `into_raw_with_allocator` as that interacts poorly with Miri's Stacked Borrows.
         let mut b = mem::ManuallyDrop::new(b);
@@ -2132,3 +2136,75 @@ impl<E: Error> Error for Box<E> {
         Error::provide(&**self, request);
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use core::kani;
+
+    use super::*;
+    use crate::vec::Vec;
+
+    // `Box<[T]>` raw pointer proofs
+
+    const ARRAY_LEN: usize = 3;
+
+    fn any_boxed_slice() -> Box<[u8]> {
+        let arr: [u8; ARRAY_LEN] = kani::any();
+        let len = kani::any_where(|&x: &usize| x <= ARRAY_LEN);
+        Box::from(&arr[..len])
+    }
+
+    #[kani::proof_for_contract(Box::<[u8]>::into_raw)]
+    fn check_into_raw() {
+        let b = any_boxed_slice();
+        let len = b.len();
+        let raw = Box::into_raw(b);
+        assert!(!raw.is_null() && raw.len() == len);
+    }
+
+    // The pointer comes from `Box::leak`, so `from_raw` is checked on its own.
+    #[kani::proof_for_contract(Box::<[u8]>::from_raw)]
+    #[kani::unwind(4)]
+    fn check_from_raw() {
+        let b = any_boxed_slice();
+        let copy = b.clone();
+        let raw: *mut [u8] = Box::leak(b);
+        let b = unsafe { Box::from_raw(raw) };
+        assert!(b == copy);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(4)]
+    fn check_raw_round_trip() {
+        let b = any_boxed_slice();
+        let copy = b.clone();
+        let b = unsafe { Box::from_raw(Box::into_raw(b)) };
+        assert!(b == copy);
+    }
+
+    static mut DROPS: usize = 0;
+
+    struct Counted;
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            unsafe { DROPS += 1 };
+        }
+    }
+
+    // Only the rebuilt box drops the elements, each exactly once.
+    #[kani::proof]
+    #[kani::unwind(4)]
+    fn check_raw_round_trip_drop() {
+        let len = kani::any_where(|&x: &usize| x <= ARRAY_LEN);
+        let mut vect = Vec::with_capacity(len);
+        for _ in 0..len {
+            vect.push(Counted);
+        }
+        let raw = Box::into_raw(vect.into_boxed_slice());
+        assert!(unsafe { DROPS } == 0);
+        drop(unsafe { Box::from_raw(raw) });
+        assert!(unsafe { DROPS } == len);
+    }
+}
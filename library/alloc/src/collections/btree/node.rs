@@ -31,10 +31,16 @@
 //   since leaf edges are empty and need no data representation. In an internal node,
 //   an edge both identifies a position and contains a pointer to a child node.
 
+#[cfg(kani)]
+use core::kani;
 use core::marker::PhantomData;
 use core::mem::{self, MaybeUninit};
 use core::ptr::{self, NonNull};
 use core::slice::SliceIndex;
+#[cfg(kani)]
+use core::ub_checks::{Invariant, can_dereference, can_write};
+
+use safety::{ensures, requires};
 
 use crate::alloc::{Allocator, Layout};
 use crate::boxed::Box;
@@ -67,6 +73,9 @@ struct LeafNode<K, V> {
 
 impl<K, V> LeafNode<K, V> {
     /// Initializes a new `LeafNode` in-place.
+    #[requires(can_write(this))]
+    #[ensures(|_| unsafe { (*this).is_safe() && (*this).len == 0 && (*this).parent.is_none() })]
+    #[cfg_attr(kani, kani::modifies(this))]
     unsafe fn init(this: *mut Self) {
         // As a general policy, we leave fields uninitialized if they can be, as this should
         // be both slightly faster and easier to track in Valgrind.
@@ -87,6 +96,15 @@ impl<K, V> LeafNode<K, V> {
     }
 }
 
+/// Only the first `len` keys and values are initialized, so `len` must fit in the arrays.
+#[cfg(kani)]
+#[unstable(feature = "ub_checks", issue = "none")]
+impl<K, V> Invariant for LeafNode<K, V> {
+    fn is_safe(&self) -> bool {
+        usize::from(self.len) <= CAPACITY
+    }
+}
+
 /// The underlying representation of internal nodes. As with `LeafNode`s, these should be hidden
 /// behind `BoxedNode`s to prevent dropping uninitialized keys and values. Any pointer to an
 /// `InternalNode` can be directly cast to a pointer to the underlying `LeafNode` portion of the
@@ -120,6 +138,23 @@ impl<K, V> InternalNode<K, V> {
     }
 }
 
+/// An internal node with `len` keys has `len + 1` initialized edges, each pointing
+/// to a child whose parent link leads back to that edge.
+#[cfg(kani)]
+#[unstable(feature = "ub_checks", issue = "none")]
+impl<K, V> Invariant for InternalNode<K, V> {
+    fn is_safe(&self) -> bool {
+        let parent = NonNull::from(self);
+        self.data.is_safe()
+            && self.edges[..=usize::from(self.data.len)].iter().enumerate().all(|(idx, edge)| {
+                let child = unsafe { edge.assume_init_read() };
+                can_dereference(child.as_ptr())
+                    && unsafe { child.as_ref() }.parent == Some(parent)
+                    && unsafe { child.as_ref().parent_idx.assume_init() } == idx as u16
+            })
+    }
+}
+
 /// A managed, non-null pointer to a node. This is either an owned pointer to
 /// `LeafNode<K, V>` or an owned pointer to `InternalNode<K, V>`.
 ///
@@ -789,6 +824,8 @@ impl<Node, Type> Handle<Node, Type> {
 impl<BorrowType, K, V, NodeType> Handle<NodeRef<BorrowType, K, V, NodeType>, marker::KV> {
     /// Creates a new handle to a key-value pair in `node`.
     /// Unsafe because the caller must ensure that `idx < node.len()`.
+    #[requires(idx < node.len())]
+    #[ensures(|handle| handle.idx == idx)]
     pub(super) unsafe fn new_kv(node: NodeRef<BorrowType, K, V, NodeType>, idx: usize) -> Self {
         debug_assert!(idx < node.len());
 
@@ -865,6 +902,8 @@ impl<K, V, NodeType, HandleType> Handle<NodeRef<marker::DormantMut, K, V, NodeTy
 impl<BorrowType, K, V, NodeType> Handle<NodeRef<BorrowType, K, V, NodeType>, marker::Edge> {
     /// Creates a new handle to an edge in `node`.
     /// Unsafe because the caller must ensure that `idx <= node.len()`.
+    #[requires(idx <= node.len())]
+    #[ensures(|handle| handle.idx == idx)]
     pub(super) unsafe fn new_edge(node: NodeRef<BorrowType, K, V, NodeType>, idx: usize) -> Self {
         debug_assert!(idx <= node.len());
 
@@ -902,6 +941,12 @@ pub(super) enum LeftOrRight<T> {
 /// The goal of the split point is for its key and value to end up in a parent node;
 /// the keys, values and edges to the left of the split point become the left child;
 /// the keys, values and edges to the right of the split point become the right child.
+#[requires(edge_idx <= CAPACITY)]
+#[ensures(|(middle_kv_idx, insertion)| match *insertion {
+    LeftOrRight::Left(idx) => idx <= *middle_kv_idx && *middle_kv_idx >= MIN_LEN_AFTER_SPLIT - 1,
+    LeftOrRight::Right(idx) => idx < CAPACITY - *middle_kv_idx && *middle_kv_idx >= MIN_LEN_AFTER_SPLIT,
+})]
+#[ensures(|(middle_kv_idx, _)| CAPACITY - *middle_kv_idx - 1 >= MIN_LEN_AFTER_SPLIT - 1)]
 fn splitpoint(edge_idx: usize) -> (usize, LeftOrRight<usize>) {
     debug_assert!(edge_idx <= CAPACITY);
     // Rust issue #74834 tries to explain these symmetric rules.
@@ -917,6 +962,9 @@ impl<'a, K: 'a, V: 'a> Handle<NodeRef<marker::Mut<'a>, K, V, marker::Leaf>, mark
     /// Inserts a new key-value pair between the key-value pairs to the right and left of
     /// this edge. This method assumes that there is enough space in the node for the new
     /// pair to fit.
+    #[requires(self.node.len() < CAPACITY && self.idx <= self.node.len())]
+    #[ensures(|kv| kv.idx == old(self.idx) && kv.node.len() == old(self.node.len()) + 1)]
+    #[cfg_attr(kani, kani::modifies(NodeRef::as_leaf_ptr(&self.node)))]
     unsafe fn insert_fit(
         mut self,
         key: K,
@@ -1209,6 +1257,10 @@ impl<K, V, NodeType> Handle<NodeRef<marker::Dying, K, V, NodeType>, marker::KV>
 impl<'a, K: 'a, V: 'a, NodeType> Handle<NodeRef<marker::Mut<'a>, K, V, NodeType>, marker::KV> {
     /// Helps implementations of `split` for a particular `NodeType`,
     /// by taking care of leaf data.
+    #[requires(self.idx < self.node.len())]
+    #[ensures(|_| self.node.len() == self.idx)]
+    #[ensures(|_| usize::from(new_node.len) == old(self.node.len() - self.idx - 1))]
+    #[cfg_attr(kani, kani::modifies(NodeRef::as_leaf_ptr(&self.node), new_node))]
     fn split_leaf_data(&mut self, new_node: &mut LeafNode<K, V>) -> (K, V) {
         debug_assert!(self.idx < self.node.len());
         let old_len = self.node.len();
@@ -1809,6 +1861,8 @@ pub(super) mod marker {
 ///
 /// # Safety
 /// The slice has more than `idx` elements.
+#[requires(idx < slice.len())]
+#[cfg_attr(kani, kani::modifies(slice))]
 unsafe fn slice_insert<T>(slice: &mut [MaybeUninit<T>], idx: usize, val: T) {
     unsafe {
         let len = slice.len();
@@ -1826,6 +1880,8 @@ unsafe fn slice_insert<T>(slice: &mut [MaybeUninit<T>], idx: usize, val: T) {
 ///
 /// # Safety
 /// The slice has more than `idx` elements.
+#[requires(idx < slice.len())]
+#[cfg_attr(kani, kani::modifies(slice))]
 unsafe fn slice_remove<T>(slice: &mut [MaybeUninit<T>], idx: usize) -> T {
     unsafe {
         let len = slice.len();
@@ -1841,6 +1897,8 @@ unsafe fn slice_remove<T>(slice: &mut [MaybeUninit<T>], idx: usize) -> T {
 ///
 /// # Safety
 /// The slice has at least `distance` elements.
+#[requires(distance <= slice.len())]
+#[cfg_attr(kani, kani::modifies(slice))]
 unsafe fn slice_shl<T>(slice: &mut [MaybeUninit<T>], distance: usize) {
     unsafe {
         let slice_ptr = slice.as_mut_ptr();
@@ -1852,6 +1910,8 @@ unsafe fn slice_shl<T>(slice: &mut [MaybeUninit<T>], distance: usize) {
 ///
 /// # Safety
 /// The slice has at least `distance` elements.
+#[requires(distance <= slice.len())]
+#[cfg_attr(kani, kani::modifies(slice))]
 unsafe fn slice_shr<T>(slice: &mut [MaybeUninit<T>], distance: usize) {
     unsafe {
         let slice_ptr = slice.as_mut_ptr();
@@ -1871,3 +1931,216 @@ fn move_to_slice<T>(src: &mut [MaybeUninit<T>], dst: &mut [MaybeUninit<T>]) {
 
 #[cfg(test)]
 mod tests;
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::alloc::Global;
+    use crate::collections::BTreeMap;
+
+    // Node layout proofs
+
+    /// Builds a leaf holding `len` pairs with symbolic keys and values.
+    fn any_leaf(len: usize) -> NodeRef<marker::Owned, u8, u8, marker::Leaf> {
+        let mut leaf = NodeRef::new_leaf(Global);
+        for _ in 0..len {
+            leaf.borrow_mut().push(kani::any(), kani::any());
+        }
+        leaf
+    }
+
+    /// Builds a leaf holding `len` pairs whose keys are the odd numbers `1, 3, ...`.
+    fn sorted_leaf(len: usize) -> NodeRef<marker::Owned, u8, u8, marker::Leaf> {
+        let mut leaf = NodeRef::new_leaf(Global);
+        for i in 0..len {
+            leaf.borrow_mut().push(2 * i as u8 + 1, i as u8);
+        }
+        leaf
+    }
+
+    fn is_sorted(keys: &[u8]) -> bool {
+        keys.windows(2).all(|w| w[0] < w[1])
+    }
+
+    fn leaf_is_safe<BorrowType, Type>(node: &NodeRef<BorrowType, u8, u8, Type>) -> bool {
+        unsafe { &*NodeRef::as_leaf_ptr(node) }.is_safe()
+    }
+
+    fn internal_is_safe<BorrowType>(node: &NodeRef<BorrowType, u8, u8, marker::Internal>) -> bool {
+        unsafe { &*NodeRef::as_internal_ptr(node) }.is_safe()
+    }
+
+    #[kani::proof_for_contract(LeafNode::init)]
+    fn check_leaf_init() {
+        let mut leaf = MaybeUninit::<LeafNode<u8, u8>>::uninit();
+        unsafe { LeafNode::init(leaf.as_mut_ptr()) };
+    }
+
+    #[kani::proof_for_contract(splitpoint)]
+    fn check_splitpoint() {
+        let _ = splitpoint(kani::any());
+    }
+
+    /// Returns a key area whose first `len` slots are initialized.
+    fn any_key_area(len: usize) -> [MaybeUninit<u8>; CAPACITY] {
+        let mut arr = [const { MaybeUninit::uninit() }; CAPACITY];
+        for elem in &mut arr[..len] {
+            elem.write(kani::any());
+        }
+        arr
+    }
+
+    #[kani::proof_for_contract(slice_insert)]
+    #[kani::unwind(12)]
+    fn check_slice_insert() {
+        let len = kani::any_where(|&len: &usize| len < CAPACITY);
+        let mut arr = any_key_area(len);
+        unsafe { slice_insert(&mut arr[..len + 1], kani::any(), kani::any::<u8>()) };
+    }
+
+    #[kani::proof_for_contract(slice_remove)]
+    #[kani::unwind(12)]
+    fn check_slice_remove() {
+        let len = kani::any_where(|&len: &usize| len <= CAPACITY);
+        let mut arr = any_key_area(len);
+        let _: u8 = unsafe { slice_remove(&mut arr[..len], kani::any()) };
+    }
+
+    macro_rules! check_slice_shift {
+        ($($name:ident: $f:ident;)+) => {$(
+            #[kani::proof_for_contract($f)]
+            #[kani::unwind(12)]
+            fn $name() {
+                let len = kani::any_where(|&len: &usize| len <= CAPACITY);
+                let mut arr = any_key_area(len);
+                unsafe { $f(&mut arr[..len], kani::any()) };
+            }
+        )+};
+    }
+
+    check_slice_shift! {
+        check_slice_shl: slice_shl;
+        check_slice_shr: slice_shr;
+    }
+
+    #[kani::proof_for_contract(Handle::<NodeRef<marker::Immut<'_>, u8, u8, marker::Leaf>, marker::KV>::new_kv)]
+    #[kani::unwind(12)]
+    fn check_new_kv() {
+        let leaf = any_leaf(kani::any_where(|&len: &usize| len <= CAPACITY));
+        let _ = unsafe { Handle::new_kv(leaf.reborrow(), kani::any()) };
+    }
+
+    #[kani::proof_for_contract(Handle::<NodeRef<marker::Immut<'_>, u8, u8, marker::Leaf>, marker::Edge>::new_edge)]
+    #[kani::unwind(12)]
+    fn check_new_edge() {
+        let leaf = any_leaf(kani::any_where(|&len: &usize| len <= CAPACITY));
+        let _ = unsafe { Handle::new_edge(leaf.reborrow(), kani::any()) };
+    }
+
+    #[kani::proof_for_contract(Handle::<NodeRef<marker::Mut<'_>, u8, u8, marker::Leaf>, marker::Edge>::insert_fit)]
+    #[kani::unwind(12)]
+    fn check_leaf_insert_fit() {
+        let mut leaf = any_leaf(kani::any_where(|&len: &usize| len <= CAPACITY));
+        let idx = kani::any();
+        let edge = Handle { node: leaf.borrow_mut(), idx, _marker: PhantomData };
+        let _ = unsafe { edge.insert_fit(kani::any(), kani::any()) };
+    }
+
+    #[kani::proof_for_contract(Handle::<NodeRef<marker::Mut<'_>, u8, u8, marker::Leaf>, marker::KV>::split_leaf_data)]
+    #[kani::unwind(12)]
+    fn check_split_leaf_data() {
+        let mut leaf = any_leaf(kani::any_where(|&len: &usize| len <= CAPACITY));
+        let mut new_node = LeafNode::new(Global);
+        let idx = kani::any();
+        let mut kv = Handle { node: leaf.borrow_mut(), idx, _marker: PhantomData };
+        let _ = kv.split_leaf_data(&mut *new_node);
+    }
+
+    // Inserting into a full leaf splits it around a middle pair such that both
+    // halves stay sorted and long enough, and the returned handle still points
+    // at the inserted pair.
+    #[kani::proof]
+    #[kani::unwind(13)]
+    fn check_leaf_insert_split() {
+        let mut leaf = sorted_leaf(CAPACITY);
+        let idx = kani::any_where(|&idx: &usize| idx <= CAPACITY);
+        let key = 2 * idx as u8;
+
+        let edge = unsafe { Handle::new_edge(leaf.borrow_mut(), idx) };
+        let (split, handle) = edge.insert(key, u8::MAX, Global);
+        let split = split.unwrap();
+
+        let left = split.left.reborrow();
+        let right = split.right.reborrow();
+        assert!(leaf_is_safe(&left) && leaf_is_safe(&right));
+        assert_eq!(left.len() + 1 + right.len(), CAPACITY + 1);
+        assert!(left.len() >= MIN_LEN_AFTER_SPLIT && right.len() >= MIN_LEN_AFTER_SPLIT);
+        assert!(is_sorted(left.keys()) && is_sorted(right.keys()));
+        assert!(left.keys().last().is_some_and(|&k| k < split.kv.0));
+        assert!(right.keys().first().is_some_and(|&k| k > split.kv.0));
+
+        let (inserted, _) = unsafe { handle.awaken() }.into_kv_mut();
+        assert_eq!(*inserted, key);
+    }
+
+    // Splitting a full internal node moves the edges right of the middle pair
+    // into the new node and relinks every moved child to its new parent.
+    #[kani::proof]
+    #[kani::unwind(14)]
+    fn check_internal_split() {
+        let mut root = NodeRef::new_leaf(Global).forget_type();
+        let mut internal = root.push_internal_level(Global);
+        for i in 0..CAPACITY {
+            internal.push(2 * i as u8 + 1, 0, NodeRef::new_leaf(Global).forget_type());
+        }
+        assert!(internal_is_safe(&internal));
+
+        let idx = kani::any_where(|&idx: &usize| idx < CAPACITY);
+        let kv = unsafe { Handle::new_kv(internal, idx) };
+        let split = kv.split(Global);
+
+        assert_eq!(split.kv.0, 2 * idx as u8 + 1);
+        assert_eq!(split.left.len(), idx);
+        assert_eq!(split.right.len(), CAPACITY - idx - 1);
+        assert!(internal_is_safe(&split.left) && internal_is_safe(&split.right));
+    }
+
+    // Inserting enough keys to split the root keeps the map ordered.
+    #[kani::proof]
+    #[kani::unwind(14)]
+    fn check_map_insert_split() {
+        let mut map = BTreeMap::new();
+        for i in 0..CAPACITY {
+            map.insert(2 * i as u8, ());
+        }
+        let key: u8 = kani::any();
+        map.insert(key, ());
+
+        assert!(map.contains_key(&key));
+        assert_eq!(map.len(), CAPACITY + usize::from(key % 2 == 1 || key >= 2 * CAPACITY as u8));
+        let mut prev = None;
+        for (&k, _) in &map {
+            assert!(prev.is_none_or(|p| p < k));
+            prev = Some(k);
+        }
+    }
+
+    // Removing any key from a map just past a single node's capacity rebalances
+    // or merges its leaves without losing or reordering the remaining keys.
+    #[kani::proof]
+    #[kani::unwind(14)]
+    fn check_map_remove_merge() {
+        let mut map: BTreeMap<u8, ()> = (0..=CAPACITY as u8).map(|k| (k, ())).collect();
+        let key = kani::any_where(|&k: &u8| k <= CAPACITY as u8);
+        map.remove(&key);
+
+        assert!(!map.contains_key(&key));
+        assert_eq!(map.len(), CAPACITY);
+        let mut prev = None;
+        for (&k, _) in &map {
+            assert!(k != key && prev.is_none_or(|p| p < k));
+            prev = Some(k);
+        }
+    }
+}
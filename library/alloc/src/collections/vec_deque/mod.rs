@@ -17,8 +17,12 @@ use core::iter::{ByRefSized, repeat_n, repeat_with};
 use core::mem;
 use core::mem::{ManuallyDrop, SizedTypeProperties};
 use core::ops::{Index, IndexMut, Range, RangeBounds};
+#[cfg(kani)]
+use core::ub_checks::Invariant;
 use core::{fmt, ptr, slice};
 
+use safety::{ensures, requires};
+
 use crate::alloc::{Allocator, Global};
 use crate::collections::{TryReserveError, TryReserveErrorKind};
 use crate::raw_vec::RawVec;
@@ -104,6 +108,17 @@ pub struct VecDeque<
     buf: RawVec<T, A>,
 }
 
+/// The field invariants documented on `VecDeque`: `head` is a valid physical
+/// index (or `0` for an unallocated buffer) and the elements fit in the buffer.
+#[cfg(kani)]
+#[unstable(feature = "ub_checks", issue = "none")]
+impl<T, A: Allocator> Invariant for VecDeque<T, A> {
+    fn is_safe(&self) -> bool {
+        let cap = self.capacity();
+        (self.head < cap || (cap == 0 && self.head == 0)) && self.len <= cap
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T: Clone, A: Allocator + Clone> Clone for VecDeque<T, A> {
     #[track_caller]
@@ -202,6 +217,12 @@ impl<T, A: Allocator> VecDeque<T, A> {
         }
     }
 
+    /// Returns a slice pointer to the whole buffer.
+    #[cfg(kani)]
+    fn buffer(&self) -> *mut [T] {
+        ptr::slice_from_raw_parts_mut(self.ptr(), self.capacity())
+    }
+
     /// Returns `true` if the buffer is at full capacity.
     #[inline]
     fn is_full(&self) -> bool {
@@ -2430,6 +2451,7 @@ impl<T, A: Allocator> VecDeque<T, A> {
     /// }
     /// ```
     #[stable(feature = "deque_make_contiguous", since = "1.48.0")]
+    #[ensures(|slice| slice.len() == old(self.len))]
     pub fn make_contiguous(&mut self) -> &mut [T] {
         if T::IS_ZST {
             self.head = 0;
@@ -2650,6 +2672,10 @@ impl<T, A: Allocator> VecDeque<T, A> {
     // so it's sound to call here because we're calling with something
     // less than half the length, which is never above half the capacity.
 
+    #[requires(self.is_safe() && mid <= self.len / 2)]
+    #[ensures(|_| self.is_safe() && self.len == old(self.len))]
+    #[ensures(|_| self.head == old(self.to_physical_idx(mid)))]
+    #[cfg_attr(kani, kani::modifies(self, self.buffer()))]
     unsafe fn rotate_left_inner(&mut self, mid: usize) {
         debug_assert!(mid * 2 <= self.len());
         unsafe {
@@ -2658,6 +2684,10 @@ impl<T, A: Allocator> VecDeque<T, A> {
         self.head = self.to_physical_idx(mid);
     }
 
+    #[requires(self.is_safe() && k <= self.len / 2)]
+    #[ensures(|_| self.is_safe() && self.len == old(self.len))]
+    #[ensures(|_| self.head == old(self.wrap_sub(self.head, k)))]
+    #[cfg_attr(kani, kani::modifies(self, self.buffer()))]
     unsafe fn rotate_right_inner(&mut self, k: usize) {
         debug_assert!(k * 2 <= self.len());
         self.head = self.wrap_sub(self.head, k);
@@ -3228,6 +3258,7 @@ impl<T, const N: usize> From<[T; N]> for VecDeque<T> {
 #[unstable(feature = "kani", issue = "none")]
 mod verify {
     use core::kani;
+    use core::ub_checks::Invariant;
 
     use crate::collections::VecDeque;
 
@@ -3262,4 +3293,117 @@ mod verify {
             assert!(deque[k] == arr[k]);
         }
     }
+
+    // Rotation and re-layout proofs
+
+    const MAX_CAP: usize = 5;
+
+    /// Builds a deque over a fully initialized buffer of symbolic capacity at
+    /// most `MAX_CAP`, with a symbolic `head` and `len`, so its elements may
+    /// wrap around the end of the buffer.
+    fn any_deque() -> VecDeque<u8> {
+        let mut deque = VecDeque::with_capacity(kani::any_where(|&cap: &usize| cap <= MAX_CAP));
+        let cap = deque.capacity();
+        for i in 0..cap {
+            unsafe { deque.ptr().add(i).write(kani::any()) };
+        }
+        deque.head = kani::any_where(|&head: &usize| head < cap || head == 0);
+        deque.len = kani::any_where(|&len: &usize| len <= cap);
+        deque
+    }
+
+    /// Returns the elements of `deque` in logical order.
+    fn snapshot(deque: &VecDeque<u8>) -> [u8; MAX_CAP] {
+        let mut elems = [0; MAX_CAP];
+        for i in 0..deque.len() {
+            elems[i] = deque[i];
+        }
+        elems
+    }
+
+    #[kani::proof_for_contract(VecDeque::rotate_left_inner)]
+    #[kani::unwind(6)]
+    fn check_rotate_left_inner() {
+        let mut deque = any_deque();
+        let mid = kani::any();
+        unsafe { deque.rotate_left_inner(mid) };
+    }
+
+    #[kani::proof_for_contract(VecDeque::rotate_right_inner)]
+    #[kani::unwind(6)]
+    fn check_rotate_right_inner() {
+        let mut deque = any_deque();
+        let k = kani::any();
+        unsafe { deque.rotate_right_inner(k) };
+    }
+
+    // The contract cannot name the old elements of a generic deque, so the order they come back
+    // in is asserted here.
+    #[kani::proof_for_contract(VecDeque::make_contiguous)]
+    #[kani::unwind(6)]
+    fn check_make_contiguous_contract() {
+        let mut deque = any_deque();
+        let before = snapshot(&deque);
+        let len = deque.len();
+        let slice = deque.make_contiguous();
+        assert_eq!(slice, &before[..len]);
+    }
+
+    // The re-layout keeps the logical order and leaves a single slice behind.
+    #[kani::proof]
+    #[kani::unwind(6)]
+    fn check_make_contiguous() {
+        let mut deque = any_deque();
+        let before = snapshot(&deque);
+        let len = deque.len();
+
+        let slice = deque.make_contiguous();
+        assert_eq!(slice, &before[..len]);
+
+        assert!(deque.is_safe());
+        assert!(deque.is_contiguous());
+        assert_eq!(deque.as_slices().1.len(), 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(6)]
+    fn check_rotate_left() {
+        let mut deque = any_deque();
+        let before = snapshot(&deque);
+        let len = deque.len();
+        let n = kani::any_where(|&n: &usize| n <= len);
+
+        deque.rotate_left(n);
+
+        assert!(deque.is_safe());
+        assert_eq!(deque.len(), len);
+        for i in 0..len {
+            assert_eq!(deque[i], before[(i + n) % len]);
+        }
+    }
+
+    #[kani::proof]
+    #[kani::unwind(6)]
+    fn check_rotate_right() {
+        let mut deque = any_deque();
+        let before = snapshot(&deque);
+        let len = deque.len();
+        let n = kani::any_where(|&n: &usize| n <= len);
+
+        deque.rotate_right(n);
+
+        assert!(deque.is_safe());
+        assert_eq!(deque.len(), len);
+        for i in 0..len {
+            assert_eq!(deque[(i + n) % len], before[i]);
+        }
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_rotate_past_len() {
+        let mut deque = any_deque();
+        let n = kani::any_where(|&n: &usize| n > deque.len());
+        deque.rotate_left(n);
+    }
 }
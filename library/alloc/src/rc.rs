@@ -251,6 +251,8 @@ use core::hash::{Hash, Hasher};
 use core::intrinsics::abort;
 #[cfg(not(no_global_oom_handling))]
 use core::iter;
+#[cfg(kani)]
+use core::kani;
 use core::marker::{PhantomData, Unsize};
 use core::mem::{self, ManuallyDrop, align_of_val_raw};
 use core::num::NonZeroUsize;
@@ -264,6 +266,8 @@ use core::ptr::{self, NonNull, drop_in_place};
 use core::slice::from_raw_parts_mut;
 use core::{borrow, fmt, hint};
 
+use safety::{ensures, requires};
+
 #[cfg(not(no_global_oom_handling))]
 use crate::alloc::handle_alloc_error;
 use crate::alloc::{AllocError, Allocator, Global, Layout};
@@ -2002,6 +2006,14 @@ impl<A: Allocator> Rc<dyn Any, A> {
     /// ```
     #[inline]
     #[stable(feature = "rc_downcast", since = "1.29.0")]
+    #[ensures(|result| result.is_ok() == old((*self).is::<T>()))]
+    #[ensures(|result| {
+        let (ptr, strong) = match result {
+            Ok(this) => (this.ptr.as_ptr() as *const (), Rc::strong_count(this)),
+            Err(this) => (this.ptr.as_ptr() as *const (), Rc::strong_count(this)),
+        };
+        ptr == old(self.ptr.as_ptr() as *const ()) && strong == old(Rc::strong_count(&self))
+    })]
     pub fn downcast<T: Any>(self) -> Result<Rc<T, A>, Self> {
         if (*self).is::<T>() {
             unsafe {
@@ -2041,6 +2053,9 @@ impl<A: Allocator> Rc<dyn Any, A> {
     /// [`downcast`]: Self::downcast
     #[inline]
     #[unstable(feature = "downcast_unchecked", issue = "90850")]
+    #[requires((*self).is::<T>())]
+    #[ensures(|this| this.ptr.as_ptr() as *const () == old(self.ptr.as_ptr() as *const ()))]
+    #[ensures(|this| Rc::strong_count(this) == old(Rc::strong_count(&self)))]
     pub unsafe fn downcast_unchecked<T: Any>(self) -> Rc<T, A> {
         unsafe {
             let (ptr, alloc) = Rc::into_inner_with_allocator(self);
@@ -4158,3 +4173,49 @@ impl<T: ?Sized, A: Allocator> Drop for UniqueRcUninit<T, A> {
         }
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // `Rc<dyn Any>` downcast proofs
+
+    /// An `Rc` holding either a `u8` or a `u16`, possibly shared.
+    fn any_rc() -> (Rc<dyn Any>, Option<Rc<dyn Any>>) {
+        let rc: Rc<dyn Any> =
+            if kani::any() { Rc::new(kani::any::<u8>()) } else { Rc::new(kani::any::<u16>()) };
+        let other = if kani::any() { Some(rc.clone()) } else { None };
+        (rc, other)
+    }
+
+    #[kani::proof_for_contract(Rc::downcast)]
+    fn check_downcast() {
+        let (rc, _other) = any_rc();
+        let _ = rc.downcast::<u8>();
+    }
+
+    #[kani::proof_for_contract(Rc::downcast_unchecked)]
+    fn check_downcast_unchecked() {
+        let (rc, _other) = any_rc();
+        let _ = unsafe { rc.downcast_unchecked::<u8>() };
+    }
+
+    // A successful downcast reads back the stored value; a mismatched one hands back the
+    // original `Rc` untouched.
+    #[kani::proof]
+    fn check_downcast_value() {
+        let value: u8 = kani::any();
+        let rc: Rc<dyn Any> = Rc::new(value);
+        let rc = rc.downcast::<u16>().unwrap_err();
+        assert_eq!(*rc.downcast::<u8>().unwrap(), value);
+    }
+
+    #[kani::proof]
+    #[kani::stub_verified(Rc::downcast_unchecked)]
+    #[kani::should_panic]
+    fn check_downcast_unchecked_mismatch() {
+        let rc: Rc<dyn Any> = Rc::new(kani::any::<u16>());
+        let _ = unsafe { rc.downcast_unchecked::<u8>() };
+    }
+}
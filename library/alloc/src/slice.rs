@@ -12,6 +12,8 @@
 use core::borrow::{Borrow, BorrowMut};
 #[cfg(not(no_global_oom_handling))]
 use core::cmp::Ordering::{self, Less};
+#[cfg(kani)]
+use core::kani;
 #[cfg(not(no_global_oom_handling))]
 use core::mem::MaybeUninit;
 #[cfg(not(no_global_oom_handling))]
@@ -57,6 +59,8 @@ pub use core::slice::{from_raw_parts, from_raw_parts_mut};
 #[unstable(feature = "slice_range", issue = "76393")]
 pub use core::slice::{range, try_range};
 
+use safety::ensures;
+
 ////////////////////////////////////////////////////////////////////////////////
 // Basic slice extension methods
 ////////////////////////////////////////////////////////////////////////////////
@@ -506,6 +510,7 @@ impl<T> [T] {
     #[rustc_allow_incoherent_impl]
     #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "repeat_generic_slice", since = "1.40.0")]
+    #[ensures(|buf| buf.len() == self.len() * n)]
     pub fn repeat(&self, n: usize) -> Vec<T>
     where
         T: Copy,
@@ -528,6 +533,9 @@ impl<T> [T] {
         {
             let mut m = n >> 1;
             // If `m > 0`, there are remaining bits up to the leftmost '1'.
+            #[safety::loop_invariant(m <= n >> 1
+                && buf.len() <= capacity / (2 * m).max(1)
+                && buf.capacity() >= capacity)]
             while m > 0 {
                 // `buf.extend(buf)`:
                 unsafe {
@@ -726,6 +734,7 @@ pub trait Join<Separator> {
 impl<T: Clone, V: Borrow<[T]>> Concat<T> for [V] {
     type Output = Vec<T>;
 
+    #[cfg_attr(kani, ensures(|result| Some(result.len()) == joined_len(slice, 0)))]
     fn concat(slice: &Self) -> Vec<T> {
         let size = slice.iter().map(|slice| slice.borrow().len()).sum();
         let mut result = Vec::with_capacity(size);
@@ -741,6 +750,7 @@ impl<T: Clone, V: Borrow<[T]>> Concat<T> for [V] {
 impl<T: Clone, V: Borrow<[T]>> Join<&T> for [V] {
     type Output = Vec<T>;
 
+    #[cfg_attr(kani, ensures(|result| Some(result.len()) == joined_len(slice, 1)))]
     fn join(slice: &Self, sep: &T) -> Vec<T> {
         let mut iter = slice.iter();
         let first = match iter.next() {
@@ -764,6 +774,7 @@ impl<T: Clone, V: Borrow<[T]>> Join<&T> for [V] {
 impl<T: Clone, V: Borrow<[T]>> Join<&[T]> for [V] {
     type Output = Vec<T>;
 
+    #[cfg_attr(kani, ensures(|result| Some(result.len()) == joined_len(slice, sep.len())))]
     fn join(slice: &Self, sep: &[T]) -> Vec<T> {
         let mut iter = slice.iter();
         let first = match iter.next() {
@@ -783,6 +794,14 @@ impl<T: Clone, V: Borrow<[T]>> Join<&[T]> for [V] {
     }
 }
 
+/// Ghost function: the length of `slice` joined with a separator of `sep_len` elements,
+/// or `None` if it overflows.
+#[cfg(all(kani, not(no_global_oom_handling)))]
+fn joined_len<T, V: Borrow<[T]>>(slice: &[V], sep_len: usize) -> Option<usize> {
+    let seps = sep_len.checked_mul(slice.len().saturating_sub(1))?;
+    slice.iter().try_fold(seps, |len, v| len.checked_add(v.borrow().len()))
+}
+
 ////////////////////////////////////////////////////////////////////////////////
 // Standard trait implementations for slices
 ////////////////////////////////////////////////////////////////////////////////
@@ -871,3 +890,101 @@ impl<T> sort::stable::BufGuard<T> for Vec<T> {
         self.spare_capacity_mut()
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // `repeat` proofs
+
+    const PATTERN_LEN: usize = 3;
+    const MAX_REPEAT: usize = 5;
+
+    #[kani::proof_for_contract(<[u8]>::repeat)]
+    fn check_repeat() {
+        let pattern: [u8; PATTERN_LEN] = kani::any();
+        let pattern = kani::slice::any_slice_of_array(&pattern);
+        let n = kani::any_where(|&n: &usize| pattern.len().checked_mul(n).is_some());
+        let _ = pattern.repeat(n);
+    }
+
+    // The doubling and remainder copies write exactly `n` copies of the pattern.
+    #[kani::proof]
+    #[kani::unwind(16)]
+    fn check_repeat_contents() {
+        let pattern: [u8; PATTERN_LEN] = kani::any();
+        let pattern = kani::slice::any_slice_of_array(&pattern);
+        let n = kani::any_where(|&n: &usize| n <= MAX_REPEAT);
+        let buf = pattern.repeat(n);
+        assert_eq!(buf.len(), pattern.len() * n);
+        let i = kani::any_where(|&i: &usize| i < buf.len());
+        assert_eq!(buf[i], pattern[i % pattern.len()]);
+    }
+
+    // `concat` and `join` proofs
+
+    const PART_LEN: usize = 2;
+
+    /// Checks that `joined` is `parts` with `sep` between consecutive parts.
+    fn check_joined(joined: &[u8], parts: &[&[u8]], sep: &[u8]) {
+        let mut pos = 0;
+        for (i, part) in parts.iter().enumerate() {
+            if i > 0 {
+                assert_eq!(&joined[pos..][..sep.len()], sep);
+                pos += sep.len();
+            }
+            assert_eq!(&joined[pos..][..part.len()], *part);
+            pos += part.len();
+        }
+        assert_eq!(pos, joined.len());
+    }
+
+    macro_rules! any_parts {
+        ($parts:ident) => {
+            let (a, b, c): ([u8; PART_LEN], [u8; PART_LEN], [u8; PART_LEN]) = kani::any();
+            let parts = [
+                kani::slice::any_slice_of_array(&a),
+                kani::slice::any_slice_of_array(&b),
+                kani::slice::any_slice_of_array(&c),
+            ];
+            let $parts = kani::slice::any_slice_of_array(&parts);
+        };
+    }
+
+    #[kani::proof_for_contract(<[&[u8]] as Concat<u8>>::concat)]
+    #[kani::unwind(4)]
+    fn check_concat() {
+        any_parts!(parts);
+        kani::assume(joined_len(parts, 0).is_some());
+        check_joined(&parts.concat(), parts, &[]);
+    }
+
+    #[kani::proof_for_contract(<[&[u8]] as Join<&u8>>::join)]
+    #[kani::unwind(4)]
+    fn check_join_element() {
+        any_parts!(parts);
+        let sep: u8 = kani::any();
+        kani::assume(joined_len(parts, 1).is_some());
+        check_joined(&parts.join(&sep), parts, &[sep]);
+    }
+
+    #[kani::proof_for_contract(<[&[u8]] as Join<&[u8]>>::join)]
+    #[kani::unwind(4)]
+    fn check_join_slice() {
+        any_parts!(parts);
+        let sep: [u8; PART_LEN] = kani::any();
+        let sep = kani::slice::any_slice_of_array(&sep);
+        kani::assume(joined_len(parts, sep.len()).is_some());
+        check_joined(&parts.join(sep), parts, sep);
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_repeat_overflow() {
+        let pattern: [u8; PATTERN_LEN] = kani::any();
+        let pattern = kani::slice::any_slice_of_array(&pattern);
+        let n = kani::any_where(|&n: &usize| pattern.len().checked_mul(n).is_none());
+        let _ = pattern.repeat(n);
+    }
+}
@@ -9,6 +9,8 @@
 
 use core::borrow::{Borrow, BorrowMut};
 use core::iter::FusedIterator;
+#[cfg(kani)]
+use core::kani;
 use core::mem::MaybeUninit;
 #[stable(feature = "encode_utf16", since = "1.8.0")]
 pub use core::str::EncodeUtf16;
@@ -49,6 +51,8 @@ pub use core::str::{from_raw_parts, from_raw_parts_mut};
 use core::unicode::conversions;
 use core::{mem, ptr};
 
+use safety::ensures;
+
 use crate::borrow::ToOwned;
 use crate::boxed::Box;
 use crate::slice::{Concat, Join, SliceIndex};
@@ -127,6 +131,7 @@ macro_rules! copy_slice_and_advance {
 // [T] and str both impl AsRef<[T]> for some T
 // => s.borrow().as_ref() and we always have slices
 #[cfg(not(no_global_oom_handling))]
+#[cfg_attr(kani, ensures(|result| result.len() == joined_len::<B, T, S>(slice, sep).unwrap()))]
 fn join_generic_copy<B, T, S>(slice: &[S], sep: &[T]) -> Vec<T>
 where
     T: Copy,
@@ -185,6 +190,17 @@ where
     result
 }
 
+/// Ghost function: the `reserved_len` of `join_generic_copy`, or `None` if it overflows.
+#[cfg(all(kani, not(no_global_oom_handling)))]
+fn joined_len<B, T, S>(slice: &[S], sep: &[T]) -> Option<usize>
+where
+    B: AsRef<[T]> + ?Sized,
+    S: Borrow<B>,
+{
+    let seps = sep.len().checked_mul(slice.len().saturating_sub(1))?;
+    slice.iter().try_fold(seps, |len, s| len.checked_add(s.borrow().as_ref().len()))
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Borrow<str> for String {
     #[inline]
@@ -528,6 +544,7 @@ impl str {
     #[must_use]
     #[stable(feature = "repeat_str", since = "1.16.0")]
     #[inline]
+    #[ensures(|s| s.len() == self.len() * n)]
     pub fn repeat(&self, n: usize) -> String {
         unsafe { String::from_utf8_unchecked(self.as_bytes().repeat(n)) }
     }
@@ -712,3 +729,85 @@ unsafe fn replace_ascii(utf8_bytes: &[u8], from: u8, to: u8) -> String {
     // SAFETY: We replaced ascii with ascii on valid utf8 strings.
     unsafe { String::from_utf8_unchecked(result) }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // `repeat` proofs
+
+    const STRING_CHARS: usize = 2;
+    const MAX_REPEAT: usize = 4;
+
+    /// A string of up to `STRING_CHARS` symbolic chars.
+    fn any_string() -> String {
+        let mut s = String::new();
+        for _ in 0..kani::any_where(|&n: &usize| n <= STRING_CHARS) {
+            s.push(kani::any());
+        }
+        s
+    }
+
+    #[kani::proof_for_contract(<str>::repeat)]
+    #[kani::unwind(3)]
+    fn check_repeat() {
+        let s = any_string();
+        let n = kani::any_where(|&n: &usize| s.len().checked_mul(n).is_some());
+        let _ = s.repeat(n);
+    }
+
+    // Every copy of the pattern is intact, so the result is valid UTF-8.
+    #[kani::proof]
+    #[kani::unwind(33)]
+    fn check_repeat_contents() {
+        let s = any_string();
+        let n = kani::any_where(|&n: &usize| n <= MAX_REPEAT);
+        let repeated = s.repeat(n);
+        assert_eq!(repeated.len(), s.len() * n);
+        let copy = kani::any_where(|&copy: &usize| copy < n);
+        assert_eq!(&repeated[copy * s.len()..][..s.len()], s);
+        assert!(from_utf8(repeated.as_bytes()).is_ok());
+    }
+
+    // `join` proofs
+
+    const PARTS: usize = 3;
+
+    #[kani::proof_for_contract(join_generic_copy::<[u8], u8, &[u8]>)]
+    #[kani::unwind(4)]
+    fn check_join_generic_copy() {
+        let (a, b, c, sep): ([u8; 2], [u8; 2], [u8; 2], [u8; 2]) = kani::any();
+        let parts = [
+            kani::slice::any_slice_of_array(&a),
+            kani::slice::any_slice_of_array(&b),
+            kani::slice::any_slice_of_array(&c),
+        ];
+        let parts = kani::slice::any_slice_of_array(&parts);
+        let sep = kani::slice::any_slice_of_array(&sep);
+        kani::assume(joined_len::<[u8], u8, &[u8]>(parts, sep).is_some());
+        let _ = join_generic_copy::<[u8], u8, &[u8]>(parts, sep);
+    }
+
+    // The copies into spare capacity interleave the separator exactly, so joining valid
+    // strings gives a valid string.
+    #[kani::proof]
+    #[kani::unwind(9)]
+    fn check_join() {
+        let parts: [String; PARTS] = [any_string(), any_string(), any_string()];
+        let parts = kani::slice::any_slice_of_array(&parts);
+        let sep = any_string();
+        let joined = parts.join(&*sep);
+        let mut pos = 0;
+        for (i, part) in parts.iter().enumerate() {
+            if i > 0 {
+                assert_eq!(&joined[pos..][..sep.len()], sep);
+                pos += sep.len();
+            }
+            assert_eq!(&joined[pos..][..part.len()], part.as_str());
+            pos += part.len();
+        }
+        assert_eq!(pos, joined.len());
+        assert!(from_utf8(joined.as_bytes()).is_ok());
+    }
+}
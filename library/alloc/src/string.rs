@@ -46,6 +46,8 @@ use core::error::Error;
 use core::iter::FusedIterator;
 #[cfg(not(no_global_oom_handling))]
 use core::iter::from_fn;
+#[cfg(kani)]
+use core::kani;
 #[cfg(not(no_global_oom_handling))]
 use core::ops::Add;
 #[cfg(not(no_global_oom_handling))]
@@ -56,6 +58,8 @@ use core::ops::{self, Range, RangeBounds};
 use core::str::pattern::{Pattern, Utf8Pattern};
 use core::{fmt, hash, ptr, slice};
 
+use safety::{ensures, requires};
+
 #[cfg(not(no_global_oom_handling))]
 use crate::alloc::Allocator;
 #[cfg(not(no_global_oom_handling))]
@@ -1521,6 +1525,9 @@ impl String {
     #[stable(feature = "rust1", since = "1.0.0")]
     #[track_caller]
     #[rustc_confusables("delete", "take")]
+    #[ensures(|&ch| self.len() == old(self.len()) - ch.len_utf8())]
+    #[ensures(|_| str::from_utf8(self.as_bytes()).is_ok())]
+    #[cfg_attr(kani, kani::modifies(self))]
     pub fn remove(&mut self, idx: usize) -> char {
         let ch = match self[idx..].chars().next() {
             Some(ch) => ch,
@@ -1639,20 +1646,6 @@ impl String {
     where
         F: FnMut(char) -> bool,
     {
-        struct SetLenOnDrop<'a> {
-            s: &'a mut String,
-            idx: usize,
-            del_bytes: usize,
-        }
-
-        impl<'a> Drop for SetLenOnDrop<'a> {
-            fn drop(&mut self) {
-                let new_len = self.idx - self.del_bytes;
-                debug_assert!(new_len <= self.s.len());
-                unsafe { self.s.vec.set_len(new_len) };
-            }
-        }
-
         let len = self.len();
         let mut guard = SetLenOnDrop { s: self, idx: 0, del_bytes: 0 };
 
@@ -1717,6 +1710,9 @@ impl String {
     #[track_caller]
     #[stable(feature = "rust1", since = "1.0.0")]
     #[rustc_confusables("set")]
+    #[ensures(|_| self.len() == old(self.len()) + ch.len_utf8() && self[idx..].starts_with(ch))]
+    #[ensures(|_| str::from_utf8(self.as_bytes()).is_ok())]
+    #[cfg_attr(kani, kani::modifies(self))]
     pub fn insert(&mut self, idx: usize, ch: char) {
         assert!(self.is_char_boundary(idx));
 
@@ -1774,6 +1770,9 @@ impl String {
     #[track_caller]
     #[stable(feature = "insert_str", since = "1.16.0")]
     #[rustc_diagnostic_item = "string_insert_str"]
+    #[ensures(|_| self.len() == old(self.len()) + string.len() && self[idx..].starts_with(string))]
+    #[ensures(|_| str::from_utf8(self.as_bytes()).is_ok())]
+    #[cfg_attr(kani, kani::modifies(self))]
     pub fn insert_str(&mut self, idx: usize, string: &str) {
         assert!(self.is_char_boundary(idx));
 
@@ -2066,6 +2065,13 @@ impl String {
     #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "splice", since = "1.27.0")]
     #[track_caller]
+    #[ensures(|_| {
+        let replaced = old(self.char_boundary_range(&range).unwrap());
+        self.len() == old(self.len()) - replaced.len() + replace_with.len()
+            && self[replaced.start..].starts_with(replace_with)
+    })]
+    #[ensures(|_| str::from_utf8(self.as_bytes()).is_ok())]
+    #[cfg_attr(kani, kani::modifies(self))]
     pub fn replace_range<R>(&mut self, range: R, replace_with: &str)
     where
         R: RangeBounds<usize>,
@@ -2096,6 +2102,24 @@ impl String {
         unsafe { self.as_mut_vec() }.splice((start, end), replace_with.bytes());
     }
 
+    /// Ghost function: the byte range `replace_range(range, _)` replaces, if both of its
+    /// ends are char boundaries and in order.
+    #[cfg(all(kani, not(no_global_oom_handling)))]
+    fn char_boundary_range<R: RangeBounds<usize>>(&self, range: &R) -> Option<Range<usize>> {
+        let start = match range.start_bound() {
+            Included(&n) => n,
+            Excluded(&n) => n.checked_add(1)?,
+            Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Included(&n) => n.checked_add(1)?,
+            Excluded(&n) => n,
+            Unbounded => self.len(),
+        };
+        (start <= end && self.is_char_boundary(start) && self.is_char_boundary(end))
+            .then_some(start..end)
+    }
+
     /// Converts this `String` into a <code>[Box]<[str]></code>.
     ///
     /// Before doing the conversion, this method discards excess capacity like [`shrink_to_fit`].
@@ -2153,6 +2177,27 @@ impl String {
     }
 }
 
+/// Guard used by `String::retain` to restore the string's length, even if the predicate
+/// panics. The first `idx - del_bytes` bytes always hold the chars kept so far.
+struct SetLenOnDrop<'a> {
+    s: &'a mut String,
+    idx: usize,
+    del_bytes: usize,
+}
+
+impl<'a> Drop for SetLenOnDrop<'a> {
+    #[requires(self.del_bytes <= self.idx && self.idx <= self.s.len())]
+    #[requires(str::from_utf8(&self.s.as_bytes()[..self.idx - self.del_bytes]).is_ok())]
+    #[ensures(|_| self.s.len() == old(self.idx - self.del_bytes))]
+    #[ensures(|_| str::from_utf8(self.s.as_bytes()).is_ok())]
+    #[cfg_attr(kani, kani::modifies(&self.s.vec))]
+    fn drop(&mut self) {
+        let new_len = self.idx - self.del_bytes;
+        debug_assert!(new_len <= self.s.len());
+        unsafe { self.s.vec.set_len(new_len) };
+    }
+}
+
 impl FromUtf8Error {
     /// Returns a slice of [`u8`]s bytes that were attempted to convert to a `String`.
     ///
@@ -3538,3 +3583,178 @@ impl From<char> for String {
         c.to_string()
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // `insert`, `insert_str`, `remove` and `replace_range` proofs
+
+    const STRING_CHARS: usize = 3;
+
+    /// A string of up to `STRING_CHARS` symbolic chars.
+    fn any_string() -> String {
+        let mut s = String::new();
+        for _ in 0..kani::any_where(|&n: &usize| n <= STRING_CHARS) {
+            s.push(kani::any());
+        }
+        s
+    }
+
+    #[kani::proof_for_contract(String::insert)]
+    #[kani::unwind(17)]
+    fn check_insert() {
+        let mut s = any_string();
+        let idx = kani::any_where(|&idx: &usize| s.is_char_boundary(idx));
+        s.insert(idx, kani::any());
+    }
+
+    #[kani::proof_for_contract(String::insert_str)]
+    #[kani::unwind(25)]
+    fn check_insert_str() {
+        let mut s = any_string();
+        let string = any_string();
+        let idx = kani::any_where(|&idx: &usize| s.is_char_boundary(idx));
+        s.insert_str(idx, &string);
+    }
+
+    #[kani::proof_for_contract(String::remove)]
+    #[kani::unwind(17)]
+    fn check_remove() {
+        let mut s = any_string();
+        let idx = kani::any_where(|&idx: &usize| idx < s.len() && s.is_char_boundary(idx));
+        let _ = s.remove(idx);
+    }
+
+    #[kani::proof_for_contract(String::replace_range::<Range<usize>>)]
+    #[kani::unwind(25)]
+    fn check_replace_range() {
+        let mut s = any_string();
+        let replace_with = any_string();
+        let range = kani::any::<usize>()..kani::any::<usize>();
+        kani::assume(s.char_boundary_range(&range).is_some());
+        s.replace_range(range, &replace_with);
+    }
+
+    // Removing an inserted char gives it back and restores the original string.
+    #[kani::proof]
+    #[kani::unwind(17)]
+    fn check_insert_remove() {
+        let mut s = any_string();
+        let original = s.clone();
+        let idx = kani::any_where(|&idx: &usize| s.is_char_boundary(idx));
+        let ch = kani::any();
+        s.insert(idx, ch);
+        assert_eq!(s.remove(idx), ch);
+        assert_eq!(s, original);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(17)]
+    #[kani::should_panic]
+    fn check_insert_inside_char() {
+        let mut s = any_string();
+        let idx = kani::any_where(|&idx: &usize| !s.is_char_boundary(idx));
+        s.insert(idx, kani::any());
+    }
+
+    #[kani::proof]
+    #[kani::unwind(25)]
+    #[kani::should_panic]
+    fn check_insert_str_inside_char() {
+        let mut s = any_string();
+        let string = any_string();
+        let idx = kani::any_where(|&idx: &usize| !s.is_char_boundary(idx));
+        s.insert_str(idx, &string);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(17)]
+    #[kani::should_panic]
+    fn check_remove_out_of_place() {
+        let mut s = any_string();
+        let idx = kani::any_where(|&idx: &usize| idx >= s.len() || !s.is_char_boundary(idx));
+        let _ = s.remove(idx);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(25)]
+    #[kani::should_panic]
+    fn check_replace_range_invalid() {
+        let mut s = any_string();
+        let replace_with = any_string();
+        let range = kani::any::<usize>()..kani::any::<usize>();
+        kani::assume(s.char_boundary_range(&range).is_none());
+        s.replace_range(range, &replace_with);
+    }
+
+    // `retain` proofs
+
+    #[kani::proof_for_contract(<SetLenOnDrop<'_> as Drop>::drop)]
+    #[kani::unwind(17)]
+    fn check_set_len_on_drop() {
+        let mut s = any_string();
+        let idx = kani::any_where(|&idx: &usize| s.is_char_boundary(idx));
+        let kept = kani::any_where(|&kept: &usize| kept <= idx && s.is_char_boundary(kept));
+        drop(SetLenOnDrop { s: &mut s, idx, del_bytes: idx - kept });
+    }
+
+    #[kani::proof]
+    #[kani::unwind(17)]
+    fn check_retain() {
+        let mut s = any_string();
+        let keep: [bool; STRING_CHARS] = kani::any();
+        let mut expected = String::new();
+        for (c, &keep) in s.chars().zip(&keep) {
+            if keep {
+                expected.push(c);
+            }
+        }
+        let mut calls = 0;
+        s.retain(|_| {
+            calls += 1;
+            keep[calls - 1]
+        });
+        assert!(str::from_utf8(s.as_bytes()).is_ok());
+        assert_eq!(s, expected);
+    }
+
+    const LOSSY_BYTES: usize = 6;
+
+    /// Reference decoder following the `Utf8Error` documentation: keep the valid prefix,
+    /// emit one U+FFFD for the invalid sequence (or truncated tail) after it, and resume.
+    fn lossy_reference(mut v: &[u8]) -> String {
+        let mut res = String::new();
+        loop {
+            match str::from_utf8(v) {
+                Ok(valid) => {
+                    res.push_str(valid);
+                    return res;
+                }
+                Err(e) => {
+                    let (valid, rest) = v.split_at(e.valid_up_to());
+                    // SAFETY: `from_utf8` validated the bytes up to `valid_up_to`.
+                    res.push_str(unsafe { str::from_utf8_unchecked(valid) });
+                    res.push(char::REPLACEMENT_CHARACTER);
+                    v = &rest[e.error_len().unwrap_or(rest.len())..];
+                }
+            }
+        }
+    }
+
+    #[kani::proof]
+    #[kani::unwind(8)]
+    fn check_from_utf8_lossy() {
+        let bytes: [u8; LOSSY_BYTES] = kani::any();
+        let v = kani::slice::any_slice_of_array(&bytes);
+        let lossy = String::from_utf8_lossy(v);
+        let expected = lossy_reference(v);
+        assert_eq!(lossy.len(), expected.len());
+        assert_eq!(*lossy, *expected);
+        match lossy {
+            Cow::Borrowed(s) => assert_eq!(s.as_ptr(), v.as_ptr()),
+            Cow::Owned(_) => assert!(str::from_utf8(v).is_err()),
+        }
+    }
+}
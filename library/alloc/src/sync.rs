@@ -17,6 +17,8 @@ use core::hash::{Hash, Hasher};
 use core::intrinsics::abort;
 #[cfg(not(no_global_oom_handling))]
 use core::iter;
+#[cfg(kani)]
+use core::kani;
 use core::marker::{PhantomData, Unsize};
 use core::mem::{self, ManuallyDrop, align_of_val_raw};
 use core::num::NonZeroUsize;
@@ -30,6 +32,8 @@ use core::sync::atomic::Ordering::{Acquire, Relaxed, Release};
 use core::sync::atomic::{self, Atomic};
 use core::{borrow, fmt, hint};
 
+use safety::{ensures, requires};
+
 #[cfg(not(no_global_oom_handling))]
 use crate::alloc::handle_alloc_error;
 use crate::alloc::{AllocError, Allocator, Global, Layout};
@@ -1732,6 +1736,9 @@ impl<T: ?Sized, A: Allocator> Arc<T, A> {
     #[must_use = "this returns a new `Weak` pointer, \
                   without modifying the original `Arc`"]
     #[stable(feature = "arc_weak", since = "1.4.0")]
+    #[ensures(|weak| ptr::addr_eq(weak.ptr.as_ptr(), this.ptr.as_ptr()))]
+    #[ensures(|_| Arc::weak_count(this) == old(Arc::weak_count(this)) + 1)]
+    #[cfg_attr(kani, kani::modifies(&this.inner().weak))]
     pub fn downgrade(this: &Self) -> Weak<T, A>
     where
         A: Clone,
@@ -2708,6 +2715,14 @@ impl<A: Allocator> Arc<dyn Any + Send + Sync, A> {
     /// ```
     #[inline]
     #[stable(feature = "rc_downcast", since = "1.29.0")]
+    #[ensures(|result| result.is_ok() == old((*self).is::<T>()))]
+    #[ensures(|result| {
+        let (ptr, strong) = match result {
+            Ok(this) => (this.ptr.as_ptr() as *const (), Arc::strong_count(this)),
+            Err(this) => (this.ptr.as_ptr() as *const (), Arc::strong_count(this)),
+        };
+        ptr == old(self.ptr.as_ptr() as *const ()) && strong == old(Arc::strong_count(&self))
+    })]
     pub fn downcast<T>(self) -> Result<Arc<T, A>, Self>
     where
         T: Any + Send + Sync,
@@ -2750,6 +2765,9 @@ impl<A: Allocator> Arc<dyn Any + Send + Sync, A> {
     /// [`downcast`]: Self::downcast
     #[inline]
     #[unstable(feature = "downcast_unchecked", issue = "90850")]
+    #[requires((*self).is::<T>())]
+    #[ensures(|this| this.ptr.as_ptr() as *const () == old(self.ptr.as_ptr() as *const ()))]
+    #[ensures(|this| Arc::strong_count(this) == old(Arc::strong_count(&self)))]
     pub unsafe fn downcast_unchecked<T>(self) -> Arc<T, A>
     where
         T: Any + Send + Sync,
@@ -3078,6 +3096,9 @@ impl<T: ?Sized, A: Allocator> Weak<T, A> {
     #[must_use = "this returns a new `Arc`, \
                   without modifying the original weak pointer"]
     #[stable(feature = "arc_weak", since = "1.4.0")]
+    #[ensures(|result| result.is_some() == (old(self.strong_count()) > 0))]
+    #[ensures(|result| self.strong_count() == old(self.strong_count()) + result.is_some() as usize)]
+    #[cfg_attr(kani, kani::modifies(self.ptr.as_ptr()))]
     pub fn upgrade(&self) -> Option<Arc<T, A>>
     where
         A: Clone,
@@ -4528,3 +4549,229 @@ unsafe impl<#[may_dangle] T: ?Sized, A: Allocator> Drop for UniqueArc<T, A> {
         unsafe { ptr::drop_in_place(&mut (*self.ptr.as_ptr()).data) };
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // `Arc`/`Weak` proofs
+
+    static mut DATA_DROPS: usize = 0;
+    static mut DEALLOCS: usize = 0;
+
+    /// Payload that counts how often it has been dropped.
+    struct Tracked;
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            unsafe { DATA_DROPS += 1 };
+        }
+    }
+
+    /// Allocator that counts how often an `ArcInner` has been freed.
+    #[derive(Clone)]
+    struct CountingAlloc;
+
+    unsafe impl Allocator for CountingAlloc {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            Global.allocate(layout)
+        }
+
+        unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+            unsafe {
+                DEALLOCS += 1;
+                Global.deallocate(ptr, layout);
+            }
+        }
+    }
+
+    #[kani::proof_for_contract(Arc::downgrade)]
+    fn check_downgrade() {
+        let arc = Arc::new_in(Tracked, CountingAlloc);
+        let _weak = if kani::any() { Some(Arc::downgrade(&arc)) } else { None };
+        let _weak = Arc::downgrade(&arc);
+    }
+
+    #[kani::proof_for_contract(Weak::upgrade)]
+    fn check_upgrade() {
+        let arc = Arc::new_in(Tracked, CountingAlloc);
+        let weak = Arc::downgrade(&arc);
+        let _other = if kani::any() { Some(arc.clone()) } else { None };
+        if kani::any() {
+            drop(arc);
+        }
+        let _upgraded = weak.upgrade();
+    }
+
+    /// A reference held by one symbolic thread.
+    enum Handle {
+        Empty,
+        Strong(Arc<Tracked, CountingAlloc>),
+        Weak(Weak<Tracked, CountingAlloc>),
+    }
+
+    const THREADS: usize = 2;
+    const SLOTS: usize = 2;
+    const STEPS: usize = 4;
+
+    /// Bounded sequences of `Arc`/`Weak` operations on handles held by symbolic threads.
+    ///
+    /// Kani cannot spawn threads, and the operations run to completion one after
+    /// the other: every step, a nondeterministically chosen thread performs a
+    /// nondeterministically chosen clone, drop, downgrade or upgrade on one of
+    /// its handles. This covers every order in which the threads can complete
+    /// their operations, but not operations overlapping between their atomic
+    /// accesses. Ghost counts of the live handles are kept alongside and checked
+    /// against the protocol after every step.
+    struct OpSequence {
+        threads: [[Handle; SLOTS]; THREADS],
+        strong: usize,
+        weak: usize,
+    }
+
+    impl OpSequence {
+        fn new() -> OpSequence {
+            let arc = Arc::new_in(Tracked, CountingAlloc);
+            let other = if kani::any() {
+                Handle::Strong(arc.clone())
+            } else {
+                Handle::Weak(Arc::downgrade(&arc))
+            };
+            let (strong, weak) = if let Handle::Strong(_) = other { (2, 0) } else { (1, 1) };
+            OpSequence {
+                threads: [[Handle::Strong(arc), Handle::Empty], [other, Handle::Empty]],
+                strong,
+                weak,
+            }
+        }
+
+        /// Performs one symbolic operation on one handle of `thread`.
+        fn step(&mut self, thread: usize) {
+            let [a, b] = &mut self.threads[thread];
+            let (src, dst) = if kani::any() { (a, b) } else { (b, a) };
+            let dst_empty = matches!(dst, Handle::Empty);
+            match kani::any::<u8>() % 4 {
+                // Clone
+                0 if dst_empty => match src {
+                    Handle::Strong(arc) => {
+                        *dst = Handle::Strong(Arc::clone(arc));
+                        self.strong += 1;
+                    }
+                    Handle::Weak(weak) => {
+                        *dst = Handle::Weak(weak.clone());
+                        self.weak += 1;
+                    }
+                    Handle::Empty => {}
+                },
+                // Drop
+                1 => match mem::replace(src, Handle::Empty) {
+                    Handle::Strong(arc) => {
+                        drop(arc);
+                        self.strong -= 1;
+                    }
+                    Handle::Weak(weak) => {
+                        drop(weak);
+                        self.weak -= 1;
+                    }
+                    Handle::Empty => {}
+                },
+                // Downgrade
+                2 if dst_empty => {
+                    if let Handle::Strong(arc) = src {
+                        *dst = Handle::Weak(Arc::downgrade(arc));
+                        self.weak += 1;
+                    }
+                }
+                // Upgrade
+                3 if dst_empty => {
+                    if let Handle::Weak(weak) = src {
+                        let upgraded = weak.upgrade();
+                        assert_eq!(upgraded.is_some(), self.strong > 0);
+                        if let Some(arc) = upgraded {
+                            *dst = Handle::Strong(arc);
+                            self.strong += 1;
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        /// Checks the observable counts and the drop/deallocation protocol.
+        fn check(&self) {
+            for handle in self.threads.iter().flatten() {
+                match handle {
+                    Handle::Strong(arc) => {
+                        assert_eq!(Arc::strong_count(arc), self.strong);
+                        assert_eq!(Arc::weak_count(arc), self.weak);
+                    }
+                    Handle::Weak(weak) => assert_eq!(weak.strong_count(), self.strong),
+                    Handle::Empty => {}
+                }
+            }
+            // The last strong drop drops the data, exactly once.
+            assert_eq!(unsafe { DATA_DROPS }, (self.strong == 0) as usize);
+            // The last weak drop (counting the implicit one held by the strong
+            // references) deallocates the `ArcInner`, exactly once.
+            assert_eq!(unsafe { DEALLOCS }, (self.strong == 0 && self.weak == 0) as usize);
+        }
+    }
+
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_sequenced_clone_drop_upgrade() {
+        let mut model = OpSequence::new();
+        model.check();
+        for _ in 0..STEPS {
+            let thread: usize = kani::any_where(|t: &usize| *t < THREADS);
+            model.step(thread);
+            model.check();
+        }
+        // Tearing down the remaining handles completes the protocol.
+        let OpSequence { threads, .. } = model;
+        drop(threads);
+        assert_eq!(unsafe { DATA_DROPS }, 1);
+        assert_eq!(unsafe { DEALLOCS }, 1);
+    }
+
+    // `Arc<dyn Any + Send + Sync>` downcast proofs
+
+    /// An `Arc` holding either a `u8` or a `u16`, possibly shared.
+    fn any_arc() -> (Arc<dyn Any + Send + Sync>, Option<Arc<dyn Any + Send + Sync>>) {
+        let arc: Arc<dyn Any + Send + Sync> =
+            if kani::any() { Arc::new(kani::any::<u8>()) } else { Arc::new(kani::any::<u16>()) };
+        let other = if kani::any() { Some(arc.clone()) } else { None };
+        (arc, other)
+    }
+
+    #[kani::proof_for_contract(Arc::downcast)]
+    fn check_downcast() {
+        let (arc, _other) = any_arc();
+        let _ = arc.downcast::<u8>();
+    }
+
+    #[kani::proof_for_contract(Arc::downcast_unchecked)]
+    fn check_downcast_unchecked() {
+        let (arc, _other) = any_arc();
+        let _ = unsafe { arc.downcast_unchecked::<u8>() };
+    }
+
+    // A successful downcast reads back the stored value; a mismatched one hands back the
+    // original `Arc` untouched.
+    #[kani::proof]
+    fn check_downcast_value() {
+        let value: u8 = kani::any();
+        let arc: Arc<dyn Any + Send + Sync> = Arc::new(value);
+        let arc = arc.downcast::<u16>().unwrap_err();
+        assert_eq!(*arc.downcast::<u8>().unwrap(), value);
+    }
+
+    #[kani::proof]
+    #[kani::stub_verified(Arc::downcast_unchecked)]
+    #[kani::should_panic]
+    fn check_downcast_unchecked_mismatch() {
+        let arc: Arc<dyn Any + Send + Sync> = Arc::new(kani::any::<u16>());
+        let _ = unsafe { arc.downcast_unchecked::<u8>() };
+    }
+}
@@ -0,0 +1,207 @@
+#[cfg(kani)]
+use core::kani;
+use core::ptr;
+#[cfg(kani)]
+use core::ub_checks::Invariant;
+
+use safety::{ensures, requires};
+
+use super::Vec;
+use crate::alloc::Allocator;
+
+// Hole-tracking state of `Vec::retain_mut`.
+//
+// Vec: [Kept, Kept, Hole, Hole, Hole, Hole, Unchecked, Unchecked]
+//      |<-              processed len   ->| ^- next to check
+//                  |<-  deleted cnt     ->|
+//      |<-              original_len                          ->|
+// Kept: Elements which predicate returns true on.
+// Hole: Moved or dropped element slot.
+// Unchecked: Unchecked valid elements.
+//
+// This drop guard will be invoked when predicate or `drop` of element panicked.
+// It shifts unchecked elements to cover holes and `set_len` to the correct length.
+// In cases when predicate and `drop` never panick, it will be optimized out.
+pub(super) struct BackshiftOnDrop<'a, T, A: Allocator> {
+    pub(super) v: &'a mut Vec<T, A>,
+    pub(super) processed_len: usize,
+    pub(super) deleted_cnt: usize,
+    pub(super) original_len: usize,
+}
+
+/// The counters stay within the original elements, and the vector's own length
+/// stays zero so that nothing is dropped twice if the guard is leaked.
+#[cfg(kani)]
+#[unstable(feature = "ub_checks", issue = "none")]
+impl<T, A: Allocator> Invariant for BackshiftOnDrop<'_, T, A> {
+    fn is_safe(&self) -> bool {
+        self.deleted_cnt <= self.processed_len
+            && self.processed_len <= self.original_len
+            && self.original_len <= self.v.capacity()
+            && self.v.len() == 0
+    }
+}
+
+impl<T, A: Allocator> Drop for BackshiftOnDrop<'_, T, A> {
+    fn drop(&mut self) {
+        if self.deleted_cnt > 0 {
+            // SAFETY: Trailing unchecked items must be valid since we never touch them.
+            unsafe {
+                ptr::copy(
+                    self.v.as_ptr().add(self.processed_len),
+                    self.v.as_mut_ptr().add(self.processed_len - self.deleted_cnt),
+                    self.original_len - self.processed_len,
+                );
+            }
+        }
+        // SAFETY: After filling holes, all items are in contiguous memory.
+        unsafe {
+            self.v.set_len(self.original_len - self.deleted_cnt);
+        }
+    }
+}
+
+/// Runs the predicate over the unchecked elements up to `original_len`. Without
+/// `DELETED`, stops right after the first deletion so that the caller can switch
+/// to the loop that moves kept elements into the holes.
+#[requires(g.is_safe() && original_len <= g.original_len && g.processed_len <= original_len)]
+#[requires(DELETED || g.deleted_cnt == 0)]
+#[requires(!DELETED || g.deleted_cnt > 0 || g.processed_len == original_len)]
+#[ensures(|_| g.is_safe() && g.deleted_cnt >= old(g.deleted_cnt))]
+#[ensures(|_| g.processed_len == original_len || (!DELETED && g.deleted_cnt == 1))]
+#[ensures(|_| g.processed_len - g.deleted_cnt >= old(g.processed_len - g.deleted_cnt))]
+#[cfg_attr(kani, kani::modifies(g, f, g.elements()))]
+pub(super) fn process_loop<F, T, A: Allocator, const DELETED: bool>(
+    original_len: usize,
+    f: &mut F,
+    g: &mut BackshiftOnDrop<'_, T, A>,
+) where
+    F: FnMut(&mut T) -> bool,
+{
+    while g.processed_len != original_len {
+        // SAFETY: Unchecked element must be valid.
+        let cur = unsafe { &mut *g.v.as_mut_ptr().add(g.processed_len) };
+        if !f(cur) {
+            // Advance early to avoid double drop if `drop_in_place` panicked.
+            g.processed_len += 1;
+            g.deleted_cnt += 1;
+            // SAFETY: We never touch this element again after dropped.
+            unsafe { ptr::drop_in_place(cur) };
+            // We already advanced the counter.
+            if DELETED {
+                continue;
+            } else {
+                break;
+            }
+        }
+        if DELETED {
+            // SAFETY: `deleted_cnt` > 0, so the hole slot must not overlap with current element.
+            // We use copy for move, and never touch this element again.
+            unsafe {
+                let hole_slot = g.v.as_mut_ptr().add(g.processed_len - g.deleted_cnt);
+                ptr::copy_nonoverlapping(cur, hole_slot, 1);
+            }
+        }
+        g.processed_len += 1;
+    }
+}
+
+#[cfg(kani)]
+impl<T, A: Allocator> BackshiftOnDrop<'_, T, A> {
+    /// The original elements of the vector, holes included.
+    fn elements(&self) -> *mut [T] {
+        ptr::slice_from_raw_parts_mut(self.v.as_ptr().cast_mut(), self.original_len)
+    }
+}
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::super::drop_tracking::{Tracked, drops};
+    use super::*;
+
+    // `process_loop` proofs
+
+    const LEN: usize = 4;
+
+    #[kani::proof_for_contract(process_loop)]
+    #[kani::unwind(5)]
+    fn check_process_loop() {
+        let mut vec: Vec<u8> = Vec::from(kani::any::<[u8; LEN]>());
+        let keep: [bool; LEN] = kani::any();
+        let original_len = kani::any_where(|&n: &usize| n <= LEN);
+        unsafe { vec.set_len(0) };
+        let mut g = BackshiftOnDrop { v: &mut vec, processed_len: 0, deleted_cnt: 0, original_len };
+        // The predicate keeps its state inside the closure, which the contract
+        // allows it to modify.
+        let mut i = 0;
+        let mut f = move |_: &mut u8| {
+            i += 1;
+            keep[i - 1]
+        };
+        process_loop::<_, _, _, false>(original_len, &mut f, &mut g);
+        process_loop::<_, _, _, true>(original_len, &mut f, &mut g);
+    }
+
+    /// Runs `retain_mut`'s two stages with a symbolic keep-predicate that stops
+    /// being consulted after `stop` elements, which is the state `retain_mut` is
+    /// left in when the predicate panics on element `stop`. The guard must then
+    /// keep exactly the checked elements it accepted, followed by the unchecked
+    /// ones, in their original order.
+    fn retain_until(stop: usize) {
+        let mut vec: Vec<Tracked> = (0..LEN).map(Tracked).collect();
+        let keep: [bool; LEN] = kani::any();
+        unsafe { vec.set_len(0) };
+        let mut g =
+            BackshiftOnDrop { v: &mut vec, processed_len: 0, deleted_cnt: 0, original_len: LEN };
+        let mut f = |e: &mut Tracked| keep[e.0];
+        process_loop::<_, _, _, false>(stop, &mut f, &mut g);
+        process_loop::<_, _, _, true>(stop, &mut f, &mut g);
+        drop(g);
+
+        let mut expected = (0..LEN).filter(|&i| i >= stop || keep[i]);
+        for elem in &vec {
+            assert_eq!(Some(elem.0), expected.next());
+        }
+        assert_eq!(expected.next(), None);
+        for i in 0..LEN {
+            assert_eq!(drops(i), (i < stop && !keep[i]) as u8);
+        }
+        drop(vec);
+        for i in 0..LEN {
+            assert_eq!(drops(i), 1);
+        }
+    }
+
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_retain_complete() {
+        retain_until(LEN);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_retain_interrupted() {
+        retain_until(kani::any_where(|&n: &usize| n < LEN));
+    }
+
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_retain_mut() {
+        let arr: [u8; LEN] = kani::any();
+        let keep: [bool; LEN] = kani::any();
+        let mut vec = Vec::from(arr);
+        let mut i = 0;
+        vec.retain_mut(|e| {
+            let kept = keep[i];
+            *e = e.wrapping_add(1);
+            i += 1;
+            kept
+        });
+        let mut expected = (0..LEN).filter(|&i| keep[i]).map(|i| arr[i].wrapping_add(1));
+        for elem in &vec {
+            assert_eq!(Some(*elem), expected.next());
+        }
+        assert_eq!(expected.next(), None);
+    }
+}
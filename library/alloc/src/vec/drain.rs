@@ -1,8 +1,12 @@
 use core::fmt;
 use core::iter::{FusedIterator, TrustedLen};
+#[cfg(kani)]
+use core::kani;
 use core::mem::{self, ManuallyDrop, SizedTypeProperties};
 use core::ptr::{self, NonNull};
 use core::slice::{self};
+#[cfg(kani)]
+use core::ub_checks::Invariant;
 
 use super::Vec;
 use crate::alloc::{Allocator, Global};
@@ -136,6 +140,37 @@ impl<'a, T, A: Allocator> Drain<'a, T, A> {
     }
 }
 
+#[cfg(kani)]
+impl<'a, T, A: Allocator> Drain<'a, T, A> {
+    /// The whole buffer of the source vector, including its spare capacity.
+    pub(super) fn buffer(&self) -> *mut [T] {
+        let vec = unsafe { self.vec.as_ref() };
+        ptr::slice_from_raw_parts_mut(vec.as_ptr().cast_mut(), vec.capacity())
+    }
+
+    /// The length of the source vector, i.e. the end of the kept head.
+    pub(super) fn head_len(&self) -> usize {
+        unsafe { self.vec.as_ref() }.len
+    }
+}
+
+/// The source vector is split into `[head] [hole] [tail]`: the head ends at the
+/// vector's length, the unyielded elements lie inside the hole, and the tail
+/// starts at `tail_start` and fits in the buffer.
+#[cfg(kani)]
+#[unstable(feature = "ub_checks", issue = "none")]
+impl<'a, T, A: Allocator> Invariant for Drain<'a, T, A> {
+    fn is_safe(&self) -> bool {
+        let head_len = self.head_len();
+        head_len <= self.tail_start
+            && self.iter.len() <= self.tail_start - head_len
+            && self
+                .tail_start
+                .checked_add(self.tail_len)
+                .is_some_and(|end| end <= unsafe { self.vec.as_ref() }.capacity())
+    }
+}
+
 #[stable(feature = "vec_drain_as_slice", since = "1.46.0")]
 impl<'a, T, A: Allocator> AsRef<[T]> for Drain<'a, T, A> {
     fn as_ref(&self) -> &[T] {
@@ -251,3 +286,130 @@ unsafe impl<T, A: Allocator> TrustedLen for Drain<'_, T, A> {}
 
 #[stable(feature = "fused", since = "1.26.0")]
 impl<T, A: Allocator> FusedIterator for Drain<'_, T, A> {}
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::super::drop_tracking::{Tracked, drops};
+    use super::*;
+
+    const LEN: usize = 4;
+
+    fn tracked_vec() -> Vec<Tracked> {
+        vec![Tracked(0), Tracked(1), Tracked(2), Tracked(3)]
+    }
+
+    fn any_range() -> (usize, usize) {
+        let end = kani::any_where(|&x: &usize| x <= LEN);
+        let start = kani::any_where(|&x: &usize| x <= end);
+        (start, end)
+    }
+
+    /// Yields a symbolic number of elements from either end of `drain`,
+    /// dropping each one right away.
+    fn yield_some(drain: &mut Drain<'_, Tracked>) {
+        for _ in 0..LEN {
+            if kani::any() {
+                let _ = if kani::any() { drain.next() } else { drain.next_back() };
+            }
+        }
+    }
+
+    // Dropping the drain leaves exactly the elements outside the range, in
+    // order, and drops every drained element exactly once.
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_drain_drop() {
+        let mut vec = tracked_vec();
+        let (start, end) = any_range();
+        let mut drain = vec.drain(start..end);
+        assert!(drain.is_safe());
+        yield_some(&mut drain);
+        assert!(drain.is_safe());
+        drop(drain);
+
+        assert_eq!(vec.len(), LEN - (end - start));
+        for (i, elem) in vec.iter().enumerate() {
+            assert_eq!(elem.0, if i < start { i } else { i + end - start });
+        }
+        for i in 0..LEN {
+            assert_eq!(drops(i), (start <= i && i < end) as u8);
+        }
+        drop(vec);
+        for i in 0..LEN {
+            assert_eq!(drops(i), 1);
+        }
+    }
+
+    // `keep_rest` keeps the unyielded elements in place of the drained range.
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_keep_rest() {
+        let mut vec = tracked_vec();
+        let (start, end) = any_range();
+        let mut drain = vec.drain(start..end);
+        yield_some(&mut drain);
+        let unyielded = drain.as_slice();
+        let (first, kept) = (unyielded.first().map_or(0, |e| e.0), unyielded.len());
+        drain.keep_rest();
+
+        assert_eq!(vec.len(), LEN - (end - start) + kept);
+        for (i, elem) in vec.iter().enumerate() {
+            let expected = if i < start {
+                i
+            } else if i < start + kept {
+                first + (i - start)
+            } else {
+                i - kept + end - start
+            };
+            assert_eq!(elem.0, expected);
+        }
+        for i in 0..LEN {
+            let yielded = start <= i && i < end && !(first <= i && i < first + kept);
+            assert_eq!(drops(i), yielded as u8);
+        }
+    }
+
+    // Leaking the drain leaks the drained range and the tail, but leaves the
+    // head intact and never drops anything twice.
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_drain_forget() {
+        let mut vec = tracked_vec();
+        let (start, end) = any_range();
+        let mut drain = vec.drain(start..end);
+        yield_some(&mut drain);
+        let unyielded = drain.as_slice();
+        let (first, kept) = (unyielded.first().map_or(0, |e| e.0), unyielded.len());
+        mem::forget(drain);
+
+        assert_eq!(vec.len(), start);
+        for (i, elem) in vec.iter().enumerate() {
+            assert_eq!(elem.0, i);
+        }
+        drop(vec);
+        for i in 0..LEN {
+            let yielded = start <= i && i < end && !(first <= i && i < first + kept);
+            assert_eq!(drops(i), (i < start || yielded) as u8);
+        }
+    }
+
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_drain_zst() {
+        let mut vec = vec![(); LEN];
+        let (start, end) = any_range();
+        let mut drain = vec.drain(start..end);
+        if kani::any() {
+            drain.next();
+        }
+        let kept = drain.len();
+        let keep_rest: bool = kani::any();
+        if keep_rest {
+            drain.keep_rest();
+        } else {
+            drop(drain);
+        }
+        assert_eq!(vec.len(), LEN - (end - start) + if keep_rest { kept } else { 0 });
+    }
+}
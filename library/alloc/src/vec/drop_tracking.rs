@@ -0,0 +1,20 @@
+//! Elements that record their drops, shared by the proofs of the `vec` modules.
+
+/// Number of distinct elements that can be tracked.
+const TRACKED: usize = 4;
+
+static mut DROPS: [u8; TRACKED] = [0; TRACKED];
+
+/// Element that records how often each index has been dropped.
+pub(super) struct Tracked(pub(super) usize);
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        unsafe { (*&raw mut DROPS)[self.0] += 1 };
+    }
+}
+
+/// Returns how often the element with index `i` has been dropped.
+pub(super) fn drops(i: usize) -> u8 {
+    unsafe { (*&raw const DROPS)[i] }
+}
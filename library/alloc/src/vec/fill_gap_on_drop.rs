@@ -0,0 +1,180 @@
+#[cfg(kani)]
+use core::kani;
+use core::ptr;
+#[cfg(kani)]
+use core::ub_checks::Invariant;
+
+use safety::{ensures, requires};
+
+use super::Vec;
+use crate::alloc::Allocator;
+
+/* INVARIANT: vec.len() > read > write > write-1 >= 0 */
+pub(super) struct FillGapOnDrop<'a, T, A: Allocator> {
+    /* Offset of the element we want to check if it is duplicate */
+    pub(super) read: usize,
+
+    /* Offset of the place where we want to place the non-duplicate
+     * when we find it. */
+    pub(super) write: usize,
+
+    /* The Vec that would need correction if `same_bucket` panicked */
+    pub(super) vec: &'a mut Vec<T, A>,
+}
+
+/// `read` may reach `vec.len()` once every element has been checked.
+#[cfg(kani)]
+#[unstable(feature = "ub_checks", issue = "none")]
+impl<T, A: Allocator> Invariant for FillGapOnDrop<'_, T, A> {
+    fn is_safe(&self) -> bool {
+        0 < self.write && self.write < self.read && self.read <= self.vec.len()
+    }
+}
+
+impl<T, A: Allocator> FillGapOnDrop<'_, T, A> {
+    /// Moves the unchecked elements `vec[read..]` down to `write`, closing the
+    /// gap of moved-out and dropped elements, and shortens the vector to match.
+    ///
+    /// # Safety
+    ///
+    /// `vec[..write]` and `vec[read..]` must be valid elements, and everything
+    /// in between must have been moved out or dropped.
+    #[requires(self.is_safe())]
+    #[ensures(|_| self.vec.len() == old(self.vec.len() - (self.read - self.write)))]
+    #[cfg_attr(kani, kani::modifies(self.vec, self.elements()))]
+    pub(super) unsafe fn fill_gap(&mut self) {
+        /* SAFETY: invariant guarantees that `read - write`
+         * and `len - read` never overflow and that the copy is always
+         * in-bounds. */
+        unsafe {
+            let ptr = self.vec.as_mut_ptr();
+            let len = self.vec.len();
+
+            /* How many items were left when `same_bucket` panicked.
+             * Basically vec[read..].len() */
+            let items_left = len.wrapping_sub(self.read);
+
+            /* Pointer to first item in vec[write..write+items_left] slice */
+            let dropped_ptr = ptr.add(self.write);
+            /* Pointer to first item in vec[read..] slice */
+            let valid_ptr = ptr.add(self.read);
+
+            /* Copy `vec[read..]` to `vec[write..write+items_left]`.
+             * The slices can overlap, so `copy_nonoverlapping` cannot be used */
+            ptr::copy(valid_ptr, dropped_ptr, items_left);
+
+            /* How many items have been already dropped
+             * Basically vec[read..write].len() */
+            let dropped = self.read.wrapping_sub(self.write);
+
+            self.vec.set_len(len - dropped);
+        }
+    }
+
+    #[cfg(kani)]
+    fn elements(&self) -> *mut [T] {
+        ptr::slice_from_raw_parts_mut(self.vec.as_ptr().cast_mut(), self.vec.len())
+    }
+}
+
+impl<'a, T, A: Allocator> Drop for FillGapOnDrop<'a, T, A> {
+    fn drop(&mut self) {
+        /* This code gets executed when `same_bucket` panics */
+
+        /* SAFETY: `dedup_by` upholds the invariant while the guard is alive. */
+        unsafe { self.fill_gap() };
+    }
+}
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use core::mem;
+
+    use super::super::drop_tracking::{Tracked, drops};
+    use super::*;
+
+    // `fill_gap` proofs
+
+    const LEN: usize = 4;
+
+    fn any_gap() -> (usize, usize) {
+        let read = kani::any_where(|&r: &usize| 1 < r && r <= LEN);
+        let write = kani::any_where(|&w: &usize| 0 < w && w < read);
+        (read, write)
+    }
+
+    #[kani::proof_for_contract(FillGapOnDrop::fill_gap)]
+    fn check_fill_gap() {
+        let mut vec = Vec::from(kani::any::<[u8; LEN]>());
+        let (read, write) = any_gap();
+        let mut gap = FillGapOnDrop { read, write, vec: &mut vec };
+        unsafe { gap.fill_gap() };
+        mem::forget(gap);
+    }
+
+    // State left behind when `same_bucket` panics while checking `vec[read]`:
+    // everything in `vec[write..read]` has been dropped (or moved out, which is
+    // modelled the same way), and dropping the guard must keep exactly the rest,
+    // in order, without dropping anything twice.
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_drop_after_panic() {
+        let mut vec: Vec<Tracked> = (0..LEN).map(Tracked).collect();
+        let (read, write) = any_gap();
+        for i in write..read {
+            unsafe { ptr::drop_in_place(vec.as_mut_ptr().add(i)) };
+        }
+        drop(FillGapOnDrop { read, write, vec: &mut vec });
+
+        assert_eq!(vec.len(), LEN - (read - write));
+        for (i, elem) in vec.iter().enumerate() {
+            assert_eq!(elem.0, if i < write { i } else { i + read - write });
+        }
+        drop(vec);
+        for i in 0..LEN {
+            assert_eq!(drops(i), 1);
+        }
+    }
+
+    /// Whether `sub` can be obtained from `full` by removing elements.
+    fn is_subsequence(sub: &[u8], full: &[u8]) -> bool {
+        let mut full = full.iter();
+        sub.iter().all(|x| full.any(|y| x == y))
+    }
+
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_dedup() {
+        let arr: [u8; LEN] = kani::any();
+        let len = kani::any_where(|&n: &usize| n <= LEN);
+        let mut vec = Vec::from(&arr[..len]);
+        vec.dedup();
+
+        assert!(vec.windows(2).all(|w| w[0] != w[1]));
+        assert!(is_subsequence(&vec, &arr[..len]));
+        // Every run of equal elements is kept exactly once.
+        let runs = (0..len).filter(|&i| i == 0 || arr[i] != arr[i - 1]).count();
+        assert_eq!(vec.len(), runs);
+    }
+
+    // With an arbitrary `same_bucket`, the first element is always kept and
+    // every other element is kept exactly when it is not reported as a
+    // duplicate of the last kept one.
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_dedup_by() {
+        let arr: [u8; LEN] = kani::any();
+        let answers: [bool; LEN] = kani::any();
+        let mut vec = Vec::from(arr);
+        let mut calls = 0;
+        vec.dedup_by(|_, _| {
+            calls += 1;
+            answers[calls]
+        });
+
+        assert_eq!(calls, LEN - 1);
+        let expected = (0..LEN).filter(|&i| i == 0 || !answers[i]).map(|i| arr[i]);
+        assert!(vec.iter().copied().eq(expected));
+    }
+}
@@ -157,14 +157,21 @@
 
 use core::alloc::{Allocator, Layout};
 use core::iter::{InPlaceIterable, SourceIter, TrustedRandomAccessNoCoerce};
+#[cfg(kani)]
+use core::kani;
 use core::marker::PhantomData;
 use core::mem::{self, ManuallyDrop, SizedTypeProperties};
 use core::num::NonZero;
 use core::ptr;
 
+use safety::{ensures, requires};
+
 use super::{InPlaceDrop, InPlaceDstDataSrcBufDrop, SpecFromIter, SpecFromIterNested, Vec};
 use crate::alloc::{Global, handle_alloc_error};
 
+#[ensures(|result| !*result || (!SRC::IS_ZST && !DEST::IS_ZST))]
+#[ensures(|result| !*result || align_of::<SRC>() == align_of::<DEST>())]
+#[ensures(|result| !*result || step_merge.is_some() && step_expand.is_some())]
 const fn in_place_collectible<DEST, SRC>(
     step_merge: Option<NonZero<usize>>,
     step_expand: Option<NonZero<usize>>,
@@ -189,6 +196,10 @@ const fn in_place_collectible<DEST, SRC>(
     }
 }
 
+#[requires(align_of::<SRC>() == align_of::<DEST>() && !SRC::IS_ZST && !DEST::IS_ZST)]
+#[requires(src_cap.checked_mul(size_of::<SRC>()).is_some_and(|size| size <= isize::MAX as usize))]
+#[requires(dst_cap == src_cap * size_of::<SRC>() / size_of::<DEST>())]
+#[ensures(|result| *result == (src_cap * size_of::<SRC>() != dst_cap * size_of::<DEST>()))]
 const fn needs_realloc<SRC, DEST>(src_cap: usize, dst_cap: usize) -> bool {
     if const { align_of::<SRC>() != align_of::<DEST>() } {
         // FIXME(const-hack): use unreachable! once that works in const
@@ -248,6 +259,7 @@ where
 }
 
 #[track_caller]
+#[ensures(|vec| vec.len() <= vec.capacity())]
 fn from_iter_in_place<I, T>(mut iterator: I) -> Vec<T>
 where
     I: Iterator<Item = T> + InPlaceCollect,
@@ -371,23 +383,37 @@ where
 {
     #[inline]
     default unsafe fn collect_in_place(&mut self, dst_buf: *mut T, end: *const T) -> usize {
-        // use try-fold since
-        // - it vectorizes better for some iterator adapters
-        // - unlike most internal iteration methods, it only takes a &mut self
-        // - it lets us thread the write pointer through its innards and get it back in the end
-        let sink = InPlaceDrop { inner: dst_buf, dst: dst_buf };
-        let sink =
-            self.try_fold::<_, _, Result<_, !>>(sink, write_in_place_with_drop(end)).into_ok();
-        // iteration succeeded, don't drop head
-        unsafe { ManuallyDrop::new(sink).dst.offset_from_unsigned(dst_buf) }
+        // SAFETY: the caller has to uphold the safety contract for `collect_in_place`.
+        unsafe { collect_in_place_try_fold(self, dst_buf, end) }
     }
 }
 
+/// The default implementation of `collect_in_place`, outside of the specializable method so that
+/// it can carry a contract.
+#[inline]
+#[requires(dst_buf.cast_const() <= end)]
+#[ensures(|len| dst_buf.wrapping_add(*len).cast_const() <= end)]
+unsafe fn collect_in_place_try_fold<T, I>(iter: &mut I, dst_buf: *mut T, end: *const T) -> usize
+where
+    I: Iterator<Item = T>,
+{
+    // use try-fold since
+    // - it vectorizes better for some iterator adapters
+    // - unlike most internal iteration methods, it only takes a &mut self
+    // - it lets us thread the write pointer through its innards and get it back in the end
+    let sink = InPlaceDrop { inner: dst_buf, dst: dst_buf };
+    let sink = iter.try_fold::<_, _, Result<_, !>>(sink, write_in_place_with_drop(end)).into_ok();
+    // iteration succeeded, don't drop head
+    unsafe { ManuallyDrop::new(sink).dst.offset_from_unsigned(dst_buf) }
+}
+
 impl<T, I> SpecInPlaceCollect<T, I> for I
 where
     I: Iterator<Item = T> + TrustedRandomAccessNoCoerce,
 {
     #[inline]
+    #[requires(dst_buf.cast_const() <= end)]
+    #[ensures(|len| dst_buf.wrapping_add(*len).cast_const() <= end)]
     unsafe fn collect_in_place(&mut self, dst_buf: *mut T, end: *const T) -> usize {
         let len = self.size();
         let mut drop_guard = InPlaceDrop { inner: dst_buf, dst: dst_buf };
@@ -429,3 +455,158 @@ pub(crate) unsafe trait AsVecIntoIter {
     type Item;
     fn as_into_iter(&mut self) -> &mut super::IntoIter<Self::Item>;
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use core::any::type_name;
+    use core::iter::Map;
+
+    use super::super::IntoIter;
+    use super::super::drop_tracking::{Tracked, drops};
+    use super::*;
+
+    // Helper proofs
+
+    const LEN: usize = 3;
+
+    macro_rules! check_in_place_collectible {
+        ($($name:ident: $dest:ty, $src:ty;)+) => {$(
+            #[kani::proof_for_contract(in_place_collectible)]
+            fn $name() {
+                let _ = in_place_collectible::<$dest, $src>(kani::any(), kani::any());
+            }
+        )+};
+    }
+
+    check_in_place_collectible! {
+        check_in_place_collectible_same: u32, u32;
+        check_in_place_collectible_shrink: u32, (u32, u32);
+        check_in_place_collectible_align: u16, u32;
+        check_in_place_collectible_zst: (), u8;
+    }
+
+    macro_rules! check_needs_realloc {
+        ($($name:ident: $src:ty, $dest:ty;)+) => {$(
+            #[kani::proof_for_contract(needs_realloc)]
+            fn $name() {
+                let src_cap: usize = kani::any();
+                let dst_cap = src_cap.wrapping_mul(size_of::<$src>()) / size_of::<$dest>();
+                let _ = needs_realloc::<$src, $dest>(src_cap, dst_cap);
+            }
+        )+};
+    }
+
+    check_needs_realloc! {
+        check_needs_realloc_same: u32, u32;
+        check_needs_realloc_multiple: (u32, u32), u32;
+        check_needs_realloc_remainder: [u8; 3], [u8; 2];
+    }
+
+    #[kani::proof_for_contract(<Map<IntoIter<u32>, fn(u32) -> u32> as SpecInPlaceCollect<u32, Map<IntoIter<u32>, fn(u32) -> u32>>>::collect_in_place)]
+    #[kani::unwind(4)]
+    fn check_collect_in_place() {
+        let mut iter = Vec::from(kani::any::<[u32; LEN]>())
+            .into_iter()
+            .map(u32::wrapping_neg as fn(u32) -> u32);
+        let (buf, end) = unsafe {
+            let inner = iter.as_inner().as_into_iter();
+            (inner.buf.as_ptr(), inner.end)
+        };
+        let _ = unsafe { iter.collect_in_place(buf, end) };
+    }
+
+    #[kani::proof_for_contract(collect_in_place_try_fold)]
+    #[kani::unwind(4)]
+    fn check_collect_in_place_try_fold() {
+        let mut iter = Vec::from(kani::any::<[u32; LEN]>()).into_iter().filter(|x| x % 2 == 0);
+        let (buf, end) = unsafe {
+            let inner = iter.as_inner().as_into_iter();
+            (inner.buf.as_ptr(), inner.end)
+        };
+        let _ = unsafe { collect_in_place_try_fold(&mut iter, buf, end) };
+    }
+
+    #[kani::proof_for_contract(from_iter_in_place)]
+    #[kani::unwind(4)]
+    fn check_from_iter_in_place() {
+        let vec = Vec::from(kani::any::<[u32; LEN]>());
+        let _: Vec<u32> = vec.into_iter().map(|x| x.wrapping_add(1)).collect();
+    }
+
+    /// Collects `f` mapped over a partially consumed symbolic vector both in
+    /// place and with a plain push loop, and checks that the results agree and
+    /// that the source allocation was reused.
+    fn check_against_naive<S: kani::Arbitrary + Clone, D: PartialEq>(
+        f: impl Fn(S) -> Option<D> + Clone,
+    ) {
+        let len = kani::any_where(|&n: &usize| n <= LEN);
+        let src: Vec<S> = (0..len).map(|_| kani::any()).collect();
+        let skip = kani::any_where(|&n: &usize| n <= len);
+
+        let mut naive = Vec::new();
+        for x in src.iter().skip(skip).cloned() {
+            if let Some(y) = f(x) {
+                naive.push(y);
+            }
+        }
+
+        let (buf, cap) = (src.as_ptr() as usize, src.capacity() * size_of::<S>());
+        let mut iter = src.into_iter();
+        for _ in 0..skip {
+            iter.next();
+        }
+        let collected: Vec<D> = iter.filter_map(f).collect();
+
+        assert!(collected == naive, "{} -> {}", type_name::<S>(), type_name::<D>());
+        if cap > 0 {
+            // Only a byte size that `D` does not divide forces a (shrinking) realloc.
+            if cap % size_of::<D>() == 0 {
+                assert_eq!(collected.as_ptr() as usize, buf);
+            }
+            assert!(collected.capacity() * size_of::<D>() <= cap);
+            assert!(collected.capacity() * size_of::<D>() > cap - size_of::<D>());
+        }
+    }
+
+    #[kani::proof]
+    #[kani::unwind(4)]
+    fn check_map_same_layout() {
+        check_against_naive(|x: u32| Some(x.rotate_left(3) as i32));
+    }
+
+    #[kani::proof]
+    #[kani::unwind(4)]
+    fn check_filter() {
+        check_against_naive(|x: u32| (x % 2 == 0).then_some(x));
+    }
+
+    #[kani::proof]
+    #[kani::unwind(4)]
+    fn check_map_shrink() {
+        check_against_naive(|(a, b): (u32, u32)| Some(a ^ b));
+    }
+
+    #[kani::proof]
+    #[kani::unwind(4)]
+    fn check_map_shrink_realloc() {
+        check_against_naive(|[a, b, c]: [u8; 3]| (a != c).then_some([a, b]));
+    }
+
+    // Source elements that are never yielded are still dropped exactly once,
+    // after the collected elements have taken over their allocation.
+    #[kani::proof]
+    #[kani::unwind(4)]
+    fn check_take_drops_remaining() {
+        let src: Vec<Tracked> = (0..LEN).map(Tracked).collect();
+        let buf = src.as_ptr() as usize;
+        let take = kani::any_where(|&n: &usize| n <= LEN);
+        let collected: Vec<usize> = src.into_iter().take(take).map(|t| t.0).collect();
+
+        assert!(collected.iter().copied().eq(0..take));
+        assert_eq!(collected.as_ptr() as usize, buf);
+        for i in 0..LEN {
+            assert_eq!(drops(i), 1);
+        }
+    }
+}
@@ -2,6 +2,8 @@ use core::iter::{
     FusedIterator, InPlaceIterable, SourceIter, TrustedFused, TrustedLen,
     TrustedRandomAccessNoCoerce,
 };
+#[cfg(kani)]
+use core::kani;
 use core::marker::PhantomData;
 use core::mem::{ManuallyDrop, MaybeUninit, SizedTypeProperties};
 use core::num::NonZero;
@@ -9,8 +11,12 @@ use core::num::NonZero;
 use core::ops::Deref;
 use core::ptr::{self, NonNull};
 use core::slice::{self};
+#[cfg(kani)]
+use core::ub_checks::{self, Invariant};
 use core::{array, fmt};
 
+use safety::{ensures, requires};
+
 #[cfg(not(no_global_oom_handling))]
 use super::AsVecIntoIter;
 use crate::alloc::{Allocator, Global};
@@ -60,6 +66,29 @@ pub struct IntoIter<
     pub(super) end: *const T,
 }
 
+/// The unyielded elements are initialized and lie between `buf` and `buf + cap`. For ZSTs,
+/// `ptr` stays at `buf` and the byte distance from `ptr` to `end` counts them instead.
+#[cfg(kani)]
+#[unstable(feature = "ub_checks", issue = "none")]
+impl<T, A: Allocator> Invariant for IntoIter<T, A> {
+    fn is_safe(&self) -> bool {
+        let (buf, ptr, end) = (self.buf.as_ptr().addr(), self.ptr.as_ptr().addr(), self.end.addr());
+        if T::IS_ZST {
+            return ptr == buf && ptr <= end;
+        }
+        let size = size_of::<T>();
+        buf <= ptr
+            && ptr <= end
+            && (ptr - buf) % size == 0
+            && (end - ptr) % size == 0
+            && (end - buf) / size <= self.cap
+            && ub_checks::can_dereference(ptr::slice_from_raw_parts(
+                self.ptr.as_ptr(),
+                (end - ptr) / size,
+            ))
+    }
+}
+
 #[stable(feature = "vec_intoiter_debug", since = "1.13.0")]
 impl<T: fmt::Debug, A: Allocator> fmt::Debug for IntoIter<T, A> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -80,6 +109,7 @@ impl<T, A: Allocator> IntoIter<T, A> {
     /// assert_eq!(into_iter.as_slice(), &['b', 'c']);
     /// ```
     #[stable(feature = "vec_into_iter_as_slice", since = "1.15.0")]
+    #[ensures(|slice| slice.len() == self.len() && slice.as_ptr() == self.ptr.as_ptr())]
     pub fn as_slice(&self) -> &[T] {
         unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len()) }
     }
@@ -205,6 +235,11 @@ impl<T, A: Allocator> Iterator for IntoIter<T, A> {
     type Item = T;
 
     #[inline]
+    #[cfg_attr(kani, kani::modifies(self))]
+    #[ensures(|_| self.is_safe())]
+    #[ensures(|item| item.is_some() == (old(self.len()) > 0))]
+    #[ensures(|item| self.len() == old(self.len()) - item.is_some() as usize)]
+    #[ensures(|_| self.end == old(self.end) || T::IS_ZST)]
     fn next(&mut self) -> Option<T> {
         let ptr = if T::IS_ZST {
             if self.ptr.as_ptr() == self.end as *mut T {
@@ -373,6 +408,11 @@ impl<T, A: Allocator> Iterator for IntoIter<T, A> {
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
     #[inline]
+    #[cfg_attr(kani, kani::modifies(self))]
+    #[ensures(|_| self.is_safe())]
+    #[ensures(|item| item.is_some() == (old(self.len()) > 0))]
+    #[ensures(|item| self.len() == old(self.len()) - item.is_some() as usize)]
+    #[ensures(|_| self.ptr == old(self.ptr))]
     fn next_back(&mut self) -> Option<T> {
         if T::IS_ZST {
             if self.ptr.as_ptr() == self.end as *mut _ {
@@ -484,6 +524,7 @@ impl<T: Clone, A: Allocator + Clone> Clone for IntoIter<T, A> {
 
 #[stable(feature = "rust1", since = "1.0.0")]
 unsafe impl<#[may_dangle] T, A: Allocator> Drop for IntoIter<T, A> {
+    #[requires(self.is_safe())]
     fn drop(&mut self) {
         struct DropGuard<'a, T, A: Allocator>(&'a mut IntoIter<T, A>);
 
@@ -535,3 +576,106 @@ unsafe impl<T> AsVecIntoIter for IntoIter<T> {
         self
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::vec::Vec;
+
+    // `IntoIter` proofs
+
+    const MAX_LEN: usize = 3;
+
+    static mut DROPS: usize = 0;
+
+    fn drops() -> usize {
+        unsafe { DROPS }
+    }
+
+    struct Counted(u8);
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            unsafe { DROPS += 1 };
+        }
+    }
+
+    struct CountedZst;
+
+    impl Drop for CountedZst {
+        fn drop(&mut self) {
+            unsafe { DROPS += 1 };
+        }
+    }
+
+    /// An iterator over up to `MAX_LEN` elements, partially consumed from both ends.
+    /// Returns it with the number of elements it started with.
+    fn any_into_iter<T>(elem: impl Fn() -> T) -> (IntoIter<T>, usize) {
+        let len = kani::any_where(|&len: &usize| len <= MAX_LEN);
+        let mut vec = Vec::with_capacity(len + kani::any_where(|&x: &usize| x <= 1));
+        for _ in 0..len {
+            vec.push(elem());
+        }
+        let mut iter = vec.into_iter();
+        for _ in 0..MAX_LEN {
+            if kani::any() {
+                let _ = if kani::any() { iter.next() } else { iter.next_back() };
+            }
+        }
+        (iter, len)
+    }
+
+    macro_rules! check_into_iter {
+        ($module:ident, $ty:ty, $elem:expr) => {
+            mod $module {
+                use super::*;
+
+                #[kani::proof_for_contract(<IntoIter<$ty> as Iterator>::next)]
+                #[kani::unwind(4)]
+                fn check_next() {
+                    let (mut iter, _) = any_into_iter(|| $elem);
+                    let _ = iter.next();
+                }
+
+                #[kani::proof_for_contract(<IntoIter<$ty> as DoubleEndedIterator>::next_back)]
+                #[kani::unwind(4)]
+                fn check_next_back() {
+                    let (mut iter, _) = any_into_iter(|| $elem);
+                    let _ = iter.next_back();
+                }
+
+                #[kani::proof_for_contract(IntoIter::<$ty>::as_slice)]
+                #[kani::unwind(4)]
+                fn check_as_slice() {
+                    let (iter, _) = any_into_iter(|| $elem);
+                    let _ = iter.as_slice();
+                }
+
+                // Yielded elements are dropped by the caller and the rest by the iterator,
+                // each exactly once.
+                #[kani::proof_for_contract(<IntoIter<$ty> as Drop>::drop)]
+                #[kani::unwind(4)]
+                fn check_drop() {
+                    let (iter, len) = any_into_iter(|| $elem);
+                    drop(iter);
+                    assert_eq!(drops(), len);
+                }
+
+                // Forgetting the iterator leaks the unyielded elements instead of dropping
+                // them.
+                #[kani::proof]
+                #[kani::unwind(4)]
+                fn check_forget() {
+                    let (iter, len) = any_into_iter(|| $elem);
+                    let remaining = iter.len();
+                    core::mem::forget(iter);
+                    assert_eq!(drops(), len - remaining);
+                }
+            }
+        };
+    }
+
+    check_into_iter!(check_counted, Counted, Counted(kani::any()));
+    check_into_iter!(check_counted_zst, CountedZst, CountedZst);
+}
@@ -59,13 +59,19 @@ use core::cmp::Ordering;
 use core::hash::{Hash, Hasher};
 #[cfg(not(no_global_oom_handling))]
 use core::iter;
+#[cfg(kani)]
+use core::kani;
 use core::marker::PhantomData;
 use core::mem::{self, ManuallyDrop, MaybeUninit, SizedTypeProperties};
 use core::ops::{self, Index, IndexMut, Range, RangeBounds};
 use core::ptr::{self, NonNull};
 use core::slice::{self, SliceIndex};
+#[cfg(kani)]
+use core::ub_checks;
 use core::{fmt, intrinsics};
 
+use safety::{ensures, requires};
+
 #[stable(feature = "extract_if", since = "1.87.0")]
 pub use self::extract_if::ExtractIf;
 use crate::alloc::{Allocator, Global};
@@ -120,6 +126,14 @@ use self::spec_from_elem::SpecFromElem;
 #[cfg(not(no_global_oom_handling))]
 mod spec_from_elem;
 
+use self::backshift_on_drop::{BackshiftOnDrop, process_loop};
+
+mod backshift_on_drop;
+
+use self::fill_gap_on_drop::FillGapOnDrop;
+
+mod fill_gap_on_drop;
+
 #[cfg(not(no_global_oom_handling))]
 use self::set_len_on_drop::SetLenOnDrop;
 
@@ -150,6 +164,9 @@ use self::spec_extend::SpecExtend;
 #[cfg(not(no_global_oom_handling))]
 mod spec_extend;
 
+#[cfg(kani)]
+mod drop_tracking;
+
 /// A contiguous growable array type, written as `Vec<T>`, short for 'vector'.
 ///
 /// # Examples
@@ -619,6 +636,8 @@ impl<T> Vec<T> {
     /// ```
     #[inline]
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[ensures(|vec| vec.as_ptr() == ptr && vec.len() == length)]
+    #[ensures(|vec| vec.capacity() == if T::IS_ZST { usize::MAX } else { capacity })]
     pub unsafe fn from_raw_parts(ptr: *mut T, length: usize, capacity: usize) -> Self {
         unsafe { Self::from_raw_parts_in(ptr, length, capacity, Global) }
     }
@@ -796,6 +815,8 @@ impl<T> Vec<T> {
     /// ```
     #[must_use = "losing the pointer will leak memory"]
     #[unstable(feature = "vec_into_raw_parts", reason = "new API", issue = "65816")]
+    #[ensures(|&(ptr, len, _)| ptr == old(self.as_ptr()).cast_mut() && len == old(self.len()))]
+    #[ensures(|&(_, _, cap)| cap == old(self.capacity()))]
     pub fn into_raw_parts(self) -> (*mut T, usize, usize) {
         let mut me = ManuallyDrop::new(self);
         (me.as_mut_ptr(), me.len(), me.capacity())
@@ -1949,12 +1970,24 @@ impl<T, A: Allocator> Vec<T, A> {
     /// [`spare_capacity_mut()`]: Vec::spare_capacity_mut
     #[inline]
     #[stable(feature = "rust1", since = "1.0.0")]
+    #[requires(new_len <= self.capacity())]
+    #[cfg_attr(kani, requires(new_len <= self.len || self.is_initialized(self.len..new_len)))]
+    #[ensures(|_| self.len == new_len)]
+    #[cfg_attr(kani, kani::modifies(&self.len))]
     pub unsafe fn set_len(&mut self, new_len: usize) {
         debug_assert!(new_len <= self.capacity());
 
         self.len = new_len;
     }
 
+    /// Ghost predicate: whether the buffer holds valid, initialized `T`s at the
+    /// indices in `range`, which may extend into the spare capacity.
+    #[cfg(kani)]
+    fn is_initialized(&self, range: Range<usize>) -> bool {
+        let start = self.as_ptr().wrapping_add(range.start);
+        ub_checks::can_dereference(ptr::slice_from_raw_parts(start, range.len()))
+    }
+
     /// Removes an element from the vector and returns it.
     ///
     /// The removed element is replaced by the last element of the vector.
@@ -2191,80 +2224,10 @@ impl<T, A: Allocator> Vec<T, A> {
         // since we may make some holes during the process.
         unsafe { self.set_len(0) };
 
-        // Vec: [Kept, Kept, Hole, Hole, Hole, Hole, Unchecked, Unchecked]
-        //      |<-              processed len   ->| ^- next to check
-        //                  |<-  deleted cnt     ->|
-        //      |<-              original_len                          ->|
-        // Kept: Elements which predicate returns true on.
-        // Hole: Moved or dropped element slot.
-        // Unchecked: Unchecked valid elements.
-        //
-        // This drop guard will be invoked when predicate or `drop` of element panicked.
-        // It shifts unchecked elements to cover holes and `set_len` to the correct length.
-        // In cases when predicate and `drop` never panick, it will be optimized out.
-        struct BackshiftOnDrop<'a, T, A: Allocator> {
-            v: &'a mut Vec<T, A>,
-            processed_len: usize,
-            deleted_cnt: usize,
-            original_len: usize,
-        }
-
-        impl<T, A: Allocator> Drop for BackshiftOnDrop<'_, T, A> {
-            fn drop(&mut self) {
-                if self.deleted_cnt > 0 {
-                    // SAFETY: Trailing unchecked items must be valid since we never touch them.
-                    unsafe {
-                        ptr::copy(
-                            self.v.as_ptr().add(self.processed_len),
-                            self.v.as_mut_ptr().add(self.processed_len - self.deleted_cnt),
-                            self.original_len - self.processed_len,
-                        );
-                    }
-                }
-                // SAFETY: After filling holes, all items are in contiguous memory.
-                unsafe {
-                    self.v.set_len(self.original_len - self.deleted_cnt);
-                }
-            }
-        }
-
+        // See `BackshiftOnDrop` for the layout of the vector while elements are
+        // being processed.
         let mut g = BackshiftOnDrop { v: self, processed_len: 0, deleted_cnt: 0, original_len };
 
-        fn process_loop<F, T, A: Allocator, const DELETED: bool>(
-            original_len: usize,
-            f: &mut F,
-            g: &mut BackshiftOnDrop<'_, T, A>,
-        ) where
-            F: FnMut(&mut T) -> bool,
-        {
-            while g.processed_len != original_len {
-                // SAFETY: Unchecked element must be valid.
-                let cur = unsafe { &mut *g.v.as_mut_ptr().add(g.processed_len) };
-                if !f(cur) {
-                    // Advance early to avoid double drop if `drop_in_place` panicked.
-                    g.processed_len += 1;
-                    g.deleted_cnt += 1;
-                    // SAFETY: We never touch this element again after dropped.
-                    unsafe { ptr::drop_in_place(cur) };
-                    // We already advanced the counter.
-                    if DELETED {
-                        continue;
-                    } else {
-                        break;
-                    }
-                }
-                if DELETED {
-                    // SAFETY: `deleted_cnt` > 0, so the hole slot must not overlap with current element.
-                    // We use copy for move, and never touch this element again.
-                    unsafe {
-                        let hole_slot = g.v.as_mut_ptr().add(g.processed_len - g.deleted_cnt);
-                        ptr::copy_nonoverlapping(cur, hole_slot, 1);
-                    }
-                }
-                g.processed_len += 1;
-            }
-        }
-
         // Stage 1: Nothing was deleted.
         process_loop::<F, T, A, false>(original_len, &mut f, &mut g);
 
@@ -2352,52 +2315,6 @@ impl<T, A: Allocator> Vec<T, A> {
             return;
         }
 
-        /* INVARIANT: vec.len() > read > write > write-1 >= 0 */
-        struct FillGapOnDrop<'a, T, A: core::alloc::Allocator> {
-            /* Offset of the element we want to check if it is duplicate */
-            read: usize,
-
-            /* Offset of the place where we want to place the non-duplicate
-             * when we find it. */
-            write: usize,
-
-            /* The Vec that would need correction if `same_bucket` panicked */
-            vec: &'a mut Vec<T, A>,
-        }
-
-        impl<'a, T, A: core::alloc::Allocator> Drop for FillGapOnDrop<'a, T, A> {
-            fn drop(&mut self) {
-                /* This code gets executed when `same_bucket` panics */
-
-                /* SAFETY: invariant guarantees that `read - write`
-                 * and `len - read` never overflow and that the copy is always
-                 * in-bounds. */
-                unsafe {
-                    let ptr = self.vec.as_mut_ptr();
-                    let len = self.vec.len();
-
-                    /* How many items were left when `same_bucket` panicked.
-                     * Basically vec[read..].len() */
-                    let items_left = len.wrapping_sub(self.read);
-
-                    /* Pointer to first item in vec[write..write+items_left] slice */
-                    let dropped_ptr = ptr.add(self.write);
-                    /* Pointer to first item in vec[read..] slice */
-                    let valid_ptr = ptr.add(self.read);
-
-                    /* Copy `vec[read..]` to `vec[write..write+items_left]`.
-                     * The slices can overlap, so `copy_nonoverlapping` cannot be used */
-                    ptr::copy(valid_ptr, dropped_ptr, items_left);
-
-                    /* How many items have been already dropped
-                     * Basically vec[read..write].len() */
-                    let dropped = self.read.wrapping_sub(self.write);
-
-                    self.vec.set_len(len - dropped);
-                }
-            }
-        }
-
         /* Drop items while going through Vec, it should be more efficient than
          * doing slice partition_dedup + truncate */
 
@@ -2934,6 +2851,7 @@ impl<T, A: Allocator> Vec<T, A> {
     /// ```
     #[stable(feature = "vec_spare_capacity", since = "1.60.0")]
     #[inline]
+    #[ensures(|spare| spare.len() == old(self.capacity() - self.len()))]
     pub fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
         // Note:
         // This method is not implemented in terms of `split_at_spare_mut`,
@@ -3181,6 +3099,8 @@ impl<T: Clone, A: Allocator> Vec<T, A> {
     #[cfg(not(no_global_oom_handling))]
     #[stable(feature = "vec_extend_from_within", since = "1.53.0")]
     #[track_caller]
+    #[ensures(|_| self.len() == old(self.len() + Self::range_len(&src, self.len())))]
+    #[cfg_attr(kani, kani::modifies(self))]
     pub fn extend_from_within<R>(&mut self, src: R)
     where
         R: RangeBounds<usize>,
@@ -3194,6 +3114,13 @@ impl<T: Clone, A: Allocator> Vec<T, A> {
             self.spec_extend_from_within(range);
         }
     }
+
+    /// The number of elements `extend_from_within(src)` appends to a vector of
+    /// length `len`. Panics on the same ranges as `extend_from_within`.
+    #[cfg(all(kani, not(no_global_oom_handling)))]
+    fn range_len<R: RangeBounds<usize>>(src: &R, len: usize) -> usize {
+        slice::range((src.start_bound().cloned(), src.end_bound().cloned()), ..len).len()
+    }
 }
 
 impl<T, A: Allocator, const N: usize> Vec<[T; N], A> {
@@ -4202,4 +4129,174 @@ mod verify {
             assert!(vect[k] == arr[k]);
         }
     }
+
+    // `set_len`, `spare_capacity_mut` and `extend_from_within` proofs
+
+    static mut DROPS: usize = 0;
+
+    // Element type with a destructor, to check that `set_len` hands exactly the
+    // written elements over to the vector.
+    struct Counted(u8);
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            unsafe { DROPS += 1 };
+        }
+    }
+
+    #[kani::proof_for_contract(Vec::set_len)]
+    #[kani::unwind(4)]
+    pub fn verify_set_len() {
+        let arr: [u8; ARRAY_LEN] = kani::Arbitrary::any_array();
+        let mut vect: Vec<u8> = Vec::with_capacity(2 * ARRAY_LEN);
+        vect.extend_from_slice(&arr);
+        let spare = vect.spare_capacity_mut();
+        let written = kani::any_where(|&x: &usize| x <= ARRAY_LEN);
+        for slot in &mut spare[..written] {
+            slot.write(kani::any());
+        }
+        unsafe { vect.set_len(kani::any()) };
+    }
+
+    #[kani::proof_for_contract(Vec::spare_capacity_mut)]
+    pub fn verify_spare_capacity_mut() {
+        let capacity = kani::any_where(|&x: &usize| x <= ARRAY_LEN);
+        let mut vect: Vec<u8> = Vec::with_capacity(capacity);
+        if kani::any() {
+            vect.push(kani::any());
+        }
+        let _ = vect.spare_capacity_mut();
+    }
+
+    #[kani::proof_for_contract(Vec::extend_from_within)]
+    #[kani::unwind(4)]
+    pub fn verify_extend_from_within() {
+        let arr: [u8; ARRAY_LEN] = kani::Arbitrary::any_array();
+        let mut vect = Vec::from(&arr);
+        let end = kani::any_where(|&x: &usize| x <= ARRAY_LEN);
+        let start = kani::any_where(|&x: &usize| x <= end);
+        vect.extend_from_within(start..end);
+        assert!(vect[..ARRAY_LEN] == arr);
+        assert!(vect[ARRAY_LEN..] == arr[start..end]);
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    pub fn verify_extend_from_within_invalid_range() {
+        let mut vect = Vec::from(&[0u8; ARRAY_LEN]);
+        let start = kani::any_where(|&x: &usize| x > ARRAY_LEN);
+        vect.extend_from_within(start..);
+    }
+
+    // Writing through `spare_capacity_mut` and then publishing the elements with
+    // `set_len` satisfies the `set_len` precondition, for plain bytes ...
+    #[kani::proof]
+    #[kani::stub_verified(Vec::set_len)]
+    #[kani::unwind(4)]
+    pub fn verify_spare_capacity_write_then_set_len() {
+        let arr: [u8; ARRAY_LEN] = kani::Arbitrary::any_array();
+        let mut vect: Vec<u8> = Vec::with_capacity(2 * ARRAY_LEN);
+        let old_len = kani::any_where(|&x: &usize| x <= ARRAY_LEN);
+        vect.extend_from_slice(&arr[..old_len]);
+
+        let spare = vect.spare_capacity_mut();
+        let count = kani::any_where(|&x: &usize| x <= ARRAY_LEN);
+        for (slot, value) in spare.iter_mut().zip(&arr[..count]) {
+            slot.write(*value);
+        }
+        unsafe { vect.set_len(old_len + count) };
+
+        assert!(vect[..old_len] == arr[..old_len]);
+        assert!(vect[old_len..] == arr[..count]);
+    }
+
+    // ... and for elements with a destructor, which the vector then drops
+    // exactly once each.
+    #[kani::proof]
+    #[kani::stub_verified(Vec::set_len)]
+    #[kani::unwind(4)]
+    pub fn verify_spare_capacity_write_then_set_len_drop() {
+        let mut vect: Vec<Counted> = Vec::with_capacity(2 * ARRAY_LEN);
+        let old_len = kani::any_where(|&x: &usize| x <= ARRAY_LEN);
+        for _ in 0..old_len {
+            vect.push(Counted(kani::any()));
+        }
+
+        let spare = vect.spare_capacity_mut();
+        let count = kani::any_where(|&x: &usize| x <= ARRAY_LEN);
+        for slot in &mut spare[..count] {
+            slot.write(Counted(kani::any()));
+        }
+        unsafe { vect.set_len(old_len + count) };
+
+        drop(vect);
+        assert!(unsafe { DROPS } == old_len + count);
+    }
+
+    // Growing past the capacity violates the `set_len` precondition.
+    #[kani::proof]
+    #[kani::stub_verified(Vec::set_len)]
+    #[kani::should_panic]
+    pub fn verify_set_len_past_capacity() {
+        let mut vect: Vec<u8> = Vec::with_capacity(ARRAY_LEN);
+        let new_len = kani::any_where(|&x: &usize| x > vect.capacity());
+        unsafe { vect.set_len(new_len) };
+    }
+
+    // `into_raw_parts` and `from_raw_parts` proofs
+
+    /// Up to `ARRAY_LEN` symbolic bytes, with up to as much spare capacity.
+    fn any_byte_vec() -> Vec<u8> {
+        let arr: [u8; ARRAY_LEN] = kani::Arbitrary::any_array();
+        let len = kani::any_where(|&x: &usize| x <= ARRAY_LEN);
+        let mut vect = Vec::with_capacity(len + kani::any_where(|&x: &usize| x <= ARRAY_LEN));
+        vect.extend_from_slice(&arr[..len]);
+        vect
+    }
+
+    #[kani::proof_for_contract(Vec::into_raw_parts)]
+    fn check_into_raw_parts() {
+        let vect = any_byte_vec();
+        let (old_len, old_cap) = (vect.len(), vect.capacity());
+        let (ptr, len, cap) = vect.into_raw_parts();
+        assert!(!ptr.is_null() && len == old_len && cap == old_cap);
+    }
+
+    // The parts come from a `ManuallyDrop` vector, so `from_raw_parts` is checked on its own.
+    #[kani::proof_for_contract(Vec::from_raw_parts)]
+    #[kani::unwind(4)]
+    fn check_from_raw_parts() {
+        let vect = any_byte_vec();
+        let copy = vect.clone();
+        let mut vect = ManuallyDrop::new(vect);
+        let (ptr, len, cap) = (vect.as_mut_ptr(), vect.len(), vect.capacity());
+        let vect = unsafe { Vec::from_raw_parts(ptr, len, cap) };
+        assert!(vect == copy);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(4)]
+    fn check_raw_parts_round_trip() {
+        let vect = any_byte_vec();
+        let copy = vect.clone();
+        let (ptr, len, cap) = vect.into_raw_parts();
+        let vect = unsafe { Vec::from_raw_parts(ptr, len, cap) };
+        assert!(vect == copy);
+    }
+
+    // Leaking the parts drops nothing, and the rebuilt vector drops each
+    // element exactly once.
+    #[kani::proof]
+    #[kani::unwind(4)]
+    fn check_raw_parts_round_trip_drop() {
+        let len = kani::any_where(|&x: &usize| x <= ARRAY_LEN);
+        let mut vect = Vec::with_capacity(ARRAY_LEN);
+        for _ in 0..len {
+            vect.push(Counted(kani::any()));
+        }
+        let (ptr, length, capacity) = vect.into_raw_parts();
+        assert!(unsafe { DROPS } == 0);
+        drop(unsafe { Vec::from_raw_parts(ptr, length, capacity) });
+        assert!(unsafe { DROPS } == len);
+    }
 }
@@ -1,5 +1,11 @@
+#[cfg(kani)]
+use core::kani;
 use core::ptr::{self};
 use core::slice::{self};
+#[cfg(kani)]
+use core::ub_checks::Invariant;
+
+use safety::{ensures, requires};
 
 use super::{Drain, Vec};
 use crate::alloc::{Allocator, Global};
@@ -104,6 +110,9 @@ impl<T, A: Allocator> Drain<'_, T, A> {
     /// that have been moved out.
     /// Fill that range as much as possible with new elements from the `replace_with` iterator.
     /// Returns `true` if we filled the entire range. (`replace_with.next()` didn’t return `None`.)
+    #[requires(self.is_safe())]
+    #[ensures(|filled| self.is_safe() && *filled == (self.head_len() == self.tail_start))]
+    #[cfg_attr(kani, kani::modifies(self.vec.as_ptr(), self.buffer(), replace_with))]
     unsafe fn fill<I: Iterator<Item = T>>(&mut self, replace_with: &mut I) -> bool {
         let vec = unsafe { self.vec.as_mut() };
         let range_start = vec.len;
@@ -125,6 +134,10 @@ impl<T, A: Allocator> Drain<'_, T, A> {
 
     /// Makes room for inserting more elements before the tail.
     #[track_caller]
+    #[requires(self.is_safe() && self.iter.len() == 0)]
+    #[ensures(|_| self.is_safe() && self.tail_start == old(self.tail_start) + additional)]
+    #[ensures(|_| self.head_len() == old(self.head_len()))]
+    #[cfg_attr(kani, kani::modifies(self, self.vec.as_ptr(), self.buffer()))]
     unsafe fn move_tail(&mut self, additional: usize) {
         let vec = unsafe { self.vec.as_mut() };
         let len = self.tail_start + self.tail_len;
@@ -139,3 +152,64 @@ impl<T, A: Allocator> Drain<'_, T, A> {
         self.tail_start = new_tail_start;
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // `Drain` tail-move proofs
+
+    const LEN: usize = 3;
+
+    /// Drains a symbolic range from a vector holding `0..LEN` and yields all
+    /// drained elements, as `Splice` does before refilling the hole.
+    fn emptied_drain(vec: &mut Vec<u8>) -> Drain<'_, u8> {
+        let end = kani::any_where(|&x: &usize| x <= LEN);
+        let start = kani::any_where(|&x: &usize| x <= end);
+        let mut drain = vec.drain(start..end);
+        drain.by_ref().for_each(drop);
+        drain
+    }
+
+    #[kani::proof_for_contract(Drain::fill)]
+    #[kani::unwind(4)]
+    fn check_fill() {
+        let mut vec: Vec<u8> = (0..LEN as u8).collect();
+        let mut drain = emptied_drain(&mut vec);
+        let replacement: [u8; LEN] = kani::any();
+        let count = kani::any_where(|&x: &usize| x <= LEN);
+        let mut replace_with = replacement[..count].iter().copied();
+        unsafe { drain.fill(&mut replace_with) };
+    }
+
+    #[kani::proof_for_contract(Drain::move_tail)]
+    #[kani::unwind(4)]
+    fn check_move_tail() {
+        let mut vec: Vec<u8> = (0..LEN as u8).collect();
+        let mut drain = emptied_drain(&mut vec);
+        let additional = kani::any_where(|&x: &usize| x <= LEN);
+        unsafe { drain.move_tail(additional) };
+    }
+
+    // After the tail has been moved and the hole refilled, dropping the drain
+    // leaves head, replacement and tail in order.
+    #[kani::proof]
+    #[kani::unwind(7)]
+    fn check_move_tail_then_fill() {
+        let mut vec: Vec<u8> = (0..LEN as u8).collect();
+        let mut drain = emptied_drain(&mut vec);
+        let (start, tail_start) = (drain.head_len(), drain.tail_start);
+        let additional = kani::any_where(|&x: &usize| x <= LEN);
+        let hole = tail_start - start + additional;
+        unsafe { drain.move_tail(additional) };
+        let replacement: [u8; 2 * LEN] = kani::any();
+        assert!(unsafe { drain.fill(&mut replacement[..hole].iter().copied()) });
+        drop(drain);
+
+        assert_eq!(vec.len(), start + hole + (LEN - tail_start));
+        assert!(vec[..start].iter().copied().eq(0..start as u8));
+        assert!(vec[start..start + hole] == replacement[..hole]);
+        assert!(vec[start + hole..].iter().copied().eq(tail_start as u8..LEN as u8));
+    }
+}
@@ -1,6 +1,15 @@
+//! Lowering of the contract attributes to Kani.
+//!
+//! The Kani attributes are emitted behind `cfg_attr(kani, ...)`, so they are only seen when the
+//! annotated crate is compiled by Kani.
+
 use proc_macro::TokenStream;
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
 use quote::{format_ident, quote};
-use syn::{parse_macro_input, ItemFn, Stmt};
+use syn::{
+    parse_macro_input, parse_quote, Attribute, GenericArgument, Ident, ImplItem, ItemFn, ItemImpl,
+    PathArguments, ReturnType, Stmt, Type,
+};
 
 pub(crate) fn requires(attr: TokenStream, item: TokenStream) -> TokenStream {
     rewrite_attr(attr, item, "requires")
@@ -19,7 +28,7 @@ fn rewrite_stmt_attr(attr: TokenStream, stmt_stream: TokenStream, name: &str) ->
     let stmt = parse_macro_input!(stmt_stream as Stmt);
     let attribute = format_ident!("{}", name);
     quote!(
-        #[kani_core::#attribute(#args)]
+        #[cfg_attr(kani, kani_core::#attribute(#args))]
         #stmt
     )
     .into()
@@ -30,8 +39,103 @@ fn rewrite_attr(attr: TokenStream, item: TokenStream, name: &str) -> TokenStream
     let fn_item = parse_macro_input!(item as ItemFn);
     let attribute = format_ident!("{}", name);
     quote!(
-        #[kani_core::#attribute(#args)]
+        #[cfg_attr(kani, kani_core::#attribute(#args))]
         #fn_item
     )
     .into()
 }
+
+/// Adds the contracts maintaining the type invariant to the methods of an `impl` block.
+pub(crate) fn invariant_contracts(mut item: ItemImpl) -> TokenStream {
+    let self_ident = match &*item.self_ty {
+        Type::Path(ty) => ty.path.segments.last().map(|seg| seg.ident.clone()),
+        _ => None,
+    };
+    let is_safe = quote!(core::ub_checks::Invariant::is_safe);
+    for impl_item in &mut item.items {
+        let ImplItem::Fn(method) = impl_item else {
+            continue;
+        };
+        let sig = &method.sig;
+        let contracts: Vec<Attribute> = match sig.receiver() {
+            None => {
+                let ReturnType::Type(_, ret) = &sig.output else {
+                    continue;
+                };
+                match constructed(ret, self_ident.as_ref()) {
+                    Some(Constructed::Value) => vec![parse_quote!(
+                        #[cfg_attr(kani, kani_core::ensures(|result: &#ret| #is_safe(result)))]
+                    )],
+                    Some(Constructed::Wrapped) => vec![parse_quote!(
+                        #[cfg_attr(kani, kani_core::ensures(|result: &#ret| {
+                            result.as_ref().map_or(true, #is_safe)
+                        }))]
+                    )],
+                    None => continue,
+                }
+            }
+            Some(receiver) if receiver.reference.is_some() && receiver.mutability.is_some() => {
+                if returns_borrow(&sig.output) {
+                    continue;
+                }
+                vec![
+                    parse_quote!(#[cfg_attr(kani, kani_core::requires(#is_safe(self)))]),
+                    parse_quote!(#[cfg_attr(kani, kani_core::ensures(|_| #is_safe(self)))]),
+                ]
+            }
+            Some(_) => continue,
+        };
+        method.attrs.splice(0..0, contracts);
+    }
+    quote!(#item).into()
+}
+
+enum Constructed {
+    /// The function returns `Self`.
+    Value,
+    /// The function returns `Option<Self>` or `Result<Self, _>`.
+    Wrapped,
+}
+
+fn constructed(ty: &Type, self_ident: Option<&Ident>) -> Option<Constructed> {
+    let is_self = |ty: &Type| match ty {
+        Type::Path(ty) if ty.qself.is_none() => {
+            let ident = &ty.path.segments.last().unwrap().ident;
+            ident == "Self" || Some(ident) == self_ident
+        }
+        _ => false,
+    };
+    if is_self(ty) {
+        return Some(Constructed::Value);
+    }
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let seg = path.path.segments.last()?;
+    let PathArguments::AngleBracketed(args) = &seg.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(inner)
+            if (seg.ident == "Option" || seg.ident == "Result") && is_self(inner) =>
+        {
+            Some(Constructed::Wrapped)
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` if the return type may borrow from the arguments.
+pub(crate) fn returns_borrow(output: &ReturnType) -> bool {
+    fn has_borrow(tokens: TokenStream2) -> bool {
+        tokens.into_iter().any(|tt| match tt {
+            TokenTree::Punct(punct) => punct.as_char() == '&' || punct.as_char() == '\'',
+            TokenTree::Group(group) => has_borrow(group.stream()),
+            _ => false,
+        })
+    }
+    match output {
+        ReturnType::Default => false,
+        ReturnType::Type(_, ty) => has_borrow(quote!(#ty)),
+    }
+}
@@ -1,25 +1,27 @@
 //! Implement a few placeholders for contract attributes until they get implemented upstream.
-//! Each tool should implement their own version in a separate module of this crate.
+//!
+//! A single `#[requires]`/`#[ensures]`/`#[loop_invariant]` annotation is lowered to every
+//! supported tool at once, each lowering being gated by a `cfg` of the annotated crate:
+//! - `kani`: the Kani contract attributes,
+//! - `safety_checks` (without `kani`): debug assertions checked at runtime,
+//! - anything else, including the other verification tools: no-ops.
+//!
+//! Each lowering is implemented in a separate module of this crate.
 
 use proc_macro::TokenStream;
 use proc_macro_error::proc_macro_error;
 use quote::{format_ident, quote, quote_spanned};
 use syn::{
     parse_macro_input, parse_quote, spanned::Spanned, Data, DataEnum, DeriveInput, Fields,
-    GenericParam, Generics, Ident, Index, ItemStruct,
+    GenericParam, Generics, Ident, Index, Item, ItemStruct,
 };
 
-#[cfg(kani_host)]
-#[path = "kani.rs"]
-mod tool;
-
-#[cfg(not(kani_host))]
-#[path = "runtime.rs"]
-mod tool;
+mod kani;
+mod runtime;
 
 /// Expands the `#[invariant(...)]` attribute macro.
-/// The macro expands to an implementation of the `is_safe` method for the `Invariant` trait.
-/// This attribute is only supported for structs.
+/// On a struct, the macro expands to an implementation of the `is_safe` method for the
+/// `Invariant` trait, which only exists under `cfg(kani)`.
 ///
 /// # Example
 ///
@@ -33,23 +35,49 @@ mod tool;
 ///
 /// expands to:
 /// ```ignore
+/// #[cfg(kani)]
 /// impl core::ub_checks::Invariant for Square {
 ///   fn is_safe(&self) -> bool {
 ///     self.width == self.height
 ///   }
 /// }
 /// ```
+///
+/// Without arguments on an `impl` block of such a type, the macro adds Kani contracts that
+/// maintain the invariant: constructors, i.e. associated functions returning `Self`,
+/// `Option<Self>` or `Result<Self, _>`, ensure that the new value is safe, and `&mut self` methods
+/// require and ensure that `self` is safe. Methods returning a borrow are skipped, as `self` is
+/// still borrowed when their postcondition is checked.
+///
+/// ```ignore
+/// #[invariant]
+/// impl Square {
+///     fn new(side: u32) -> Self { .. }
+///     fn grow(&mut self) { .. }
+/// }
+/// ```
 /// For more information on the Invariant trait, see its documentation in core::ub_checks.
 #[proc_macro_error]
 #[proc_macro_attribute]
 pub fn invariant(attr: TokenStream, item: TokenStream) -> TokenStream {
+    match parse_macro_input!(item as Item) {
+        Item::Struct(item) if !attr.is_empty() => invariant_impl(attr, item),
+        Item::Impl(item) if attr.is_empty() => kani::invariant_contracts(item),
+        item => proc_macro_error::abort!(
+            item,
+            "`#[invariant(..)]` applies to structs, and `#[invariant]` to their `impl` blocks"
+        ),
+    }
+}
+
+fn invariant_impl(attr: TokenStream, item: ItemStruct) -> TokenStream {
     let safe_body = proc_macro2::TokenStream::from(attr);
-    let item = parse_macro_input!(item as ItemStruct);
     let item_name = &item.ident;
     let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
 
     let expanded = quote! {
         #item
+        #[cfg(kani)]
         #[unstable(feature="invariant", issue="none")]
         impl #impl_generics core::ub_checks::Invariant for #item_name #ty_generics #where_clause {
             fn is_safe(&self) -> bool {
@@ -149,19 +177,19 @@ pub fn derive_invariant(item: TokenStream) -> TokenStream {
 #[proc_macro_error]
 #[proc_macro_attribute]
 pub fn requires(attr: TokenStream, item: TokenStream) -> TokenStream {
-    tool::requires(attr, item)
+    runtime::requires(attr.clone(), kani::requires(attr, item))
 }
 
 #[proc_macro_error]
 #[proc_macro_attribute]
 pub fn ensures(attr: TokenStream, item: TokenStream) -> TokenStream {
-    tool::ensures(attr, item)
+    runtime::ensures(attr.clone(), kani::ensures(attr, item))
 }
 
 #[proc_macro_error]
 #[proc_macro_attribute]
 pub fn loop_invariant(attr: TokenStream, stmt_stream: TokenStream) -> TokenStream {
-    tool::loop_invariant(attr, stmt_stream)
+    kani::loop_invariant(attr.clone(), runtime::loop_invariant(attr, stmt_stream))
 }
 
 /// Add a bound `T: Invariant` to every type parameter T.
@@ -1,22 +1,163 @@
+//! Lowering of the contract attributes to runtime checks.
+//!
+//! The checks are debug assertions emitted behind `cfg(all(not(kani), safety_checks))`, so they
+//! are only compiled in when the annotated crate is built with `--cfg safety_checks`. Otherwise,
+//! and for verification tools other than Kani, the annotated code is left as is.
+//!
+//! Some contracts are only checked by Kani:
+//! - contracts of `const` and `async` functions,
+//! - postconditions of functions returning `impl Trait`, `!` or a borrow, which cannot escape the
+//!   closure running the body,
+//! - conditions using Kani-only predicates (see [`KANI_ONLY`]),
+//! - postconditions reading an argument passed by value, which the body may have moved.
+//!
+//! Contracts calling helpers that only exist under `cfg(kani)` must be written as
+//! `#[cfg_attr(kani, requires(..))]`.
+
 use proc_macro::TokenStream;
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Attribute, Expr, FnArg, ItemFn, Pat, ReturnType, Type};
 
-/// For now, runtime requires is a no-op.
-///
-/// TODO: At runtime the `requires` should become an assert unsafe precondition.
-pub(crate) fn requires(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    item
+/// Identifiers of predicates that have no runtime counterpart: Kani itself, `old` values, type
+/// invariants (see `#[invariant]`), and the memory predicates of `ub_checks`, which always hold
+/// at runtime.
+const KANI_ONLY: &[&str] = &[
+    "kani",
+    "old",
+    "is_safe",
+    "can_dereference",
+    "can_write",
+    "can_read_unaligned",
+    "can_write_unaligned",
+    "same_allocation",
+    "float_to_int_in_range",
+];
+
+/// Checks the precondition on entry of the function.
+pub(crate) fn requires(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let cond = parse_macro_input!(attr as Expr);
+    let mut fn_item = parse_macro_input!(item as ItemFn);
+    if !is_checkable(&fn_item) || mentions(quote!(#cond), KANI_ONLY) {
+        return quote!(#fn_item).into();
+    }
+    let msg = format!("precondition violated: {}", quote!(#cond));
+    let stmts = &fn_item.block.stmts;
+    *fn_item.block = parse_quote!({
+        #[cfg(all(not(kani), safety_checks))]
+        core::debug_assert!(#cond, "{}", #msg);
+        #(#stmts)*
+    });
+    quote!(#fn_item).into()
 }
 
-/// For now, runtime ensures is a no-op.
+/// Checks the postcondition on the returned value.
 ///
-/// TODO: At runtime the `ensures` should become an assert as well.
-pub(crate) fn ensures(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    item
+/// The check needs the body to run in a closure, to get hold of its result. So that nothing
+/// changes without the check, the function is duplicated: an unchanged copy is compiled without
+/// `safety_checks`, and a checked copy with it.
+pub(crate) fn ensures(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let check = parse_macro_input!(attr as Expr);
+    let fn_item = parse_macro_input!(item as ItemFn);
+    let ret: Type = match &fn_item.sig.output {
+        ReturnType::Default => parse_quote!(()),
+        ReturnType::Type(_, ty) => (**ty).clone(),
+    };
+    let check_tokens = quote!(#check);
+    if !is_checkable(&fn_item)
+        || matches!(ret, Type::ImplTrait(_) | Type::Never(_))
+        || crate::kani::returns_borrow(&fn_item.sig.output)
+        || mentions(check_tokens.clone(), KANI_ONLY)
+        || mentions(check_tokens, &moved_args(&fn_item))
+    {
+        return quote!(#fn_item).into();
+    }
+
+    let unchecked_cfg: Attribute = parse_quote!(#[cfg(not(all(not(kani), safety_checks)))]);
+    let checked_cfg: Attribute = parse_quote!(#[cfg(all(not(kani), safety_checks))]);
+    // An earlier postcondition already split the function.
+    if has_attr(&fn_item.attrs, &unchecked_cfg) {
+        return quote!(#fn_item).into();
+    }
+    let unchecked = if has_attr(&fn_item.attrs, &checked_cfg) {
+        None
+    } else {
+        let mut unchecked = fn_item.clone();
+        unchecked.attrs.insert(0, unchecked_cfg);
+        Some(unchecked)
+    };
+
+    let mut checked = fn_item;
+    if !has_attr(&checked.attrs, &checked_cfg) {
+        checked.attrs.insert(0, checked_cfg);
+    }
+    let msg = format!("postcondition violated: {}", quote!(#check));
+    let block = &checked.block;
+    *checked.block = parse_quote!({
+        // Gives the closure parameter its type, which a closure called in place does not get.
+        fn check_result<R: ?Sized>(result: &R, check: impl FnOnce(&R) -> bool) -> bool {
+            check(result)
+        }
+        let result: #ret = (|| #block)();
+        core::debug_assert!(check_result(&result, #check), "{}", #msg);
+        result
+    });
+    quote!(
+        #unchecked
+        #checked
+    )
+    .into()
 }
 
-/// For now, runtime loop_invariant is a no-op.
-///
-/// TODO: At runtime the `loop_invariant` should become an assert as well.
+/// Loop invariants are not checked at runtime.
 pub(crate) fn loop_invariant(_attr: TokenStream, stmt_stream: TokenStream) -> TokenStream {
     stmt_stream
 }
+
+/// Runtime checks cannot be evaluated in `const` functions, nor wrap the body of `async` ones.
+fn is_checkable(fn_item: &ItemFn) -> bool {
+    fn_item.sig.constness.is_none() && fn_item.sig.asyncness.is_none()
+}
+
+/// Returns `true` if `attrs` contain `attr`.
+fn has_attr(attrs: &[Attribute], attr: &Attribute) -> bool {
+    let attr = quote!(#attr).to_string();
+    attrs.iter().any(|other| quote!(#other).to_string() == attr)
+}
+
+/// Returns the names of the arguments passed by value whose type may not be `Copy`.
+fn moved_args(fn_item: &ItemFn) -> Vec<String> {
+    const SCALARS: &[&str] = &[
+        "bool", "char", "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64",
+        "i128", "isize", "f16", "f32", "f64", "f128",
+    ];
+    fn_item
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            FnArg::Receiver(receiver) if receiver.reference.is_none() => Some("self".to_string()),
+            FnArg::Receiver(_) => None,
+            FnArg::Typed(arg) => {
+                let by_value = match &*arg.ty {
+                    Type::Reference(_) | Type::Ptr(_) => false,
+                    Type::Path(ty) => !SCALARS.iter().any(|scalar| ty.path.is_ident(scalar)),
+                    _ => true,
+                };
+                match &*arg.pat {
+                    Pat::Ident(pat) if by_value => Some(pat.ident.to_string()),
+                    _ => None,
+                }
+            }
+        })
+        .collect()
+}
+
+/// Returns `true` if `tokens` contain one of the identifiers `idents`.
+fn mentions<S: AsRef<str>>(tokens: TokenStream2, idents: &[S]) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        TokenTree::Ident(ident) => idents.iter().any(|name| ident == name.as_ref()),
+        TokenTree::Group(group) => mentions(group.stream(), idents),
+        _ => false,
+    })
+}
@@ -4,7 +4,7 @@
 // collections, resulting in having to optimize down excess IR multiple times.
 // Your performance intuition is useless. Run perf.
 
-use safety::{Invariant, ensures, requires};
+use safety::{ensures, invariant, requires};
 
 #[cfg(kani)]
 use crate::cmp;
@@ -46,7 +46,7 @@ const fn size_align<T>() -> (usize, usize) {
 #[stable(feature = "alloc_layout", since = "1.28.0")]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[lang = "alloc_layout"]
-#[derive(Invariant)]
+#[invariant(self.align.is_safe() && self.size <= Layout::max_size_for_align(self.align))]
 pub struct Layout {
     // size of the requested block of memory, measured in bytes.
     size: usize,
@@ -61,6 +61,7 @@ pub struct Layout {
     align: Alignment,
 }
 
+#[invariant]
 impl Layout {
     /// Constructs a `Layout` from a given `size` and `align`,
     /// or returns `LayoutError` if any of the following conditions
@@ -143,7 +144,6 @@ impl Layout {
     #[inline]
     #[track_caller]
     #[requires(Layout::from_size_align(size, align).is_ok())]
-    #[ensures(|result| result.is_safe())]
     #[ensures(|result| result.size() == size)]
     #[ensures(|result| result.align() == align)]
     pub const unsafe fn from_size_align_unchecked(size: usize, align: usize) -> Self {
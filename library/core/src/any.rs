@@ -86,6 +86,12 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
+use safety::{ensures, requires};
+
+#[cfg(kani)]
+use crate::kani;
+#[cfg(kani)]
+use crate::ptr;
 use crate::{fmt, hash, intrinsics};
 
 ///////////////////////////////////////////////////////////////////////////////
@@ -291,6 +297,8 @@ impl dyn Any {
     /// with the incorrect type is *undefined behavior*.
     #[unstable(feature = "downcast_unchecked", issue = "90850")]
     #[inline]
+    #[requires(self.is::<T>())]
+    #[ensures(|r| ptr::addr_eq(*r, self))]
     pub unsafe fn downcast_ref_unchecked<T: Any>(&self) -> &T {
         debug_assert!(self.is::<T>());
         // SAFETY: caller guarantees that T is the correct type
@@ -321,6 +329,8 @@ impl dyn Any {
     /// with the incorrect type is *undefined behavior*.
     #[unstable(feature = "downcast_unchecked", issue = "90850")]
     #[inline]
+    #[requires(self.is::<T>())]
+    #[ensures(|r| ptr::addr_eq(&**r, old(self as *const Self)))]
     pub unsafe fn downcast_mut_unchecked<T: Any>(&mut self) -> &mut T {
         debug_assert!(self.is::<T>());
         // SAFETY: caller guarantees that T is the correct type
@@ -427,6 +437,8 @@ impl dyn Any + Send {
     /// with the incorrect type is *undefined behavior*.
     #[unstable(feature = "downcast_unchecked", issue = "90850")]
     #[inline]
+    #[requires(self.is::<T>())]
+    #[ensures(|r| ptr::addr_eq(*r, self))]
     pub unsafe fn downcast_ref_unchecked<T: Any>(&self) -> &T {
         // SAFETY: guaranteed by caller
         unsafe { <dyn Any>::downcast_ref_unchecked::<T>(self) }
@@ -456,6 +468,8 @@ impl dyn Any + Send {
     /// with the incorrect type is *undefined behavior*.
     #[unstable(feature = "downcast_unchecked", issue = "90850")]
     #[inline]
+    #[requires(self.is::<T>())]
+    #[ensures(|r| ptr::addr_eq(&**r, old(self as *const Self)))]
     pub unsafe fn downcast_mut_unchecked<T: Any>(&mut self) -> &mut T {
         // SAFETY: guaranteed by caller
         unsafe { <dyn Any>::downcast_mut_unchecked::<T>(self) }
@@ -560,6 +574,8 @@ impl dyn Any + Send + Sync {
     /// with the incorrect type is *undefined behavior*.
     #[unstable(feature = "downcast_unchecked", issue = "90850")]
     #[inline]
+    #[requires(self.is::<T>())]
+    #[ensures(|r| ptr::addr_eq(*r, self))]
     pub unsafe fn downcast_ref_unchecked<T: Any>(&self) -> &T {
         // SAFETY: guaranteed by caller
         unsafe { <dyn Any>::downcast_ref_unchecked::<T>(self) }
@@ -588,6 +604,8 @@ impl dyn Any + Send + Sync {
     /// with the incorrect type is *undefined behavior*.
     #[unstable(feature = "downcast_unchecked", issue = "90850")]
     #[inline]
+    #[requires(self.is::<T>())]
+    #[ensures(|r| ptr::addr_eq(&**r, old(self as *const Self)))]
     pub unsafe fn downcast_mut_unchecked<T: Any>(&mut self) -> &mut T {
         // SAFETY: guaranteed by caller
         unsafe { <dyn Any>::downcast_mut_unchecked::<T>(self) }
@@ -896,3 +914,94 @@ pub const fn type_name<T: ?Sized>() -> &'static str {
 pub const fn type_name_of_val<T: ?Sized>(_val: &T) -> &'static str {
     type_name::<T>()
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // `TypeId` equality proofs
+    // Both sides of the `const_eval_select` in `TypeId::eq` must tell the types apart:
+    // `type_id_eq` during const evaluation and the `u128` comparison at runtime.
+
+    const TYPE_IDS: [TypeId; 12] = [
+        TypeId::of::<()>(),
+        TypeId::of::<bool>(),
+        TypeId::of::<char>(),
+        TypeId::of::<u8>(),
+        TypeId::of::<i8>(),
+        TypeId::of::<u32>(),
+        TypeId::of::<i32>(),
+        TypeId::of::<usize>(),
+        TypeId::of::<f32>(),
+        TypeId::of::<str>(),
+        TypeId::of::<[u8]>(),
+        TypeId::of::<&'static str>(),
+    ];
+
+    /// Whether `ids[i] == ids[j]` holds exactly when `i == j`.
+    const fn pairwise_distinct(ids: &[TypeId]) -> bool {
+        let mut i = 0;
+        while i < ids.len() {
+            let mut j = 0;
+            while j < ids.len() {
+                if (ids[i] == ids[j]) != (i == j) {
+                    return false;
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    #[kani::proof]
+    fn check_type_id_eq_const() {
+        const DISTINCT: bool = pairwise_distinct(&TYPE_IDS);
+        assert!(DISTINCT);
+    }
+
+    #[kani::proof]
+    fn check_type_id_eq_runtime() {
+        let i = kani::any_where(|&i: &usize| i < TYPE_IDS.len());
+        let j = kani::any_where(|&j: &usize| j < TYPE_IDS.len());
+        assert_eq!(TYPE_IDS[i] == TYPE_IDS[j], i == j);
+    }
+
+    // `downcast_ref_unchecked` and `downcast_mut_unchecked` proofs
+
+    macro_rules! check_downcast_unchecked {
+        ($module:ident, $dyn:ty) => {
+            mod $module {
+                use super::*;
+
+                #[kani::proof_for_contract(<$dyn>::downcast_ref_unchecked)]
+                fn check_downcast_ref_unchecked() {
+                    let (a, b): (u8, u16) = kani::any();
+                    let any: &$dyn = if kani::any() { &a } else { &b };
+                    let _ = unsafe { any.downcast_ref_unchecked::<u8>() };
+                }
+
+                #[kani::proof_for_contract(<$dyn>::downcast_mut_unchecked)]
+                fn check_downcast_mut_unchecked() {
+                    let (mut a, mut b): (u8, u16) = kani::any();
+                    let any: &mut $dyn = if kani::any() { &mut a } else { &mut b };
+                    let _ = unsafe { any.downcast_mut_unchecked::<u8>() };
+                }
+
+                #[kani::proof]
+                #[kani::stub_verified(<$dyn>::downcast_ref_unchecked)]
+                #[kani::should_panic]
+                fn check_downcast_ref_unchecked_mismatch() {
+                    let b: u16 = kani::any();
+                    let any: &$dyn = &b;
+                    let _ = unsafe { any.downcast_ref_unchecked::<u8>() };
+                }
+            }
+        };
+    }
+
+    check_downcast_unchecked!(dyn_any, dyn Any);
+    check_downcast_unchecked!(dyn_any_send, dyn Any + Send);
+    check_downcast_unchecked!(dyn_any_send_sync, dyn Any + Send + Sync);
+}
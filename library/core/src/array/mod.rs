@@ -4,6 +4,8 @@
 
 #![stable(feature = "core_array", since = "1.35.0")]
 
+use safety::{ensures, requires};
+
 use crate::borrow::{Borrow, BorrowMut};
 use crate::cmp::Ordering;
 use crate::convert::Infallible;
@@ -12,12 +14,15 @@ use crate::fmt;
 use crate::hash::{self, Hash};
 use crate::intrinsics::transmute_unchecked;
 use crate::iter::{UncheckedIterator, repeat_n};
+#[cfg(kani)]
+use crate::kani;
 use crate::mem::{self, MaybeUninit};
 use crate::ops::{
     ChangeOutputType, ControlFlow, FromResidual, Index, IndexMut, NeverShortCircuit, Residual, Try,
 };
 use crate::ptr::{null, null_mut};
 use crate::slice::{Iter, IterMut};
+use crate::ub_checks::Invariant;
 
 mod ascii;
 mod drain;
@@ -162,6 +167,7 @@ where
 /// Converts a reference to `T` into a reference to an array of length 1 (without copying).
 #[stable(feature = "array_from_ref", since = "1.53.0")]
 #[rustc_const_stable(feature = "const_array_from_ref_shared", since = "1.63.0")]
+#[ensures(|result: &&[T; 1]| crate::ptr::eq(&result[0], s))]
 pub const fn from_ref<T>(s: &T) -> &[T; 1] {
     // SAFETY: Converting `&T` to `&[T; 1]` is sound.
     unsafe { &*(s as *const T).cast::<[T; 1]>() }
@@ -170,6 +176,7 @@ pub const fn from_ref<T>(s: &T) -> &[T; 1] {
 /// Converts a mutable reference to `T` into a mutable reference to an array of length 1 (without copying).
 #[stable(feature = "array_from_ref", since = "1.53.0")]
 #[rustc_const_stable(feature = "const_array_from_ref", since = "1.83.0")]
+#[ensures(|result: &&mut [T; 1]| crate::ptr::eq(&result[0], old(s as *const T)))]
 pub const fn from_mut<T>(s: &mut T) -> &mut [T; 1] {
     // SAFETY: Converting `&mut T` to `&mut [T; 1]` is sound.
     unsafe { &mut *(s as *mut T).cast::<[T; 1]>() }
@@ -907,6 +914,15 @@ struct Guard<'a, T> {
     pub initialized: usize,
 }
 
+#[unstable(feature = "ub_checks", issue = "none")]
+impl<T> Invariant for Guard<'_, T> {
+    /// Exactly the first `initialized` slots hold values, so the count can never exceed
+    /// the buffer.
+    fn is_safe(&self) -> bool {
+        self.initialized <= self.array_mut.len()
+    }
+}
+
 impl<T> Guard<'_, T> {
     /// Adds an item to the array and updates the initialized item counter.
     ///
@@ -914,6 +930,9 @@ impl<T> Guard<'_, T> {
     ///
     /// No more than N elements must be initialized.
     #[inline]
+    #[requires(self.initialized < self.array_mut.len())]
+    #[cfg_attr(kani, kani::modifies(self, &raw mut self.array_mut[self.initialized]))]
+    #[ensures(|_| self.initialized == old(self.initialized) + 1 && self.is_safe())]
     pub(crate) unsafe fn push_unchecked(&mut self, item: T) {
         // SAFETY: If `initialized` was correct before and the caller does not
         // invoke this method more than N times then writes will be in-bounds
@@ -927,6 +946,7 @@ impl<T> Guard<'_, T> {
 
 impl<T> Drop for Guard<'_, T> {
     #[inline]
+    #[requires(self.is_safe())]
     fn drop(&mut self) {
         debug_assert!(self.initialized <= self.array_mut.len());
 
@@ -950,6 +970,9 @@ impl<T> Drop for Guard<'_, T> {
 ///
 /// Used for [`Iterator::next_chunk`].
 #[inline]
+#[ensures(|result: &Result<[T; N], IntoIter<T, N>>| {
+    result.as_ref().err().is_none_or(|rest| rest.len() < N)
+})]
 pub(crate) fn iter_next_chunk<T, const N: usize>(
     iter: &mut impl Iterator<Item = T>,
 ) -> Result<[T; N], IntoIter<T, N>> {
@@ -973,6 +996,8 @@ pub(crate) fn iter_next_chunk<T, const N: usize>(
 /// Unfortunately this loop has two exit conditions, the buffer filling up
 /// or the iterator running out of items, making it tend to optimize poorly.
 #[inline]
+#[cfg_attr(kani, kani::modifies(buffer, iter))]
+#[ensures(|result: &Result<(), usize>| result.err().is_none_or(|initialized| initialized < buffer.len()))]
 fn iter_next_chunk_erased<T>(
     buffer: &mut [MaybeUninit<T>],
     iter: &mut impl Iterator<Item = T>,
@@ -994,3 +1019,166 @@ fn iter_next_chunk_erased<T>(
     mem::forget(guard);
     Ok(())
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::cell::Cell;
+
+    // `Guard` and `map` proofs
+
+    const N: usize = 3;
+
+    struct Counted<'a> {
+        drops: &'a Cell<usize>,
+    }
+
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    #[kani::proof_for_contract(from_ref)]
+    fn check_from_ref() {
+        let x: u8 = kani::any();
+        let _ = from_ref(&x);
+    }
+
+    #[kani::proof_for_contract(from_mut)]
+    fn check_from_mut() {
+        let mut x: u8 = kani::any();
+        let _ = from_mut(&mut x);
+    }
+
+    #[kani::proof_for_contract(Guard::<u8>::push_unchecked)]
+    fn check_push_unchecked() {
+        let mut buffer = [const { MaybeUninit::<u8>::uninit() }; N];
+        let initialized = kani::any_where(|&initialized: &usize| initialized < N);
+        let mut guard = Guard { array_mut: &mut buffer, initialized };
+        unsafe { guard.push_unchecked(kani::any()) };
+        mem::forget(guard);
+    }
+
+    #[kani::proof_for_contract(<Guard<'_, Counted<'_>> as Drop>::drop)]
+    #[kani::unwind(4)]
+    fn check_guard_drop() {
+        let drops = Cell::new(0);
+        let mut buffer = [const { MaybeUninit::<Counted<'_>>::uninit() }; N];
+        let initialized = kani::any_where(|&initialized: &usize| initialized <= N);
+        for slot in &mut buffer[..initialized] {
+            slot.write(Counted { drops: &drops });
+        }
+        drop(Guard { array_mut: &mut buffer, initialized });
+        assert_eq!(drops.get(), initialized);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(4)]
+    fn check_map() {
+        let drops = Cell::new(0);
+        let values: [u8; N] = kani::any();
+        let offset: u8 = kani::any();
+        let mapped = values.map(|v| (v.wrapping_add(offset), Counted { drops: &drops }));
+        assert_eq!(drops.get(), 0);
+        for (i, (v, _)) in mapped.iter().enumerate() {
+            assert_eq!(*v, values[i].wrapping_add(offset));
+        }
+        drop(mapped);
+        assert_eq!(drops.get(), N);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(4)]
+    fn check_try_map_short_circuit() {
+        let drops = Cell::new(0);
+        let input: [Counted<'_>; N] = from_fn(|_| Counted { drops: &drops });
+        let fail_at: usize = kani::any();
+        let mut index = 0;
+        let result = input.try_map(|item| {
+            index += 1;
+            if index - 1 == fail_at { None } else { Some(item) }
+        });
+        if fail_at < N {
+            // The failing input, the inputs never reached, and the outputs already
+            // written are each dropped exactly once.
+            assert!(result.is_none());
+            assert_eq!(drops.get(), N);
+        } else {
+            assert!(result.is_some());
+            assert_eq!(drops.get(), 0);
+            drop(result);
+            assert_eq!(drops.get(), N);
+        }
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    #[kani::unwind(4)]
+    fn check_map_panic() {
+        let values: [u8; N] = kani::any();
+        let panic_at: usize = kani::any_where(|&panic_at: &usize| panic_at < N);
+        let mut index = 0;
+        let _ = values.map(|v| {
+            assert_ne!(index, panic_at);
+            index += 1;
+            v
+        });
+    }
+
+    #[kani::proof]
+    #[kani::unwind(4)]
+    fn check_try_from_fn_early_exit() {
+        let drops = Cell::new(0);
+        let fail_at: usize = kani::any();
+        let result: Result<[Counted<'_>; N], usize> =
+            try_from_fn(|i| if i == fail_at { Err(i) } else { Ok(Counted { drops: &drops }) });
+        if fail_at < N {
+            // Only the elements produced before the failure existed, and each was dropped.
+            assert_eq!(result.err(), Some(fail_at));
+            assert_eq!(drops.get(), fail_at);
+        } else {
+            assert!(result.is_ok());
+            assert_eq!(drops.get(), 0);
+            drop(result);
+            assert_eq!(drops.get(), N);
+        }
+    }
+
+    #[kani::proof_for_contract(iter_next_chunk_erased)]
+    #[kani::unwind(5)]
+    fn check_iter_next_chunk_erased() {
+        let values: [u8; N + 1] = kani::any();
+        let mut iter = kani::slice::any_slice_of_array(&values).iter().copied();
+        let mut buffer = [const { MaybeUninit::<u8>::uninit() }; N];
+        let _ = iter_next_chunk_erased(&mut buffer, &mut iter);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_iter_next_chunk() {
+        let drops = Cell::new(0);
+        let items: [Counted<'_>; N + 1] = from_fn(|_| Counted { drops: &drops });
+        let len = kani::any_where(|&len: &usize| len <= N + 1);
+        let mut iter = items.into_iter().take(len);
+        // `take` drops the items past `len` once the iterator itself is dropped.
+        match iter_next_chunk::<_, N>(&mut iter) {
+            Ok(chunk) => {
+                assert!(len >= N);
+                assert_eq!(drops.get(), 0);
+                drop(chunk);
+                assert_eq!(drops.get(), N);
+            }
+            Err(rest) => {
+                // A short chunk hands back exactly the items it pulled, still alive.
+                assert_eq!(rest.len(), len);
+                assert_eq!(drops.get(), 0);
+                drop(rest);
+                assert_eq!(drops.get(), len);
+            }
+        }
+        drop(iter);
+        assert_eq!(drops.get(), N + 1);
+    }
+}
@@ -1,6 +1,14 @@
+use safety::ensures;
+#[cfg(kani)]
+use safety::requires;
+
 use super::UnsafeCell;
 use crate::hint::unreachable_unchecked;
+#[cfg(kani)]
+use crate::kani;
 use crate::ops::{Deref, DerefMut};
+#[cfg(kani)]
+use crate::ptr;
 use crate::{fmt, mem};
 
 enum State<T, F> {
@@ -105,6 +113,7 @@ impl<T, F: FnOnce() -> T> LazyCell<T, F> {
     /// ```
     #[inline]
     #[stable(feature = "lazy_cell", since = "1.80.0")]
+    #[ensures(|result| LazyCell::get(this).is_some_and(|value| ptr::eq(value, *result)))]
     pub fn force(this: &LazyCell<T, F>) -> &T {
         // SAFETY:
         // This invalidates any mutable references to the data. The resulting
@@ -188,6 +197,8 @@ impl<T, F: FnOnce() -> T> LazyCell<T, F> {
     /// # Safety
     /// May only be called when the state is `Uninit`.
     #[cold]
+    #[cfg_attr(kani, requires(LazyCell::is_uninit(this)))]
+    #[ensures(|result| LazyCell::get(this).is_some_and(|value| ptr::eq(value, *result)))]
     unsafe fn really_init(this: &LazyCell<T, F>) -> &T {
         // SAFETY:
         // This function is only called when the state is uninitialized,
@@ -219,6 +230,14 @@ impl<T, F: FnOnce() -> T> LazyCell<T, F> {
 }
 
 impl<T, F> LazyCell<T, F> {
+    /// Whether the initialization function is still present, i.e. the cell has
+    /// neither been forced nor poisoned.
+    #[cfg(kani)]
+    fn is_uninit(this: &LazyCell<T, F>) -> bool {
+        // SAFETY: Only used in contracts, where no mutable reference to the state exists.
+        matches!(unsafe { &*this.state.get() }, State::Uninit(_))
+    }
+
     /// Returns a mutable reference to the value if initialized, or `None` if not.
     ///
     /// # Examples
@@ -318,3 +337,113 @@ impl<T: fmt::Debug, F> fmt::Debug for LazyCell<T, F> {
 const fn panic_poisoned() -> ! {
     panic!("LazyCell instance has previously been poisoned")
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::cell::Cell;
+
+    // The state enum makes "initialization function present xor value present"
+    // hold by construction; these proofs check that `force` only ever moves from
+    // the former to the latter, and does so exactly once.
+
+    fn poisoned<T, F>() -> LazyCell<T, F> {
+        LazyCell { state: UnsafeCell::new(State::Poisoned) }
+    }
+
+    #[kani::proof_for_contract(LazyCell::force)]
+    fn check_force() {
+        let value: u64 = kani::any();
+        let cell = LazyCell::new(move || value);
+        if kani::any() {
+            LazyCell::force(&cell);
+        }
+        assert_eq!(*LazyCell::force(&cell), value);
+    }
+
+    #[kani::proof_for_contract(LazyCell::really_init)]
+    fn check_really_init() {
+        let value: u64 = kani::any();
+        let cell = LazyCell::new(move || value);
+        assert_eq!(*unsafe { LazyCell::really_init(&cell) }, value);
+    }
+
+    #[kani::proof]
+    fn check_force_runs_once() {
+        let runs = Cell::new(0);
+        let value: [u32; 2] = kani::any();
+        let cell = LazyCell::new(|| {
+            runs.set(runs.get() + 1);
+            value
+        });
+        assert!(LazyCell::get(&cell).is_none());
+        assert_eq!(*cell, value);
+        assert_eq!(*cell, value);
+        assert_eq!(LazyCell::get(&cell), Some(&value));
+        assert_eq!(runs.get(), 1);
+    }
+
+    #[kani::proof]
+    fn check_force_mut() {
+        let value: u32 = kani::any();
+        let mut cell = LazyCell::new(move || value);
+        if kani::any() {
+            LazyCell::force(&cell);
+        }
+        *LazyCell::force_mut(&mut cell) = value.wrapping_add(1);
+        assert_eq!(LazyCell::get_mut(&mut cell), Some(&mut value.wrapping_add(1)));
+    }
+
+    #[kani::proof]
+    fn check_into_inner() {
+        let value: u32 = kani::any();
+        let cell = LazyCell::new(move || value);
+        let forced: bool = kani::any();
+        if forced {
+            LazyCell::force(&cell);
+        }
+        match LazyCell::into_inner(cell) {
+            Ok(v) => assert!(forced && v == value),
+            Err(f) => assert!(!forced && f() == value),
+        }
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_force_poisoned() {
+        let cell: LazyCell<u32, fn() -> u32> = poisoned();
+        LazyCell::force(&cell);
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_force_mut_poisoned() {
+        let mut cell: LazyCell<u32, fn() -> u32> = poisoned();
+        LazyCell::force_mut(&mut cell);
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_into_inner_poisoned() {
+        let cell: LazyCell<u32, fn() -> u32> = poisoned();
+        let _ = LazyCell::into_inner(cell);
+    }
+
+    /// A broken `force` that skips the state check and always runs the
+    /// initialization path.
+    fn force_unchecked<T, F: FnOnce() -> T>(this: &LazyCell<T, F>) -> &T {
+        unsafe { LazyCell::really_init(this) }
+    }
+
+    // Forcing twice through the broken stub calls `really_init` on a cell that
+    // is already initialized, which must be caught.
+    #[kani::proof]
+    #[kani::should_panic]
+    #[kani::stub(LazyCell::force, force_unchecked)]
+    fn check_force_twice_broken() {
+        let cell = LazyCell::new(|| 1u32);
+        LazyCell::force(&cell);
+        LazyCell::force(&cell);
+    }
+}
@@ -1,5 +1,7 @@
 //! impl char {}
 
+use safety::ensures;
+
 use super::*;
 #[cfg(kani)]
 use crate::kani;
@@ -401,6 +403,7 @@ impl char {
     #[must_use = "this returns the result of the operation, \
                   without modifying the original"]
     #[inline]
+    #[ensures(|result| result.is_none_or(|digit| digit < radix && self.is_ascii_alphanumeric()))]
     pub const fn to_digit(self, radix: u32) -> Option<u32> {
         assert!(
             radix >= 2 && radix <= 36,
@@ -1936,8 +1939,6 @@ pub const fn encode_utf16_raw(mut code: u32, dst: &mut [u16]) -> &mut [u16] {
 #[cfg(kani)]
 #[unstable(feature = "kani", issue = "none")]
 mod verify {
-    use safety::ensures;
-
     use super::*;
 
     #[ensures(|result| c.is_ascii() == (result.is_some() && (result.unwrap() as u8 as char == *c)))]
@@ -1956,4 +1957,44 @@ mod verify {
         let non_ascii: char = kani::any_where(|c: &char| !c.is_ascii());
         as_ascii_clone(&non_ascii);
     }
+
+    // The case mapping iterators yield between one and three chars, and agree with the
+    // ASCII conversions on ASCII input. Symbolic chars are limited to the first two planes,
+    // which contain every key of the case mapping tables.
+    macro_rules! check_case_mapping {
+        ($name:ident, $to_case:ident, $to_ascii_case:ident) => {
+            #[kani::proof]
+            #[kani::unwind(12)]
+            fn $name() {
+                let c = kani::any_where(|c: &char| (*c as u32) < 0x20000);
+                let mapping = c.$to_case();
+                let len = mapping.len();
+                assert!(len >= 1 && len <= 3);
+                assert_eq!(mapping.count(), len);
+                if c.is_ascii() {
+                    let mut mapping = c.$to_case();
+                    assert_eq!(mapping.next(), Some(c.$to_ascii_case()));
+                    assert_eq!(mapping.next(), None);
+                }
+            }
+        };
+    }
+
+    check_case_mapping!(check_to_lowercase, to_lowercase, to_ascii_lowercase);
+    check_case_mapping!(check_to_uppercase, to_uppercase, to_ascii_uppercase);
+
+    #[kani::proof_for_contract(char::to_digit)]
+    fn check_to_digit() {
+        let c: char = kani::any();
+        let radix: u32 = kani::any_where(|r| (2..=36).contains(r));
+        c.to_digit(radix);
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_to_digit_invalid_radix() {
+        let c: char = kani::any();
+        let radix: u32 = kani::any_where(|r| !(2..=36).contains(r));
+        c.to_digit(radix);
+    }
 }
@@ -29,6 +29,8 @@ mod bytewise;
 pub(crate) use bytewise::BytewiseEq;
 
 use self::Ordering::*;
+#[cfg(kani)]
+use crate::kani;
 use crate::marker::PointeeSized;
 use crate::ops::ControlFlow;
 
@@ -664,6 +666,14 @@ impl Ordering {
 #[repr(transparent)]
 pub struct Reverse<T>(#[stable(feature = "reverse_cmp_key", since = "1.19.0")] pub T);
 
+#[cfg(kani)]
+#[stable(feature = "reverse_cmp_key", since = "1.19.0")]
+impl<T: kani::Arbitrary> kani::Arbitrary for Reverse<T> {
+    fn any() -> Self {
+        Self { 0: kani::any() }
+    }
+}
+
 #[stable(feature = "reverse_cmp_key", since = "1.19.0")]
 impl<T: PartialOrd> PartialOrd for Reverse<T> {
     #[inline]
@@ -1801,6 +1811,8 @@ where
 
 // Implementation of PartialEq, Eq, PartialOrd and Ord for primitive types
 mod impls {
+    use safety::ensures;
+
     use crate::cmp::Ordering::{self, Equal, Greater, Less};
     use crate::hint::unreachable_unchecked;
     use crate::marker::PointeeSized;
@@ -1979,6 +1991,8 @@ mod impls {
         }
 
         #[inline]
+        #[ensures(|result| min <= *result && *result <= max)]
+        #[ensures(|result| !(min <= self && self <= max) || *result == self)]
         fn clamp(self, min: bool, max: bool) -> bool {
             assert!(min <= max);
             self.max(min).min(max)
@@ -2190,3 +2204,59 @@ mod impls {
         }
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // Maps the harnesses of `cmp/laws.rs` to proofs. `coretests` maps the same harnesses to
+    // randomized tests, so they also run under Miri.
+    macro_rules! dual_proof {
+        (#[should_panic] fn $harness:ident() $body:block) => {
+            #[kani::proof]
+            #[kani::should_panic]
+            fn $harness() $body
+        };
+        (fn $harness:ident() $body:block) => {
+            #[kani::proof]
+            fn $harness() $body
+        };
+    }
+
+    mod laws {
+        use crate::kani;
+
+        include!("cmp/laws.rs");
+    }
+
+    // `Ord::clamp` proofs
+    #[kani::proof_for_contract(<bool as Ord>::clamp)]
+    fn check_clamp_bool() {
+        let (min, max): (bool, bool) = kani::any();
+        kani::assume(min <= max);
+        let _ = kani::any::<bool>().clamp(min, max);
+    }
+
+    macro_rules! check_clamp {
+        ($($harness:ident: $ty:ty),+ $(,)?) => {$(
+            #[kani::proof]
+            fn $harness() {
+                let (value, min, max): ($ty, $ty, $ty) = kani::any();
+                kani::assume(min <= max);
+                let result = value.clone().clamp(min.clone(), max.clone());
+                assert!(min <= result && result <= max);
+                assert!(!(min <= value && value <= max) || result == value);
+            }
+        )+};
+    }
+
+    check_clamp! {
+        check_clamp_u8: u8,
+        check_clamp_i32: i32,
+        check_clamp_char: char,
+        check_clamp_tuple: (u8, i8),
+        check_clamp_array: [u8; 2],
+        check_clamp_reverse: Reverse<i8>,
+    }
+}
@@ -0,0 +1,91 @@
+// Comparison harnesses shared by Kani and Miri.
+//
+// This file is included by `cmp::verify` in `core` and by `kani_miri` in `coretests`. Each site
+// provides `kani::any`, `kani::assume`, `kani::Arbitrary` and a `dual_proof!` macro: under Kani the
+// harnesses are proofs over symbolic values, under Miri they are tests over random values. Only
+// the public API may be used here.
+
+use core::cmp::Ordering::{Equal, Greater, Less};
+use core::cmp::Reverse;
+use core::fmt::Debug;
+
+// Total order laws for the comparison chains of tuples, arrays and `Reverse`, checked on
+// symbolic values of small element types:
+// - `partial_cmp` is `Some(cmp)` and `==` agrees with `Equal`,
+// - `cmp` is antisymmetric and the operators agree with it,
+// - `cmp` is transitive,
+// - `min`/`max` return one of their arguments, in the right order.
+fn check_total_order<T: Ord + Clone + Debug + kani::Arbitrary>() {
+    let (a, b, c): (T, T, T) = (kani::any(), kani::any(), kani::any());
+
+    assert_eq!(a.partial_cmp(&b), Some(a.cmp(&b)));
+    assert_eq!(a == b, a.cmp(&b) == Equal);
+    assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+    assert_eq!(a < b, a.cmp(&b) == Less);
+    assert_eq!(a <= b, a.cmp(&b) != Greater);
+    assert_eq!(a > b, a.cmp(&b) == Greater);
+    assert_eq!(a >= b, a.cmp(&b) != Less);
+
+    if a <= b && b <= c {
+        assert!(a <= c);
+    }
+    if a < b && b <= c {
+        assert!(a < c);
+    }
+
+    let min = a.clone().min(b.clone());
+    let max = a.clone().max(b.clone());
+    assert!(min <= max);
+    assert!((min == a && max == b) || (min == b && max == a));
+}
+
+macro_rules! check_total_order {
+    ($($harness:ident: $ty:ty),+ $(,)?) => {$(
+        dual_proof! {
+            fn $harness() {
+                check_total_order::<$ty>();
+            }
+        }
+    )+};
+}
+
+check_total_order! {
+    check_total_order_pair: (u8, i8),
+    check_total_order_triple: (bool, u8, char),
+    check_total_order_nested_tuple: ((u8, bool), i8),
+    check_total_order_array: [u8; 3],
+    check_total_order_array_of_pairs: [(bool, u8); 2],
+    check_total_order_empty_array: [u8; 0],
+    check_total_order_reverse: Reverse<i8>,
+    check_total_order_reverse_tuple: Reverse<(u8, u8)>,
+    check_total_order_tuple_of_reverse: (Reverse<u8>, u8),
+}
+
+dual_proof! {
+    fn check_reverse_inverts_order() {
+        let (a, b): (i16, i16) = kani::any();
+        assert_eq!(Reverse(a).cmp(&Reverse(b)), b.cmp(&a));
+        assert_eq!(Reverse(a) < Reverse(b), b < a);
+    }
+}
+
+// Tuples and arrays compare lexicographically: the first unequal component decides.
+dual_proof! {
+    fn check_lexicographic_order() {
+        let (a, b): ([u8; 3], [u8; 3]) = kani::any();
+        let expected = a[0].cmp(&b[0]).then(a[1].cmp(&b[1])).then(a[2].cmp(&b[2]));
+        assert_eq!(a.cmp(&b), expected);
+        assert_eq!((a[0], a[1], a[2]).cmp(&(b[0], b[1], b[2])), expected);
+    }
+}
+
+dual_proof! {
+    #[should_panic]
+    fn check_clamp_inverted_bounds() {
+        // Built rather than assumed, so that every random sample is inverted.
+        let (value, min, max): (u8, u8, u8) = kani::any();
+        let min = min.max(1);
+        let max = max % min;
+        let _ = value.clamp(min, max);
+    }
+}
@@ -1,10 +1,15 @@
 //! Helper code for character escaping.
 
+use safety::ensures;
+
 use crate::ascii;
 use crate::fmt::{self, Write};
+#[cfg(kani)]
+use crate::kani;
 use crate::marker::PhantomData;
 use crate::num::NonZero;
 use crate::ops::Range;
+use crate::ub_checks::Invariant;
 
 const HEX_DIGITS: [ascii::Char; 16] = *b"0123456789abcdef".as_ascii().unwrap();
 
@@ -285,7 +290,27 @@ impl<const N: usize, ESCAPING> EscapeIterInner<N, ESCAPING> {
     }
 }
 
+#[unstable(feature = "ub_checks", issue = "none")]
+impl<const N: usize> Invariant for EscapeIterInner<N, AlwaysEscaped> {
+    /// `alive` must lie within the buffer and only cover printable ASCII, which is what
+    /// lets `next` and `next_back` index it without bounds checks.
+    fn is_safe(&self) -> bool {
+        // SAFETY: Only `MaybeEscaped` iterators hold the `literal` variant.
+        let escape_seq = unsafe { &self.data.escape_seq };
+        self.alive.start <= self.alive.end
+            && usize::from(self.alive.end) <= N
+            && escape_seq[usize::from(self.alive.start)..usize::from(self.alive.end)]
+                .iter()
+                .all(|c| (0x20..0x7f).contains(&c.to_u8()))
+    }
+}
+
 impl<const N: usize> EscapeIterInner<N, AlwaysEscaped> {
+    #[cfg_attr(kani, kani::modifies(self))]
+    #[ensures(|_| self.is_safe())]
+    #[ensures(|b| b.is_some() == (old(self.len()) > 0))]
+    #[ensures(|b| self.len() == old(self.len()) - b.is_some() as usize)]
+    #[ensures(|b| b.is_none_or(|b| (0x20..0x7f).contains(&b)))]
     pub(crate) fn next(&mut self) -> Option<u8> {
         let i = self.alive.next()?;
 
@@ -296,6 +321,11 @@ impl<const N: usize> EscapeIterInner<N, AlwaysEscaped> {
         unsafe { Some(self.data.escape_seq.get_unchecked(usize::from(i)).to_u8()) }
     }
 
+    #[cfg_attr(kani, kani::modifies(self))]
+    #[ensures(|_| self.is_safe())]
+    #[ensures(|b| b.is_some() == (old(self.len()) > 0))]
+    #[ensures(|b| self.len() == old(self.len()) - b.is_some() as usize)]
+    #[ensures(|b| b.is_none_or(|b| (0x20..0x7f).contains(&b)))]
     pub(crate) fn next_back(&mut self) -> Option<u8> {
         let i = self.alive.next_back()?;
 
@@ -369,3 +399,110 @@ impl<const N: usize> fmt::Debug for EscapeIterInner<N, MaybeEscaped> {
         f.debug_tuple("EscapeIterInner").field(&format_args!("'{}'", self)).finish()
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // `EscapeIterInner` proofs
+
+    /// An escape of a symbolic byte or char, partially consumed from either end.
+    fn any_escape() -> EscapeIterInner<10, AlwaysEscaped> {
+        let mut iter = if kani::any() {
+            EscapeIterInner::ascii(kani::any())
+        } else {
+            EscapeIterInner::unicode(kani::any())
+        };
+        let _ = iter.advance_by(kani::any_where(|&n: &usize| n <= 10));
+        let _ = iter.advance_back_by(kani::any_where(|&n: &usize| n <= 10));
+        iter
+    }
+
+    #[kani::proof_for_contract(EscapeIterInner::<10, AlwaysEscaped>::next)]
+    fn check_next() {
+        let mut iter = any_escape();
+        let _ = iter.next();
+    }
+
+    #[kani::proof_for_contract(EscapeIterInner::<10, AlwaysEscaped>::next_back)]
+    fn check_next_back() {
+        let mut iter = any_escape();
+        let _ = iter.next_back();
+    }
+
+    /// Drains `iter`, checking that `len` counts the remaining items before every step.
+    fn drain<T: Copy + Default, I: ExactSizeIterator<Item = T>>(mut iter: I) -> ([T; 10], usize) {
+        let mut out = [T::default(); 10];
+        let len = iter.len();
+        assert!(len <= out.len());
+        for i in 0..len {
+            assert_eq!(iter.len(), len - i);
+            out[i] = iter.next().unwrap();
+        }
+        assert!(iter.next().is_none());
+        assert_eq!(iter.len(), 0);
+        (out, len)
+    }
+
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_ascii_escape_default() {
+        let b: u8 = kani::any();
+        let (out, len) = drain(ascii::escape_default(b));
+        let out = &out[..len];
+        assert!(out.iter().all(|b| (0x20..0x7f).contains(b)));
+        match b {
+            b'\t' => assert_eq!(out, b"\\t"),
+            b'\r' => assert_eq!(out, b"\\r"),
+            b'\n' => assert_eq!(out, b"\\n"),
+            b'\\' | b'\'' | b'"' => assert_eq!(out, [b'\\', b]),
+            0x20..=0x7e => assert_eq!(out, [b]),
+            _ => {
+                assert_eq!(out, [b'\\', b'x', HEX[usize::from(b >> 4)], HEX[usize::from(b & 0xf)]])
+            }
+        }
+    }
+
+    #[kani::proof]
+    #[kani::unwind(11)]
+    fn check_char_escape_unicode() {
+        let c: char = kani::any();
+        let (out, len) = drain(c.escape_unicode());
+        let out = &out[..len];
+        assert!(len >= 4);
+        assert_eq!(out[..3], ['\\', 'u', '{']);
+        assert_eq!(out[len - 1], '}');
+        // Lowercase hex digits without leading zeros.
+        let digits = &out[3..len - 1];
+        assert!(digits.len() == 1 || digits[0] != '0');
+        let mut value = 0;
+        for &d in digits {
+            assert!(d.is_ascii() && HEX.contains(&(d as u8)));
+            value = value * 16 + d.to_digit(16).unwrap();
+        }
+        assert_eq!(value, c as u32);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(11)]
+    fn check_char_escape_default() {
+        let c: char = kani::any();
+        let (out, len) = drain(c.escape_default());
+        let out = &out[..len];
+        assert!(out.iter().all(|c| matches!(c, '\x20'..='\x7e')));
+        match c {
+            '\t' => assert_eq!(out, ['\\', 't']),
+            '\r' => assert_eq!(out, ['\\', 'r']),
+            '\n' => assert_eq!(out, ['\\', 'n']),
+            '\\' | '\'' | '"' => assert_eq!(out, ['\\', c]),
+            '\x20'..='\x7e' => assert_eq!(out, [c]),
+            _ => {
+                let (unicode, unicode_len) = drain(c.escape_unicode());
+                assert_eq!(out, &unicode[..unicode_len]);
+            }
+        }
+    }
+}
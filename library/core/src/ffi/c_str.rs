@@ -302,7 +302,7 @@ impl CStr {
     #[must_use]
     #[stable(feature = "rust1", since = "1.0.0")]
     #[rustc_const_stable(feature = "const_cstr_from_ptr", since = "1.81.0")]
-    #[requires(!ptr.is_null() && is_null_terminated(ptr))]
+    #[cfg_attr(kani, requires(!ptr.is_null() && is_null_terminated(ptr)))]
     #[ensures(|result: &&CStr| result.is_safe())]
     pub const unsafe fn from_ptr<'a>(ptr: *const c_char) -> &'a CStr {
         // SAFETY: The caller has provided a pointer that points to a valid C
@@ -780,7 +780,7 @@ impl AsRef<CStr> for CStr {
 #[inline]
 #[unstable(feature = "cstr_internals", issue = "none")]
 #[rustc_allow_const_fn_unstable(const_eval_select)]
-#[requires(is_null_terminated(ptr))]
+#[cfg_attr(kani, requires(is_null_terminated(ptr)))]
 #[ensures(|&result| result < isize::MAX as usize && unsafe { *ptr.add(result) } == 0)]
 const unsafe fn strlen(ptr: *const c_char) -> usize {
     const_eval_select!(
@@ -8,6 +8,8 @@ use crate::fmt;
 use crate::intrinsics::{va_arg, va_copy, va_end};
 use crate::marker::{PhantomData, PhantomInvariantLifetime};
 use crate::ops::{Deref, DerefMut};
+#[cfg(kani)]
+use crate::ub_checks::Invariant;
 
 // The name is WIP, using `VaListImpl` for now.
 //
@@ -232,15 +234,50 @@ unsafe impl VaArgSafe for f64 {}
 unsafe impl<T> VaArgSafe for *mut T {}
 unsafe impl<T> VaArgSafe for *const T {}
 
+// Layout of the x86_64 System V register save area: 6 general purpose registers of 8 bytes,
+// followed by 8 SSE registers of 16 bytes. See section 3.5.7 of the System V AMD64 ABI.
+#[cfg(all(kani, target_arch = "x86_64", not(target_os = "uefi"), not(windows)))]
+const GP_AREA_END: i32 = 6 * 8;
+#[cfg(all(kani, target_arch = "x86_64", not(target_os = "uefi"), not(windows)))]
+const FP_AREA_END: i32 = GP_AREA_END + 8 * 16;
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+impl<'f> Invariant for VaListImpl<'f> {
+    /// On x86_64 System V targets, the register offsets must point at a register slot inside
+    /// their section of the register save area, and the overflow area must be 8-byte aligned.
+    ///
+    /// The layout of the other targets is not modeled yet.
+    fn is_safe(&self) -> bool {
+        crate::cfg_select! {
+            all(target_arch = "x86_64", not(target_os = "uefi"), not(windows)) => {
+                self.gp_offset >= 0
+                    && self.gp_offset <= GP_AREA_END
+                    && self.gp_offset % 8 == 0
+                    && self.fp_offset >= GP_AREA_END
+                    && self.fp_offset <= FP_AREA_END
+                    && self.fp_offset % 16 == 0
+                    && !self.reg_save_area.is_null()
+                    && self.overflow_arg_area.is_aligned_to(8)
+            }
+            _ => {
+                true
+            }
+        }
+    }
+}
+
 impl<'f> VaListImpl<'f> {
     /// Advance to the next arg.
     #[inline]
+    #[safety::requires(self.is_safe())]
     pub unsafe fn arg<T: VaArgSafe>(&mut self) -> T {
         // SAFETY: the caller must uphold the safety contract for `va_arg`.
         unsafe { va_arg(self) }
     }
 
     /// Copies the `va_list` at the current location.
+    #[safety::requires(self.is_safe())]
     pub unsafe fn with_copy<F, R>(&self, f: F) -> R
     where
         F: for<'copy> FnOnce(VaList<'copy, 'f>) -> R,
@@ -281,3 +318,155 @@ impl<'f> Drop for VaListImpl<'f> {
         // This works for now, since `va_end` is a no-op on all current LLVM targets.
     }
 }
+
+/// Kani cannot execute the `va_arg` family of intrinsics, since their lowering is target specific.
+/// Instead, these harnesses verify a model of the x86_64 System V lowering over a symbolic
+/// register save area and overflow area, showing that every read stays within these buffers
+/// as long as the `VaListImpl` invariant holds.
+#[cfg(all(kani, target_arch = "x86_64", not(target_os = "uefi"), not(windows)))]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::kani;
+
+    /// Size of the register save area in 8-byte words.
+    const REG_SAVE_WORDS: usize = FP_AREA_END as usize / 8;
+    /// Number of 8-byte argument slots in the modeled overflow area.
+    const OVERFLOW_SLOTS: usize = 4;
+
+    /// Model of `va_arg` for arguments of the INTEGER class (integers and pointers).
+    unsafe fn model_va_arg_gp<T: VaArgSafe>(ap: &mut VaListImpl<'_>) -> T {
+        if ap.gp_offset < GP_AREA_END {
+            // SAFETY: the invariant guarantees the offset points into the register save area.
+            let ptr = unsafe { ap.reg_save_area.byte_add(ap.gp_offset as usize) };
+            ap.gp_offset += 8;
+            unsafe { ptr.cast::<T>().read() }
+        } else {
+            unsafe { model_va_arg_overflow(ap) }
+        }
+    }
+
+    /// Model of `va_arg` for arguments of the SSE class (`f64`).
+    unsafe fn model_va_arg_fp(ap: &mut VaListImpl<'_>) -> f64 {
+        if ap.fp_offset < FP_AREA_END {
+            // SAFETY: the invariant guarantees the offset points into the register save area.
+            let ptr = unsafe { ap.reg_save_area.byte_add(ap.fp_offset as usize) };
+            ap.fp_offset += 16;
+            unsafe { ptr.cast::<f64>().read() }
+        } else {
+            unsafe { model_va_arg_overflow(ap) }
+        }
+    }
+
+    /// Arguments that no longer fit in registers are read from the overflow area, each one
+    /// taking an 8-byte slot.
+    unsafe fn model_va_arg_overflow<T: VaArgSafe>(ap: &mut VaListImpl<'_>) -> T {
+        let ptr = ap.overflow_arg_area;
+        ap.overflow_arg_area = unsafe { ptr.byte_add(8) };
+        unsafe { ptr.cast::<T>().read() }
+    }
+
+    /// Model of `va_copy`, which is a plain copy of the `va_list` structure on this target.
+    fn model_va_copy<'f>(src: &VaListImpl<'f>) -> VaListImpl<'f> {
+        VaListImpl { ..*src }
+    }
+
+    /// Create a `va_list` with an arbitrary number of arguments already read from the register
+    /// save area, pointing at an arbitrary slot of `overflow` with `free_slots` slots left.
+    fn any_va_list<'f>(
+        reg_save_area: &mut [u64; REG_SAVE_WORDS],
+        overflow: &mut [u64; OVERFLOW_SLOTS],
+        free_slots: usize,
+    ) -> VaListImpl<'f> {
+        let gp_used: i32 = kani::any_where(|n| *n >= 0 && *n <= 6);
+        let fp_used: i32 = kani::any_where(|n| *n >= 0 && *n <= 8);
+        let ap = VaListImpl {
+            gp_offset: gp_used * 8,
+            fp_offset: GP_AREA_END + fp_used * 16,
+            overflow_arg_area: overflow
+                .as_mut_ptr()
+                .wrapping_add(OVERFLOW_SLOTS - free_slots)
+                .cast(),
+            reg_save_area: reg_save_area.as_mut_ptr().cast(),
+            _marker: PhantomInvariantLifetime::new(),
+        };
+        assert!(ap.is_safe());
+        ap
+    }
+
+    macro_rules! check_va_arg_gp {
+        ($harness:ident, $ty:ty) => {
+            #[kani::proof]
+            fn $harness() {
+                let mut reg_save_area: [u64; REG_SAVE_WORDS] = kani::any();
+                let mut overflow: [u64; OVERFLOW_SLOTS] = kani::any();
+                let mut ap = any_va_list(&mut reg_save_area, &mut overflow, 1);
+                let _: $ty = unsafe { model_va_arg_gp(&mut ap) };
+                assert!(ap.is_safe());
+            }
+        };
+    }
+
+    check_va_arg_gp!(check_va_arg_i32, i32);
+    check_va_arg_gp!(check_va_arg_i64, i64);
+    check_va_arg_gp!(check_va_arg_isize, isize);
+    check_va_arg_gp!(check_va_arg_u32, u32);
+    check_va_arg_gp!(check_va_arg_u64, u64);
+    check_va_arg_gp!(check_va_arg_usize, usize);
+    check_va_arg_gp!(check_va_arg_const_ptr, *const u8);
+    check_va_arg_gp!(check_va_arg_mut_ptr, *mut u8);
+
+    #[kani::proof]
+    fn check_va_arg_f64() {
+        let mut reg_save_area: [u64; REG_SAVE_WORDS] = kani::any();
+        let mut overflow: [u64; OVERFLOW_SLOTS] = kani::any();
+        let mut ap = any_va_list(&mut reg_save_area, &mut overflow, 1);
+        let _ = unsafe { model_va_arg_fp(&mut ap) };
+        assert!(ap.is_safe());
+    }
+
+    /// Reading a sequence of arguments of arbitrary classes never leaves the buffers, as long
+    /// as the caller passed enough arguments.
+    #[kani::proof]
+    #[kani::unwind(4)]
+    fn check_va_arg_sequence() {
+        let mut reg_save_area: [u64; REG_SAVE_WORDS] = kani::any();
+        let mut overflow: [u64; OVERFLOW_SLOTS] = kani::any();
+        let mut ap = any_va_list(&mut reg_save_area, &mut overflow, 3);
+        for _ in 0..3 {
+            if kani::any() {
+                let _: u64 = unsafe { model_va_arg_gp(&mut ap) };
+            } else {
+                let _ = unsafe { model_va_arg_fp(&mut ap) };
+            }
+            assert!(ap.is_safe());
+        }
+    }
+
+    /// A copy of a `va_list` yields the same arguments as the original, and reading from the
+    /// copy does not advance the original.
+    #[kani::proof]
+    fn check_va_copy() {
+        let mut reg_save_area: [u64; REG_SAVE_WORDS] = kani::any();
+        let mut overflow: [u64; OVERFLOW_SLOTS] = kani::any();
+        let mut ap = any_va_list(&mut reg_save_area, &mut overflow, 1);
+        let mut copy = model_va_copy(&ap);
+        let from_copy: u64 = unsafe { model_va_arg_gp(&mut copy) };
+        assert!(copy.is_safe());
+        let from_orig: u64 = unsafe { model_va_arg_gp(&mut ap) };
+        assert_eq!(from_copy, from_orig);
+        assert_eq!(copy.gp_offset, ap.gp_offset);
+        assert_eq!(copy.overflow_arg_area, ap.overflow_arg_area);
+    }
+
+    #[kani::proof]
+    fn check_va_arg_overflow_exhausted() {
+        let mut reg_save_area: [u64; REG_SAVE_WORDS] = kani::any();
+        let mut overflow: [u64; OVERFLOW_SLOTS] = kani::any();
+        let mut ap = any_va_list(&mut reg_save_area, &mut overflow, 0);
+        kani::assume(ap.gp_offset < GP_AREA_END);
+        // Arguments passed in registers can still be read after the overflow area is used up.
+        let _: i32 = unsafe { model_va_arg_gp(&mut ap) };
+        assert!(ap.is_safe());
+    }
+}
@@ -1,6 +1,8 @@
 //! Integer and floating-point number formatting
 
 use crate::fmt::NumBuffer;
+#[cfg(kani)]
+use crate::kani;
 use crate::mem::MaybeUninit;
 use crate::num::fmt as numfmt;
 use crate::ops::{Div, Rem, Sub};
@@ -906,3 +908,20 @@ fn div_rem_1e16(n: u128) -> (u128, u64) {
     let rem = n - quot * D;
     (quot, rem as u64)
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // `div_rem_1e16` replaces the 128-bit division by a multiplication with the high word of
+    // `widening_mul`, which must agree with builtin division for every input.
+    #[kani::proof]
+    fn check_div_rem_1e16() {
+        const D: u128 = 1_0000_0000_0000_0000;
+        let n: u128 = kani::any();
+        let (quot, rem) = div_rem_1e16(n);
+        assert_eq!(quot, n / D);
+        assert_eq!(rem as u128, n % D);
+    }
+}
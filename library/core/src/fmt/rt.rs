@@ -6,9 +6,14 @@
 //!
 //! Do not modify them without understanding the consequences for the format_args!() macro.
 
+use safety::{ensures, requires};
+
 use super::*;
 use crate::hint::unreachable_unchecked;
+#[cfg(kani)]
+use crate::kani;
 use crate::ptr::NonNull;
+use crate::ub_checks::Invariant;
 
 #[lang = "format_placeholder"]
 #[derive(Copy, Clone)]
@@ -148,6 +153,7 @@ impl Argument<'_> {
     }
     #[inline]
     #[track_caller]
+    #[ensures(|result| result.as_u16() == Some(*x as u16))]
     pub const fn from_usize(x: &usize) -> Argument<'_> {
         if *x > u16::MAX as usize {
             panic!("Formatting argument out of range");
@@ -206,6 +212,7 @@ impl<'a> Arguments<'a> {
     /// const _: () = if false { panic!("a {}", "a") };
     /// ```
     #[inline]
+    #[ensures(|result: &Arguments<'a>| result.is_safe())]
     pub fn new_v1<const P: usize, const A: usize>(
         pieces: &'a [&'static str; P],
         args: &'a [rt::Argument<'a>; A],
@@ -228,6 +235,7 @@ impl<'a> Arguments<'a> {
     /// const _: () = if false { panic!("a {:1}", "a") };
     /// ```
     #[inline]
+    #[requires(Arguments { pieces, fmt: Some(fmt), args }.is_safe())]
     pub unsafe fn new_v1_formatted(
         pieces: &'a [&'static str],
         args: &'a [rt::Argument<'a>],
@@ -236,3 +244,149 @@ impl<'a> Arguments<'a> {
         Arguments { pieces, fmt: Some(fmt), args }
     }
 }
+
+impl Placeholder {
+    /// Whether every index stored in this placeholder refers to an argument of
+    /// the right kind: `position` to a placeholder argument, and every
+    /// `Count::Param` to a count argument.
+    fn is_valid_for(&self, args: &[Argument<'_>]) -> bool {
+        let is_count = |count: &Count| match *count {
+            Count::Param(i) => i < args.len() && args[i].as_u16().is_some(),
+            Count::Is(_) | Count::Implied => true,
+        };
+        self.position < args.len()
+            && args[self.position].as_u16().is_none()
+            && is_count(&self.width)
+            && is_count(&self.precision)
+    }
+}
+
+#[unstable(feature = "ub_checks", issue = "none")]
+impl Invariant for Arguments<'_> {
+    /**
+     * Safety invariant of `Arguments`, as relied upon by `fmt::write`:
+     * 1. Without placeholders, every argument is a placeholder argument and
+     *    is preceded by a piece, with at most one trailing piece.
+     * 2. With placeholders, there is a piece preceding every placeholder and
+     *    every placeholder is valid for `args`.
+     */
+    fn is_safe(&self) -> bool {
+        match self.fmt {
+            None => {
+                self.pieces.len() >= self.args.len()
+                    && self.pieces.len() <= self.args.len() + 1
+                    && self.args.iter().all(|arg| arg.as_u16().is_none())
+            }
+            Some(fmt) => {
+                self.pieces.len() >= fmt.len()
+                    && fmt.iter().all(|placeholder| placeholder.is_valid_for(self.args))
+            }
+        }
+    }
+}
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    /// A formattable value with a trivial `Display` impl, to keep the
+    /// formatting machinery out of the harnesses.
+    struct Dummy;
+
+    impl Display for Dummy {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            f.write_str("d")
+        }
+    }
+
+    /// A `Write` sink that only counts the bytes written.
+    struct Sink(usize);
+
+    impl Write for Sink {
+        fn write_str(&mut self, s: &str) -> Result {
+            self.0 += s.len();
+            Ok(())
+        }
+    }
+
+    fn any_count() -> Count {
+        match kani::any::<u8>() {
+            0 => Count::Is(kani::any()),
+            1 => Count::Param(kani::any()),
+            _ => Count::Implied,
+        }
+    }
+
+    fn any_placeholder() -> Placeholder {
+        Placeholder { position: kani::any(), flags: 0, precision: any_count(), width: any_count() }
+    }
+
+    // `Arguments` constructor proofs
+
+    #[kani::proof_for_contract(Arguments::new_v1)]
+    fn check_new_v1() {
+        let args = [Argument::new_display(&Dummy)];
+        let _ = Arguments::new_v1(&["a", "b"], &args);
+    }
+
+    #[kani::proof_for_contract(Argument::from_usize)]
+    fn check_from_usize() {
+        let x: usize = kani::any_where(|x| *x <= u16::MAX as usize);
+        let _ = Argument::from_usize(&x);
+    }
+
+    #[kani::proof_for_contract(Arguments::new_v1_formatted)]
+    fn check_new_v1_formatted() {
+        let width: usize = kani::any_where(|x| *x <= u16::MAX as usize);
+        let args = [Argument::new_display(&Dummy), Argument::from_usize(&width)];
+        let fmt = [any_placeholder(), any_placeholder()];
+        let _ = unsafe { Arguments::new_v1_formatted(&["a", "b", "c"], &args, &fmt) };
+    }
+
+    #[kani::proof]
+    #[kani::unwind(4)]
+    fn check_write_matched() {
+        let args = [Argument::new_display(&Dummy), Argument::new_display(&Dummy)];
+        let arguments = Arguments::new_v1(&["a", "b"], &args);
+        assert!(arguments.is_safe());
+        let mut sink = Sink(0);
+        assert!(write(&mut sink, arguments).is_ok());
+        assert_eq!(sink.0, 4);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(4)]
+    fn check_write_formatted() {
+        let width: usize = kani::any_where(|x| *x <= 3);
+        let args = [Argument::new_display(&Dummy), Argument::from_usize(&width)];
+        let fmt = [any_placeholder(), any_placeholder()];
+        let arguments = Arguments { pieces: &["a", "b"], fmt: Some(&fmt), args: &args };
+        kani::assume(arguments.is_safe());
+        let mut sink = Sink(0);
+        assert!(write(&mut sink, arguments).is_ok());
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_position_out_of_bounds() {
+        let args = [Argument::new_display(&Dummy)];
+        let mut placeholder = any_placeholder();
+        placeholder.position = kani::any_where(|p| *p >= args.len());
+        let fmt = [placeholder];
+        assert!(Arguments { pieces: &["a"], fmt: Some(&fmt), args: &args }.is_safe());
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_count_param_mismatched() {
+        let args = [Argument::new_display(&Dummy)];
+        let fmt = [Placeholder {
+            position: 0,
+            flags: 0,
+            precision: Count::Implied,
+            width: Count::Param(0),
+        }];
+        assert!(Arguments { pieces: &["a"], fmt: Some(&fmt), args: &args }.is_safe());
+    }
+}
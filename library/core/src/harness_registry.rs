@@ -0,0 +1,131 @@
+//! Registry of verification harnesses.
+//!
+//! Verification modules declare their harnesses inside [`register_harness!`], which adds the Kani
+//! attribute of each harness and records the challenge and module it belongs to in a table next to
+//! the harnesses. A harness cannot be declared without being recorded, so the tables are complete.
+//! They are listed in [`REGISTRIES`], so code inside the crate can enumerate harnesses by challenge
+//! or by module through [`all_harnesses`].
+//!
+//! Challenges are named after their file in `doc/src/challenges`, without the extension, e.g.
+//! `"0011-floats-ints"`. Modules are named by their path inside the crate, e.g. `"f32"`.
+
+/// Metadata of one registered harness.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct HarnessInfo {
+    /// Name of the harness function, in the `verify` module that registers it.
+    pub(crate) name: &'static str,
+    /// The challenge the harness contributes to.
+    pub(crate) challenge: &'static str,
+    /// The module whose code the harness verifies.
+    pub(crate) module: &'static str,
+}
+
+/// Declares and registers the harnesses of a `verify` module.
+///
+/// Each harness starts with `#[proof]` or `#[proof_for_contract(..)]`, which becomes the matching
+/// `kani` attribute, and may carry further attributes. Also defines a `REGISTERED_HARNESSES` table
+/// in the invoking module, which must then be added to [`REGISTRIES`]. Use as follows:
+/// ```rust,ignore (just a macro example)
+/// register_harness! {
+///     challenge = "0011-floats-ints",
+///     module = "f32",
+///
+///     #[proof_for_contract(f32::next_up)]
+///     fn check_next_up() {
+///         let _ = kani::any::<f32>().next_up();
+///     }
+/// }
+/// ```
+macro_rules! register_harness {
+    (
+        challenge = $challenge:literal,
+        module = $module:literal,
+        $(
+            #[$kind:ident $(($($target:tt)*))?]
+            $(#[$attr:meta])*
+            fn $harness:ident() $body:block
+        )*
+    ) => {
+        pub(crate) const REGISTERED_HARNESSES: &[$crate::harness_registry::HarnessInfo] = &[$(
+            $crate::harness_registry::HarnessInfo {
+                name: stringify!($harness),
+                challenge: $challenge,
+                module: $module,
+            },
+        )*];
+
+        $(
+            #[kani::$kind $(($($target)*))?]
+            $(#[$attr])*
+            fn $harness() $body
+        )*
+    };
+}
+
+pub(crate) use register_harness;
+
+/// The tables of all modules that register their harnesses.
+const REGISTRIES: &[&[HarnessInfo]] = &[
+    crate::f16::verify::REGISTERED_HARNESSES,
+    crate::f32::verify::REGISTERED_HARNESSES,
+    crate::f64::verify::REGISTERED_HARNESSES,
+    crate::f128::verify::REGISTERED_HARNESSES,
+];
+
+/// Returns every registered harness.
+pub(crate) fn all_harnesses() -> impl Iterator<Item = &'static HarnessInfo> {
+    REGISTRIES.iter().flat_map(|registry| registry.iter())
+}
+
+/// Returns the registered harnesses that contribute to `challenge`.
+pub(crate) fn harnesses_for_challenge(
+    challenge: &str,
+) -> impl Iterator<Item = &'static HarnessInfo> {
+    all_harnesses().filter(move |harness| harness.challenge == challenge)
+}
+
+/// Returns the registered harnesses that verify code of `module`.
+pub(crate) fn harnesses_in_module(module: &str) -> impl Iterator<Item = &'static HarnessInfo> {
+    all_harnesses().filter(move |harness| harness.module == module)
+}
+
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::kani;
+
+    const TOTAL: usize = {
+        let mut total = 0;
+        let mut i = 0;
+        while i < REGISTRIES.len() {
+            total += REGISTRIES[i].len();
+            i += 1;
+        }
+        total
+    };
+
+    // Each module's table is listed exactly once, and entries are identified by their module
+    // and name, so no two entries may share both.
+    #[kani::proof]
+    #[kani::unwind(64)]
+    fn check_registry_entries_unique() {
+        let all: [&HarnessInfo; TOTAL] = {
+            let mut iter = all_harnesses();
+            crate::array::from_fn(|_| iter.next().unwrap())
+        };
+        let i = kani::any_where(|i: &usize| *i < TOTAL);
+        let j = kani::any_where(|j: &usize| *j < TOTAL && *j != i);
+        assert!(all[i].module != all[j].module || all[i].name != all[j].name);
+    }
+
+    // Every entry is found again through the challenge and module filters.
+    #[kani::proof]
+    #[kani::unwind(64)]
+    fn check_registry_filters() {
+        for harness in all_harnesses() {
+            assert!(!harness.name.is_empty() && !harness.challenge.is_empty());
+            assert!(harnesses_for_challenge(harness.challenge).any(|other| other == harness));
+            assert!(harnesses_in_module(harness.module).any(|other| other == harness));
+        }
+    }
+}
@@ -4,6 +4,8 @@
 //!
 //! Hints may be compile time or runtime.
 
+#[cfg(kani)]
+use crate::kani;
 use crate::mem::MaybeUninit;
 use crate::{intrinsics, ub_checks};
 
@@ -797,3 +799,85 @@ pub fn select_unpredictable<T>(condition: bool, true_val: T, false_val: T) -> T
         crate::intrinsics::select_unpredictable(condition, true_val, false_val).assume_init()
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::cell::Cell;
+
+    // `black_box` proofs
+    // `black_box` must behave as the identity: the value comes back bit for bit, and ownership
+    // passes through without dropping or duplicating anything.
+
+    macro_rules! check_black_box {
+        ($($harness:ident: $ty:ty),+ $(,)?) => {$(
+            #[kani::proof]
+            fn $harness() {
+                let value: $ty = kani::any();
+                let result = black_box(value);
+                assert!(result.to_ne_bytes() == value.to_ne_bytes());
+            }
+        )+};
+    }
+
+    check_black_box! {
+        check_black_box_u8: u8,
+        check_black_box_i32: i32,
+        check_black_box_u64: u64,
+        check_black_box_i128: i128,
+        check_black_box_usize: usize,
+        check_black_box_f32: f32,
+        check_black_box_f64: f64,
+    }
+
+    #[kani::proof]
+    fn check_black_box_ref() {
+        let value: u32 = kani::any();
+        let shared = black_box(&value);
+        assert!(crate::ptr::eq(shared, &value));
+        assert_eq!(*shared, value);
+
+        let mut value: u32 = kani::any();
+        let addr = &raw mut value;
+        let unique = black_box(&mut value);
+        assert!(crate::ptr::eq(unique, addr));
+        *unique = 7;
+        assert_eq!(value, 7);
+    }
+
+    #[kani::proof]
+    fn check_black_box_slice_ref() {
+        let array: [u8; 4] = kani::any();
+        let slice = kani::slice::any_slice_of_array(&array);
+        let result = black_box(slice);
+        assert!(crate::ptr::eq(result, slice));
+        assert_eq!(result.len(), slice.len());
+    }
+
+    struct Counted<'a> {
+        drops: &'a Cell<usize>,
+        tag: u8,
+    }
+
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    #[kani::proof]
+    fn check_black_box_drop() {
+        let drops = Cell::new(0);
+        let tag: u8 = kani::any();
+        let value = black_box(Counted { drops: &drops, tag });
+        assert_eq!(drops.get(), 0);
+        assert_eq!(value.tag, tag);
+        drop(value);
+        assert_eq!(drops.get(), 1);
+
+        let forgotten = black_box(Counted { drops: &drops, tag });
+        crate::mem::forget(forgotten);
+        assert_eq!(drops.get(), 1);
+    }
+}
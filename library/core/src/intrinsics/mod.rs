@@ -2370,6 +2370,74 @@ pub(crate) macro const_eval_select {
     },
 }
 
+/// Generates a Kani harness checking that the compile-time arm of a [`const_eval_select()`] call
+/// and the function selecting between the arms compute the same result on their shared input
+/// domain, which is required by the stability concerns of the intrinsic.
+///
+/// Kani always executes the runtime arm of `const_eval_select`, so calling the selecting function
+/// in a harness exercises its runtime arm. The compile-time arm must be a named `const fn`, which
+/// can be called at runtime as well. Arms that are only used for UB checks are allowed to diverge
+/// and are not covered by this pattern. Use as follows:
+/// ```rust,ignore (just a macro example)
+/// check_const_eval_select_arms!(
+///     harness_name, compiletime_fn, selecting_fn,
+///     { arg1: u8 = kani::any(), arg2: &[u8] = &kani::any::<[u8; 4]>() } where domain_expr
+/// );
+/// ```
+/// The optional `where` clause restricts the inputs to the domain where the arms must agree, and
+/// attributes such as `#[kani::unwind(..)]` can be added before the harness name.
+#[cfg(kani)]
+macro_rules! check_const_eval_select_arms {
+    (
+        $(#[$attr:meta])* $harness:ident, $compiletime:path, $selecting:path,
+        { $($arg:ident : $ty:ty = $val:expr),* $(,)? } $(where $domain:expr)?
+    ) => {
+        #[kani::proof]
+        $(#[$attr])*
+        fn $harness() {
+            $(let $arg: $ty = $val;)*
+            $(kani::assume($domain);)?
+            assert_eq!($compiletime($($arg),*), $selecting($($arg),*));
+        }
+    };
+}
+
+#[cfg(kani)]
+pub(crate) use check_const_eval_select_arms;
+
+/// Generates a Kani harness checking that both branches of an [`is_val_statically_known`] test in
+/// `function` compute the same result on their shared input domain. The intrinsic may return
+/// either answer, so any observable difference between the branches is a soundness bug.
+///
+/// The use site must go through [`is_val_statically_known_or_any`], which Kani resolves
+/// nondeterministically on every call. Comparing two calls on the same inputs then covers every
+/// pairing of the "known" and "unknown" branches. Use as follows:
+/// ```rust,ignore (just a macro example)
+/// check_statically_known_branches!(
+///     harness_name, function, { arg1: u8 = kani::any(), arg2: u32 = kani::any() } where domain_expr
+/// );
+/// ```
+/// The optional `where` clause restricts the inputs, e.g. to those for which `function` does not
+/// panic, and attributes such as `#[kani::unwind(..)]` can be added before the harness name.
+#[cfg(kani)]
+macro_rules! check_statically_known_branches {
+    (
+        $(#[$attr:meta])* $harness:ident, $function:path,
+        { $($arg:ident : $ty:ty = $val:expr),* $(,)? } $(where $domain:expr)?
+    ) => {
+        #[kani::proof]
+        $(#[$attr])*
+        fn $harness() {
+            $(let $arg: $ty = $val;)*
+            $(kani::assume($domain);)?
+            assert_eq!($function($($arg),*), $function($($arg),*));
+        }
+    };
+}
+
+#[cfg(kani)]
+pub(crate) use check_statically_known_branches;
+
 /// Returns whether the argument's value is statically known at
 /// compile-time.
 ///
@@ -2452,6 +2520,31 @@ pub const fn is_val_statically_known<T: Copy>(_arg: T) -> bool {
     false
 }
 
+/// [`is_val_statically_known`] as called from library code whose branches are checked with
+/// [`check_statically_known_branches!`].
+///
+/// Kani always resolves the intrinsic to the same answer, which would leave one of the branches
+/// unverified. Under Kani this wrapper therefore answers nondeterministically at runtime, which is
+/// exactly the freedom the intrinsic's specification grants the compiler.
+#[cfg(not(kani))]
+#[inline(always)]
+pub(crate) const fn is_val_statically_known_or_any<T: Copy>(arg: T) -> bool {
+    is_val_statically_known(arg)
+}
+
+#[cfg(kani)]
+#[inline(always)]
+pub(crate) const fn is_val_statically_known_or_any<T: Copy>(arg: T) -> bool {
+    const_eval_select!(
+        @capture[T: Copy] { arg: T } -> bool :
+        if const {
+            is_val_statically_known(arg)
+        } else {
+            kani::any()
+        }
+    )
+}
+
 /// Non-overlapping *typed* swap of a single value.
 ///
 /// The codegen backends will replace this with a better implementation when
@@ -2480,7 +2573,6 @@ pub const fn is_val_statically_known<T: Copy>(_arg: T) -> bool {
 #[cfg_attr(kani, kani::modifies(y))]
 #[requires(ub_checks::can_dereference(x) && ub_checks::can_write(x))]
 #[requires(ub_checks::can_dereference(y) && ub_checks::can_write(y))]
-#[requires(x.addr() != y.addr() || core::mem::size_of::<T>() == 0)]
 #[requires(ub_checks::maybe_is_nonoverlapping(x as *const (), y as *const (), size_of::<T>(), 1))]
 #[ensures(|_| ub_checks::can_dereference(x) && ub_checks::can_dereference(y))]
 pub const unsafe fn typed_swap_nonoverlapping<T>(x: *mut T, y: *mut T) {
@@ -2521,6 +2613,10 @@ pub const fn ub_checks() -> bool {
 #[rustc_nounwind]
 #[rustc_intrinsic]
 #[miri::intrinsic_fallback_is_spec]
+#[requires(_align.is_power_of_two())]
+// Postcondition of the runtime fallback only: const eval overrides the body and returns a live
+// allocation
+#[ensures(|result| result.is_null())]
 pub const unsafe fn const_allocate(_size: usize, _align: usize) -> *mut u8 {
     // const eval overrides this function, but runtime code for now just returns null pointers.
     // See <https://github.com/rust-lang/rust/issues/93935>.
@@ -2537,11 +2633,24 @@ pub const unsafe fn const_allocate(_size: usize, _align: usize) -> *mut u8 {
 ///    - At runtime, it is not checked.
 /// - If the `ptr` is created in an another const, this intrinsic doesn't deallocate it.
 /// - If the `ptr` is pointing to a local variable, this intrinsic doesn't deallocate it.
+///
+/// At compile time, deallocating with a layout that differs from the allocation is an error:
+///
+/// ```compile_fail
+/// #![feature(core_intrinsics, const_heap)]
+/// use std::intrinsics::{const_allocate, const_deallocate};
+///
+/// const _: () = unsafe {
+///     let ptr = const_allocate(4, 4);
+///     const_deallocate(ptr, 8, 4);
+/// };
+/// ```
 #[rustc_const_unstable(feature = "const_heap", issue = "79597")]
 #[unstable(feature = "core_intrinsics", issue = "none")]
 #[rustc_nounwind]
 #[rustc_intrinsic]
 #[miri::intrinsic_fallback_is_spec]
+#[requires(_align.is_power_of_two())]
 pub const unsafe fn const_deallocate(_ptr: *mut u8, _size: usize, _align: usize) {
     // Runtime NOP
 }
@@ -2796,14 +2905,9 @@ pub const fn ptr_metadata<P: ptr::Pointee<Metadata = M> + PointeeSized, M>(ptr:
 #[rustc_nounwind]
 #[rustc_intrinsic]
 // Copy is "untyped".
-// TODO: we can no longer do this given https://github.com/model-checking/kani/issues/3325 (this
-// function used to have a dummy body, but no longer has)
-// #[cfg_attr(kani, kani::modifies(crate::ptr::slice_from_raw_parts(dst, count)))]
-// #[requires(!count.overflowing_mul(size_of::<T>()).1
-//   && ub_checks::can_dereference(core::ptr::slice_from_raw_parts(src as *const crate::mem::MaybeUninit<T>, count))
-//   && ub_checks::can_write(core::ptr::slice_from_raw_parts_mut(dst, count))
-//   && ub_checks::maybe_is_nonoverlapping(src as *const (), dst as *const (), size_of::<T>(), count))]
-// #[ensures(|_| { check_copy_untyped(src, dst, count)})]
+// Kani cannot attach contracts to intrinsics without a body
+// (https://github.com/model-checking/kani/issues/3325), so the contract for this intrinsic is
+// specified on its `ptr::copy_nonoverlapping` wrapper.
 pub const unsafe fn copy_nonoverlapping<T>(src: *const T, dst: *mut T, count: usize);
 
 /// This is an accidentally-stable alias to [`ptr::copy`]; use that instead.
@@ -2815,13 +2919,9 @@ pub const unsafe fn copy_nonoverlapping<T>(src: *const T, dst: *mut T, count: us
 #[rustc_const_stable(feature = "const_intrinsic_copy", since = "1.83.0")]
 #[rustc_nounwind]
 #[rustc_intrinsic]
-// TODO: we can no longer do this given https://github.com/model-checking/kani/issues/3325 (this
-// function used to have a dummy body, but no longer has)
-// #[requires(!count.overflowing_mul(size_of::<T>()).1
-//   && ub_checks::can_dereference(core::ptr::slice_from_raw_parts(src as *const crate::mem::MaybeUninit<T>, count))
-//   && ub_checks::can_write(core::ptr::slice_from_raw_parts_mut(dst, count)))]
-// #[ensures(|_| { check_copy_untyped(src, dst, count) })]
-// #[cfg_attr(kani, kani::modifies(crate::ptr::slice_from_raw_parts(dst, count)))]
+// Kani cannot attach contracts to intrinsics without a body
+// (https://github.com/model-checking/kani/issues/3325), so the contract for this intrinsic is
+// specified on its `ptr::copy` wrapper.
 pub const unsafe fn copy<T>(src: *const T, dst: *mut T, count: usize);
 
 /// This is an accidentally-stable alias to [`ptr::write_bytes`]; use that instead.
@@ -3189,24 +3289,24 @@ pub const unsafe fn copysignf128(x: f128, y: f128) -> f128;
 /// initialized bytes result in initialized bytes.
 ///
 /// It is UB to read the uninitialized bytes, so we cannot compare their values only their
-/// initialization state.
+/// initialization state. Every one of the `count * size_of::<T>()` copied bytes is checked.
 ///
-/// This is used for contracts only.
+/// The caller must ensure that `count * size_of::<T>()` does not overflow, and that both
+/// regions are inside their allocations.
 ///
-/// FIXME: Change this once we add support to quantifiers.
+/// This is used for contracts only.
 #[allow(dead_code)]
 #[allow(unused_variables)]
-fn check_copy_untyped<T>(src: *const T, dst: *mut T, count: usize) -> bool {
+pub(crate) fn check_copy_untyped<T>(src: *const T, dst: *mut T, count: usize) -> bool {
     #[cfg(kani)]
-    if count > 0 {
-        let byte = kani::any_where(|sz: &usize| *sz < size_of::<T>());
-        let elem = kani::any_where(|val: &usize| *val < count);
+    {
+        let len = count * size_of::<T>();
         let src_data = src as *const u8;
-        let dst_data = unsafe { dst.add(elem) } as *const u8;
-        ub_checks::can_dereference(unsafe { src_data.add(byte) })
-            == ub_checks::can_dereference(unsafe { dst_data.add(byte) })
-    } else {
-        true
+        let dst_data = dst as *const u8;
+        kani::forall!(|i in (0, len)| {
+            ub_checks::can_dereference(src_data.wrapping_add(i))
+                == ub_checks::can_dereference(dst_data.wrapping_add(i))
+        })
     }
     #[cfg(not(kani))]
     false
@@ -3238,7 +3338,14 @@ pub(crate) const fn miri_promise_symbolic_alignment(ptr: *const (), align: usize
 
 /// Copies the current location of arglist `src` to the arglist `dst`.
 ///
-/// FIXME: document safety requirements
+/// # Safety
+///
+/// * `src` must be a `va_list` that was initialized by `va_start` or `va_copy` and that has not
+///   been destroyed by `va_end` yet.
+/// * `dest` must be valid for writes of a `VaListImpl`. Any previous value behind `dest` is
+///   overwritten without being destroyed.
+/// * The copy must be destroyed with `va_end` in the same function before `src`'s variadic
+///   arguments go out of scope.
 #[rustc_intrinsic]
 #[rustc_nounwind]
 pub unsafe fn va_copy<'f>(dest: *mut VaListImpl<'f>, src: &VaListImpl<'f>);
@@ -3246,14 +3353,26 @@ pub unsafe fn va_copy<'f>(dest: *mut VaListImpl<'f>, src: &VaListImpl<'f>);
 /// Loads an argument of type `T` from the `va_list` `ap` and increment the
 /// argument `ap` points to.
 ///
-/// FIXME: document safety requirements
+/// # Safety
+///
+/// * `ap` must be a `va_list` that was initialized by `va_start` or `va_copy` and that has not
+///   been destroyed by `va_end` yet.
+/// * There must be a next variadic argument, i.e., fewer arguments than were passed by the
+///   caller have been read from `ap` so far.
+/// * The next variadic argument must have been passed with type `T` after C's default argument
+///   promotions. [`VaArgSafe`] only admits types that are not subject to promotion.
 #[rustc_intrinsic]
 #[rustc_nounwind]
 pub unsafe fn va_arg<T: VaArgSafe>(ap: &mut VaListImpl<'_>) -> T;
 
 /// Destroy the arglist `ap` after initialization with `va_start` or `va_copy`.
 ///
-/// FIXME: document safety requirements
+/// # Safety
+///
+/// * `ap` must be a `va_list` that was initialized by `va_start` or `va_copy` and that has not
+///   been destroyed by `va_end` yet. It must not be used afterwards, except as the destination
+///   of `va_copy`.
+/// * `va_end` must be called from the same function that initialized `ap`.
 #[rustc_intrinsic]
 #[rustc_nounwind]
 pub unsafe fn va_end(ap: &mut VaListImpl<'_>);
@@ -3285,33 +3404,73 @@ mod verify {
         });
     }
 
-    // #[kani::proof_for_contract(copy)]
-    // fn check_copy() {
-    //     run_with_arbitrary_ptrs::<char>(|src, dst| unsafe { copy(src, dst, kani::any()) });
-    // }
-
-    // #[kani::proof_for_contract(copy_nonoverlapping)]
-    // fn check_copy_nonoverlapping() {
-    //     // Note: cannot use `ArbitraryPointer` here.
-    //     // The `ArbitraryPtr` will arbitrarily initialize memory by indirectly invoking
-    //     // `copy_nonoverlapping`.
-    //     // Kani contract checking would fail due to existing restriction on calls to
-    //     // the function under verification.
-    //     let gen_any_ptr = |buf: &mut [MaybeUninit<char>; 100]| -> *mut char {
-    //         let base = buf.as_mut_ptr() as *mut u8;
-    //         base.wrapping_add(kani::any_where(|offset: &usize| *offset < 400)) as *mut char
-    //     };
-    //     let mut buffer1 = [MaybeUninit::<char>::uninit(); 100];
-    //     for i in 0..100 {
-    //         if kani::any() {
-    //             buffer1[i] = MaybeUninit::new(kani::any());
-    //         }
-    //     }
-    //     let mut buffer2 = [MaybeUninit::<char>::uninit(); 100];
-    //     let src = gen_any_ptr(&mut buffer1);
-    //     let dst = if kani::any() { gen_any_ptr(&mut buffer2) } else { gen_any_ptr(&mut buffer1) };
-    //     unsafe { copy_nonoverlapping(src, dst, kani::any()) }
-    // }
+    #[kani::proof_for_contract(typed_swap_nonoverlapping)]
+    pub fn check_typed_swap_unit() {
+        run_with_arbitrary_ptrs::<()>(|x, y| unsafe { typed_swap_nonoverlapping(x, y) });
+    }
+
+    /// Zero-sized values can be swapped even if both pointers have the same address.
+    #[kani::proof_for_contract(typed_swap_nonoverlapping)]
+    pub fn check_typed_swap_zst_same_addr() {
+        let mut val = [0u64; 0];
+        let ptr = &mut val as *mut [u64; 0];
+        unsafe { typed_swap_nonoverlapping(ptr, ptr) };
+    }
+
+    #[kani::proof_for_contract(crate::ptr::copy)]
+    fn check_copy() {
+        run_with_arbitrary_ptrs::<char>(|src, dst| unsafe {
+            crate::ptr::copy(src, dst, kani::any_where(|count: &usize| *count <= 2))
+        });
+    }
+
+    #[kani::proof_for_contract(crate::ptr::copy)]
+    fn check_copy_unit() {
+        run_with_arbitrary_ptrs::<()>(|src, dst| unsafe {
+            crate::ptr::copy(src, dst, kani::any())
+        });
+    }
+
+    #[kani::proof_for_contract(crate::ptr::copy_nonoverlapping)]
+    fn check_copy_nonoverlapping() {
+        // Note: cannot use `ArbitraryPointer` here.
+        // The `ArbitraryPtr` will arbitrarily initialize memory by indirectly invoking
+        // `copy_nonoverlapping`.
+        // Kani contract checking would fail due to existing restriction on calls to
+        // the function under verification.
+        let gen_any_ptr = |buf: &mut [MaybeUninit<char>; 100]| -> *mut char {
+            let base = buf.as_mut_ptr() as *mut u8;
+            base.wrapping_add(kani::any_where(|offset: &usize| *offset < 400)) as *mut char
+        };
+        let mut buffer1 = [MaybeUninit::<char>::uninit(); 100];
+        for i in 0..100 {
+            if kani::any() {
+                buffer1[i] = MaybeUninit::new(kani::any());
+            }
+        }
+        let mut buffer2 = [MaybeUninit::<char>::uninit(); 100];
+        let src = gen_any_ptr(&mut buffer1);
+        let dst = if kani::any() { gen_any_ptr(&mut buffer2) } else { gen_any_ptr(&mut buffer1) };
+        unsafe { crate::ptr::copy_nonoverlapping(src, dst, kani::any()) }
+    }
+
+    #[kani::proof_for_contract(const_allocate)]
+    fn check_const_allocate() {
+        let size: usize = kani::any();
+        let align: usize = kani::any();
+        let _ = unsafe { const_allocate(size, align) };
+    }
+
+    /// At runtime, `const_deallocate` is a no-op, even for pointers to live memory.
+    #[kani::proof_for_contract(const_deallocate)]
+    fn check_const_deallocate() {
+        let val: u64 = kani::any();
+        let mut local = val;
+        let ptr = &mut local as *mut u64 as *mut u8;
+        let align: usize = kani::any();
+        unsafe { const_deallocate(ptr, size_of::<u64>(), align) };
+        assert_eq!(local, val);
+    }
 
     //We need this wrapper because transmute_unchecked is an intrinsic, for which Kani does
     //not currently support contracts (https://github.com/model-checking/kani/issues/3345)
@@ -3938,4 +4097,153 @@ mod verify {
     fn supported_status(status: AllocationStatus) -> bool {
         status != AllocationStatus::Dangling && status != AllocationStatus::DeadObject
     }
+
+    // `discriminant_value` and `variant_count` proofs
+    //
+    // Each enum gets a `match`-based reference for its discriminants. `discriminant_value`
+    // must agree with the reference and with `mem::discriminant` equality, and
+    // `variant_count` must match the number of declared variants, uninhabited ones included.
+
+    enum Void {}
+
+    #[derive(kani::Arbitrary)]
+    #[repr(i16)]
+    enum Explicit {
+        A = -300,
+        B = 0,
+        C = 300,
+    }
+
+    #[derive(kani::Arbitrary)]
+    enum WithData {
+        X(u16),
+        Y { a: u8, b: bool },
+        Z,
+    }
+
+    #[allow(dead_code)]
+    enum WithVoid {
+        A(u8),
+        B(Void),
+        C,
+    }
+
+    const _: () = {
+        assert!(variant_count::<Explicit>() == 3);
+        assert!(variant_count::<WithData>() == 3);
+        assert!(variant_count::<WithVoid>() == 3);
+        assert!(variant_count::<Option<&u8>>() == 2);
+        assert!(variant_count::<Void>() == 0);
+    };
+
+    fn check_discriminants<T>(
+        a: &T,
+        b: &T,
+        reference: fn(&T) -> <T as DiscriminantKind>::Discriminant,
+    ) {
+        let (da, db) = (discriminant_value(a), discriminant_value(b));
+        assert!(da == reference(a));
+        assert!(db == reference(b));
+        assert_eq!(da == db, crate::mem::discriminant(a) == crate::mem::discriminant(b));
+    }
+
+    #[kani::proof]
+    fn check_discriminant_value_explicit() {
+        check_discriminants::<Explicit>(&kani::any(), &kani::any(), |e| match e {
+            Explicit::A => -300,
+            Explicit::B => 0,
+            Explicit::C => 300,
+        });
+        assert_eq!(variant_count::<Explicit>(), 3);
+    }
+
+    #[kani::proof]
+    fn check_discriminant_value_with_data() {
+        check_discriminants::<WithData>(&kani::any(), &kani::any(), |e| match e {
+            WithData::X(_) => 0,
+            WithData::Y { .. } => 1,
+            WithData::Z => 2,
+        });
+        assert_eq!(variant_count::<WithData>(), 3);
+    }
+
+    /// `None` lives in the niche of the payload, so the discriminant must be decoded from
+    /// the payload bytes rather than read from a tag.
+    #[kani::proof]
+    fn check_discriminant_value_niche() {
+        fn reference<T>(o: &Option<T>) -> isize {
+            o.is_some() as isize
+        }
+        let a: Option<crate::num::NonZero<u8>> = kani::any();
+        let b: Option<crate::num::NonZero<u8>> = kani::any();
+        check_discriminants(&a, &b, reference);
+        let (x, y): (u8, u8) = kani::any();
+        let a = if kani::any() { Some(&x) } else { None };
+        let b = if kani::any() { Some(&y) } else { None };
+        check_discriminants(&a, &b, reference);
+        let a: Option<bool> = kani::any();
+        let b: Option<bool> = kani::any();
+        check_discriminants(&a, &b, reference);
+        assert_eq!(variant_count::<Option<&u8>>(), 2);
+    }
+
+    #[kani::proof]
+    fn check_discriminant_value_uninhabited_variant() {
+        let any_with_void = || if kani::any() { WithVoid::A(kani::any()) } else { WithVoid::C };
+        check_discriminants(&any_with_void(), &any_with_void(), |e| match e {
+            WithVoid::A(_) => 0,
+            WithVoid::B(void) => match *void {},
+            WithVoid::C => 2,
+        });
+        assert_eq!(variant_count::<WithVoid>(), 3);
+        assert_eq!(variant_count::<Void>(), 0);
+    }
+
+    // `nontemporal_store` proofs
+    //
+    // The intrinsic is documented as "fully equivalent to `ptr.write(val)`" apart from caching.
+    // Kani lowers it to a plain store; these harnesses pin that down so a later change to the
+    // model cannot silently turn it into a no-op.
+
+    // Same reason as for `transmute_unchecked_wrapper`: contracts cannot be attached to the
+    // intrinsic itself.
+    #[requires(ub_checks::can_write(ptr))]
+    #[cfg_attr(kani, kani::modifies(ptr))]
+    #[ensures(|_| unsafe { *ptr == val })]
+    unsafe fn nontemporal_store_wrapper<T: Copy + PartialEq>(ptr: *mut T, val: T) {
+        unsafe { nontemporal_store(ptr, val) }
+    }
+
+    macro_rules! check_nontemporal_store {
+        ($($harness:ident: $ty:ty),+ $(,)?) => {$(
+            #[kani::proof_for_contract(nontemporal_store_wrapper)]
+            fn $harness() {
+                let mut generator = PointerGenerator::<{ 4 * size_of::<$ty>() }>::new();
+                let ptr: *mut $ty = generator.any_in_bounds().ptr;
+                kani::assume(ptr.is_aligned());
+                unsafe { nontemporal_store_wrapper(ptr, kani::any()) };
+            }
+        )+};
+    }
+
+    check_nontemporal_store! {
+        check_nontemporal_store_u8: u8,
+        check_nontemporal_store_u32: u32,
+        check_nontemporal_store_u64: u64,
+        check_nontemporal_store_u128: u128,
+        check_nontemporal_store_char: char,
+        check_nontemporal_store_pair: (u16, u16),
+    }
+
+    #[kani::proof]
+    fn check_nontemporal_store_matches_write() {
+        let init: [u32; 3] = kani::any();
+        let (mut stored, mut written) = (init, init);
+        let idx: usize = kani::any_where(|i| *i < init.len());
+        let val: u32 = kani::any();
+        unsafe { nontemporal_store(&raw mut stored[idx], val) };
+        unsafe { (&raw mut written[idx]).write(val) };
+        assert_eq!(stored, written);
+        assert_eq!(stored[idx], val);
+    }
 }
@@ -1,7 +1,12 @@
 #![unstable(feature = "core_io_borrowed_buf", issue = "117693")]
 
+use safety::{ensures, requires};
+
 use crate::fmt::{self, Debug, Formatter};
+#[cfg(kani)]
+use crate::kani;
 use crate::mem::{self, MaybeUninit};
+use crate::ub_checks::Invariant;
 use crate::{cmp, ptr};
 
 /// A borrowed byte buffer which is incrementally filled and initialized.
@@ -129,6 +134,8 @@ impl<'data> BorrowedBuf<'data> {
     }
 
     /// Returns a cursor over the unfilled part of the buffer.
+    #[requires(self.is_safe())]
+    #[ensures(|cursor| cursor.buf.is_safe() && cursor.start == cursor.buf.filled)]
     #[inline]
     pub fn unfilled<'this>(&'this mut self) -> BorrowedCursor<'this> {
         BorrowedCursor {
@@ -158,6 +165,12 @@ impl<'data> BorrowedBuf<'data> {
     /// # Safety
     ///
     /// The caller must ensure that the first `n` unfilled bytes of the buffer have already been initialized.
+    #[requires(self.is_safe() && n <= self.capacity())]
+    #[requires(crate::ub_checks::can_dereference(
+        ptr::slice_from_raw_parts(self.buf.as_ptr() as *const u8, n)
+    ))]
+    #[ensures(|buf| buf.is_safe() && buf.init == cmp::max(old(self.init), n))]
+    #[cfg_attr(kani, kani::modifies(self))]
     #[inline]
     pub unsafe fn set_init(&mut self, n: usize) -> &mut Self {
         self.init = cmp::max(self.init, n);
@@ -279,6 +292,9 @@ impl<'a> BorrowedCursor<'a> {
     /// # Panics
     ///
     /// Panics if there are less than `n` bytes initialized.
+    #[requires(self.is_safe())]
+    #[ensures(|cursor| cursor.is_safe() && cursor.buf.filled == old(self.buf.filled) + n)]
+    #[cfg_attr(kani, kani::modifies(self.buf))]
     #[inline]
     pub fn advance(&mut self, n: usize) -> &mut Self {
         // The subtraction cannot underflow by invariant of this type.
@@ -298,6 +314,9 @@ impl<'a> BorrowedCursor<'a> {
     ///
     /// The caller must ensure that the first `n` bytes of the cursor have been properly
     /// initialised.
+    #[requires(self.is_safe() && n <= self.capacity())]
+    #[ensures(|cursor| cursor.is_safe() && cursor.buf.filled == old(self.buf.filled) + n)]
+    #[cfg_attr(kani, kani::modifies(self.buf))]
     #[inline]
     pub unsafe fn advance_unchecked(&mut self, n: usize) -> &mut Self {
         self.buf.filled += n;
@@ -327,6 +346,9 @@ impl<'a> BorrowedCursor<'a> {
     /// # Safety
     ///
     /// The caller must ensure that the first `n` bytes of the buffer have already been initialized.
+    #[requires(self.is_safe() && n <= self.capacity())]
+    #[ensures(|cursor| cursor.is_safe() && cursor.buf.init >= old(self.buf.filled) + n)]
+    #[cfg_attr(kani, kani::modifies(self.buf))]
     #[inline]
     pub unsafe fn set_init(&mut self, n: usize) -> &mut Self {
         self.buf.init = cmp::max(self.buf.init, self.buf.filled + n);
@@ -338,6 +360,10 @@ impl<'a> BorrowedCursor<'a> {
     /// # Panics
     ///
     /// Panics if `self.capacity()` is less than `buf.len()`.
+    #[requires(self.is_safe())]
+    #[ensures(|_| self.is_safe() && self.buf.filled == old(self.buf.filled) + buf.len())]
+    #[ensures(|_| self.buf.filled()[old(self.buf.filled)..] == *buf)]
+    #[cfg_attr(kani, kani::modifies(self.buf, self.buf.buf))]
     #[inline]
     pub fn append(&mut self, buf: &[u8]) {
         assert!(self.capacity() >= buf.len());
@@ -354,3 +380,120 @@ impl<'a> BorrowedCursor<'a> {
         self.buf.filled += buf.len();
     }
 }
+
+#[unstable(feature = "ub_checks", issue = "none")]
+impl Invariant for BorrowedBuf<'_> {
+    /// The filled region is a prefix of the initialized region, which lies within the buffer.
+    fn is_safe(&self) -> bool {
+        self.filled <= self.init && self.init <= self.buf.len()
+    }
+}
+
+#[unstable(feature = "ub_checks", issue = "none")]
+impl Invariant for BorrowedCursor<'_> {
+    /// The cursor starts within the filled region of a valid buffer.
+    fn is_safe(&self) -> bool {
+        self.buf.is_safe() && self.start <= self.buf.filled
+    }
+}
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // `BorrowedBuf` / `BorrowedCursor` watermark proofs
+
+    const CAP: usize = 8;
+
+    /// Returns a buffer over `storage` with symbolic `filled <= init <= CAP` watermarks.
+    fn any_borrowed_buf(storage: &mut [MaybeUninit<u8>; CAP]) -> BorrowedBuf<'_> {
+        for byte in storage.iter_mut() {
+            *byte = MaybeUninit::new(kani::any());
+        }
+        let mut buf = BorrowedBuf::from(&mut storage[..]);
+        let init: usize = kani::any_where(|init| *init <= CAP);
+        let filled: usize = kani::any_where(|filled| *filled <= init);
+        // SAFETY: every byte of `storage` was initialized above.
+        unsafe { buf.set_init(init) };
+        buf.unfilled().advance(filled);
+        buf
+    }
+
+    #[kani::proof_for_contract(BorrowedBuf::unfilled)]
+    #[kani::unwind(9)]
+    fn check_unfilled() {
+        let mut storage = [MaybeUninit::uninit(); CAP];
+        let mut buf = any_borrowed_buf(&mut storage);
+        let _ = buf.unfilled();
+    }
+
+    #[kani::proof_for_contract(BorrowedBuf::set_init)]
+    #[kani::unwind(9)]
+    fn check_buf_set_init() {
+        let mut storage = [MaybeUninit::uninit(); CAP];
+        let mut buf = any_borrowed_buf(&mut storage);
+        unsafe { buf.set_init(kani::any()) };
+    }
+
+    #[kani::proof_for_contract(BorrowedCursor::advance)]
+    #[kani::unwind(9)]
+    fn check_advance() {
+        let mut storage = [MaybeUninit::uninit(); CAP];
+        let mut buf = any_borrowed_buf(&mut storage);
+        buf.unfilled().advance(kani::any());
+    }
+
+    #[kani::proof_for_contract(BorrowedCursor::advance_unchecked)]
+    #[kani::unwind(9)]
+    fn check_advance_unchecked() {
+        let mut storage = [MaybeUninit::uninit(); CAP];
+        let mut buf = any_borrowed_buf(&mut storage);
+        unsafe { buf.unfilled().advance_unchecked(kani::any()) };
+    }
+
+    #[kani::proof_for_contract(BorrowedCursor::set_init)]
+    #[kani::unwind(9)]
+    fn check_cursor_set_init() {
+        let mut storage = [MaybeUninit::uninit(); CAP];
+        let mut buf = any_borrowed_buf(&mut storage);
+        unsafe { buf.unfilled().set_init(kani::any()) };
+    }
+
+    #[kani::proof_for_contract(BorrowedCursor::append)]
+    #[kani::unwind(9)]
+    fn check_append() {
+        let mut storage = [MaybeUninit::uninit(); CAP];
+        let mut buf = any_borrowed_buf(&mut storage);
+        let data: [u8; CAP] = kani::any();
+        buf.unfilled().append(kani::slice::any_slice_of_array(&data));
+    }
+
+    #[kani::proof]
+    #[kani::unwind(9)]
+    fn check_symbolic_advances() {
+        let mut storage = [MaybeUninit::uninit(); CAP];
+        let mut buf = any_borrowed_buf(&mut storage);
+        let filled = buf.len();
+        let mut cursor = buf.unfilled();
+        let n: usize = kani::any_where(|n| *n <= cursor.init_ref().len());
+        let m: usize = kani::any_where(|m| *m <= n);
+        cursor.advance(m);
+        cursor.reborrow().advance(n - m);
+        assert_eq!(cursor.written(), n);
+        assert!(cursor.is_safe());
+        assert_eq!(buf.len(), filled + n);
+        assert!(buf.is_safe());
+    }
+
+    #[kani::proof]
+    #[kani::unwind(9)]
+    #[kani::should_panic]
+    fn check_advance_past_init() {
+        let mut storage = [MaybeUninit::uninit(); CAP];
+        let mut buf = any_borrowed_buf(&mut storage);
+        let mut cursor = buf.unfilled();
+        let n: usize = kani::any_where(|n| *n > cursor.init_ref().len());
+        cursor.advance(n);
+    }
+}
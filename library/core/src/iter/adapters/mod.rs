@@ -28,6 +28,10 @@ mod take;
 mod take_while;
 mod zip;
 
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify;
+
 #[unstable(feature = "iter_array_chunks", reason = "recently added", issue = "100450")]
 pub use self::array_chunks::ArrayChunks;
 #[unstable(feature = "std_internals", issue = "none")]
@@ -0,0 +1,113 @@
+//! Equivalence proofs for iterator adapters.
+//!
+//! Several adapters override `nth`, `fold` or `size_hint` (directly or through specialization)
+//! instead of relying on the default implementations in terms of `next`. These harnesses drive
+//! each adapter over bounded symbolic input and check every result against the same operation
+//! on a plain slice holding the elements the adapter should yield.
+
+use crate::kani;
+
+const MAX_LEN: usize = 4;
+const STEPS: usize = 3;
+
+/// Order-sensitive summary of a sequence, used to compare `fold` results.
+fn digest(acc: u32, x: u8) -> u32 {
+    acc.wrapping_mul(257).wrapping_add(x as u32)
+}
+
+/// Drives `iter` with a symbolic sequence of `next` and `nth` calls followed by a `fold`,
+/// checking each result against `expected`.
+///
+/// If `exact` is set, `size_hint` must be exact at every step; otherwise it only has to bound
+/// the number of remaining elements.
+fn check_equivalent<I: Iterator<Item = u8>>(mut iter: I, expected: &[u8], exact: bool) {
+    let mut pos = 0;
+    for _ in 0..STEPS {
+        let remaining = expected.len() - pos;
+        let (lower, upper) = iter.size_hint();
+        if exact {
+            assert_eq!((lower, upper), (remaining, Some(remaining)));
+        } else {
+            assert!(lower <= remaining && upper.is_none_or(|upper| upper >= remaining));
+        }
+        if kani::any() {
+            assert_eq!(iter.next(), expected.get(pos).copied());
+            pos = (pos + 1).min(expected.len());
+        } else {
+            let n = kani::any_where(|&n: &usize| n <= MAX_LEN);
+            assert_eq!(iter.nth(n), expected.get(pos + n).copied());
+            pos = (pos + n + 1).min(expected.len());
+        }
+    }
+    assert_eq!(iter.fold(0, digest), expected[pos..].iter().copied().fold(0, digest));
+}
+
+#[kani::proof]
+#[kani::unwind(10)]
+fn check_skip() {
+    let array: [u8; MAX_LEN] = kani::any();
+    let slice = kani::slice::any_slice_of_array(&array);
+    let n = kani::any_where(|&n: &usize| n <= MAX_LEN + 1);
+    let expected = &slice[n.min(slice.len())..];
+    check_equivalent(slice.iter().copied().skip(n), expected, true);
+}
+
+#[kani::proof]
+#[kani::unwind(10)]
+fn check_take() {
+    let array: [u8; MAX_LEN] = kani::any();
+    let slice = kani::slice::any_slice_of_array(&array);
+    let n = kani::any_where(|&n: &usize| n <= MAX_LEN + 1);
+    let expected = &slice[..n.min(slice.len())];
+    check_equivalent(slice.iter().copied().take(n), expected, true);
+}
+
+#[kani::proof]
+#[kani::unwind(10)]
+fn check_chain() {
+    let array: [u8; 2 * MAX_LEN] = kani::any();
+    let len = kani::any_where(|&len: &usize| len <= 2 * MAX_LEN);
+    let mid = kani::any_where(|&mid: &usize| mid <= len);
+    let expected = &array[..len];
+    let (a, b) = expected.split_at(mid);
+    check_equivalent(a.iter().copied().chain(b.iter().copied()), expected, true);
+}
+
+#[kani::proof]
+#[kani::unwind(10)]
+fn check_flatten() {
+    let array: [u8; MAX_LEN] = kani::any();
+    let expected = kani::slice::any_slice_of_array(&array);
+    let j = kani::any_where(|&j: &usize| j <= expected.len());
+    let i = kani::any_where(|&i: &usize| i <= j);
+    let parts = [&expected[..i], &expected[i..j], &expected[j..]];
+    // Inner iterators are only partially known, so `size_hint` is merely a bound.
+    check_equivalent(parts.into_iter().flatten().copied(), expected, false);
+}
+
+// `peek` must not consume anything, so it is interleaved with the other operations.
+#[kani::proof]
+#[kani::unwind(10)]
+fn check_peekable() {
+    let array: [u8; MAX_LEN] = kani::any();
+    let expected = kani::slice::any_slice_of_array(&array);
+    let mut iter = expected.iter().copied().peekable();
+    let mut pos = 0;
+    for _ in 0..STEPS {
+        let remaining = expected.len() - pos;
+        assert_eq!(iter.size_hint(), (remaining, Some(remaining)));
+        match kani::any::<u8>() {
+            0 => assert_eq!(iter.peek().copied(), expected.get(pos).copied()),
+            1 => {
+                assert_eq!(iter.next(), expected.get(pos).copied());
+                pos = (pos + 1).min(expected.len());
+            }
+            _ => {
+                let n = kani::any_where(|&n: &usize| n <= MAX_LEN);
+                assert_eq!(iter.nth(n), expected.get(pos + n).copied());
+                pos = (pos + n + 1).min(expected.len());
+            }
+        }
+    }
+    assert_eq!(iter.fold(0, digest), expected[pos..].iter().copied().fold(0, digest));
+}
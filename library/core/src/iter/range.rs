@@ -1,4 +1,4 @@
-use safety::requires;
+use safety::{ensures, requires};
 
 use super::{
     FusedIterator, TrustedLen, TrustedRandomAccess, TrustedRandomAccessNoCoerce, TrustedStep,
@@ -188,14 +188,16 @@ pub trait Step: Clone + PartialOrd + Sized {
 // than the signed::MAX value. Therefore `as` casting to the signed type would be incorrect.
 macro_rules! step_signed_methods {
     ($unsigned: ty) => {
-        #[requires(start.checked_add_unsigned(n as $unsigned).is_some())]
+        #[requires(<$unsigned>::try_from(n).is_ok_and(|n| start.checked_add_unsigned(n).is_some()))]
+        #[ensures(|result| Some(*result) == Self::forward_checked(start, n))]
         #[inline]
         unsafe fn forward_unchecked(start: Self, n: usize) -> Self {
             // SAFETY: the caller has to guarantee that `start + n` doesn't overflow.
             unsafe { start.checked_add_unsigned(n as $unsigned).unwrap_unchecked() }
         }
 
-        #[requires(start.checked_sub_unsigned(n as $unsigned).is_some())]
+        #[requires(<$unsigned>::try_from(n).is_ok_and(|n| start.checked_sub_unsigned(n).is_some()))]
+        #[ensures(|result| Some(*result) == Self::backward_checked(start, n))]
         #[inline]
         unsafe fn backward_unchecked(start: Self, n: usize) -> Self {
             // SAFETY: the caller has to guarantee that `start - n` doesn't overflow.
@@ -206,14 +208,16 @@ macro_rules! step_signed_methods {
 
 macro_rules! step_unsigned_methods {
     () => {
-        #[requires(start.checked_add(n as Self).is_some())]
+        #[requires(Self::try_from(n).is_ok_and(|n| start.checked_add(n).is_some()))]
+        #[ensures(|result| Some(*result) == Self::forward_checked(start, n))]
         #[inline]
         unsafe fn forward_unchecked(start: Self, n: usize) -> Self {
             // SAFETY: the caller has to guarantee that `start + n` doesn't overflow.
             unsafe { start.unchecked_add(n as Self) }
         }
 
-        #[requires(start >= (n as Self))]
+        #[requires(Self::try_from(n).is_ok_and(|n| start >= n))]
+        #[ensures(|result| Some(*result) == Self::backward_checked(start, n))]
         #[inline]
         unsafe fn backward_unchecked(start: Self, n: usize) -> Self {
             // SAFETY: the caller has to guarantee that `start - n` doesn't overflow.
@@ -475,6 +479,9 @@ impl Step for char {
         }
     }
 
+    #[ensures(|result| result.is_none_or(|end| {
+        Step::steps_between(&start, &end) == (count, Some(count))
+    }))]
     #[inline]
     fn forward_checked(start: char, count: usize) -> Option<char> {
         let start = start as u32;
@@ -491,6 +498,9 @@ impl Step for char {
         }
     }
 
+    #[ensures(|result| result.is_none_or(|begin| {
+        Step::steps_between(&begin, &start) == (count, Some(count))
+    }))]
     #[inline]
     fn backward_checked(start: char, count: usize) -> Option<char> {
         let start = start as u32;
@@ -503,13 +513,8 @@ impl Step for char {
         Some(unsafe { char::from_u32_unchecked(res) })
     }
 
-    #[requires({
-        (start as u32).checked_add(count as u32).is_some_and(|dist|
-            (start as u32) >= 0xD800 ||
-            dist < 0xD800 ||
-            dist.checked_add(0x800).is_some()
-         )
-    })]
+    #[requires(Step::forward_checked(start, count).is_some())]
+    #[ensures(|result| Some(*result) == Step::forward_checked(start, count))]
     #[inline]
     unsafe fn forward_unchecked(start: char, count: usize) -> char {
         let start = start as u32;
@@ -526,13 +531,8 @@ impl Step for char {
         unsafe { char::from_u32_unchecked(res) }
     }
 
-    #[requires({
-        (start as u32).checked_sub(count as u32).is_some_and(|dist|
-            (start as u32) < 0xE000 ||
-            dist >= 0xE000 ||
-            dist.checked_sub(0x800).is_some()
-         )
-    })]
+    #[requires(Step::backward_checked(start, count).is_some())]
+    #[ensures(|result| Some(*result) == Step::backward_checked(start, count))]
     #[inline]
     unsafe fn backward_unchecked(start: char, count: usize) -> char {
         let start = start as u32;
@@ -1424,3 +1424,181 @@ unsafe impl<A: TrustedStep> TrustedLen for ops::RangeInclusive<A> {}
 
 #[stable(feature = "fused", since = "1.26.0")]
 impl<A: Step> FusedIterator for ops::RangeInclusive<A> {}
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // `Step` proofs
+
+    macro_rules! check_step_unchecked {
+        ($($module:ident: $t:ty;)+) => {$(
+            mod $module {
+                use super::*;
+
+                #[kani::proof_for_contract(<$t as Step>::forward_unchecked)]
+                fn check_forward_unchecked() {
+                    let start: $t = kani::any();
+                    let n: usize = kani::any();
+                    let _ = unsafe { Step::forward_unchecked(start, n) };
+                }
+
+                #[kani::proof_for_contract(<$t as Step>::backward_unchecked)]
+                fn check_backward_unchecked() {
+                    let start: $t = kani::any();
+                    let n: usize = kani::any();
+                    let _ = unsafe { Step::backward_unchecked(start, n) };
+                }
+            }
+        )+};
+    }
+
+    check_step_unchecked! {
+        step_u8: u8;
+        step_u16: u16;
+        step_u32: u32;
+        step_u64: u64;
+        step_u128: u128;
+        step_usize: usize;
+        step_i8: i8;
+        step_i16: i16;
+        step_i32: i32;
+        step_i64: i64;
+        step_i128: i128;
+        step_isize: isize;
+        step_char: char;
+    }
+
+    const MAX_STEPS: u8 = 4;
+
+    // `advance_by` moves `start` by exactly the number of elements it reports as skipped.
+    #[kani::proof]
+    fn check_range_advance_by() {
+        let start: u8 = kani::any();
+        let end: u8 = kani::any();
+        let n: usize = kani::any();
+        let mut range = start..end;
+        let len = end.saturating_sub(start) as usize;
+        let result = range.advance_by(n);
+        assert_eq!(result.is_ok(), n <= len);
+        assert_eq!(range.start as usize, start as usize + n.min(len));
+        assert_eq!(range.end, end);
+    }
+
+    // Once a `RangeInclusive` has yielded its last element from either end it is marked as
+    // exhausted, so `end` is yielded exactly once and nothing after it.
+    #[kani::proof]
+    #[kani::unwind(7)]
+    fn check_range_inclusive_exhausted() {
+        let start: u8 = kani::any();
+        let end = kani::any_where(|&end: &u8| end.wrapping_sub(start) <= MAX_STEPS);
+        let mut range = start..=end;
+        let len = if start <= end { (end - start) as usize + 1 } else { 0 };
+        let mut yielded = 0;
+        let mut ends = 0;
+        for _ in 0..=MAX_STEPS + 1 {
+            let item = if kani::any() { range.next() } else { range.next_back() };
+            match item {
+                Some(x) => {
+                    yielded += 1;
+                    ends += (x == end) as usize;
+                    assert!(start <= x && x <= end);
+                }
+                None => assert!(range.is_empty()),
+            }
+        }
+        assert_eq!(yielded, len);
+        assert_eq!(ends, (len > 0) as usize);
+        assert_eq!(range.next(), None);
+        assert_eq!(range.next_back(), None);
+    }
+
+    // `Step for char` proofs
+
+    const SURROGATES: u32 = 0x800;
+    const GAP_STEPS: usize = 3;
+
+    fn naive_forward(start: char, count: usize) -> Option<char> {
+        let mut u = start as u32;
+        for _ in 0..count {
+            loop {
+                u = u.checked_add(1)?;
+                if u > char::MAX as u32 {
+                    return None;
+                }
+                if char::from_u32(u).is_some() {
+                    break;
+                }
+            }
+        }
+        char::from_u32(u)
+    }
+
+    fn naive_backward(start: char, count: usize) -> Option<char> {
+        let mut u = start as u32;
+        for _ in 0..count {
+            loop {
+                u = u.checked_sub(1)?;
+                if char::from_u32(u).is_some() {
+                    break;
+                }
+            }
+        }
+        char::from_u32(u)
+    }
+
+    /// A `char` within `GAP_STEPS` of either side of the surrogate gap, or of the ends of the
+    /// `char` range.
+    fn any_char_near_edges() -> char {
+        let offset = kani::any_where(|&offset: &u32| offset <= GAP_STEPS as u32);
+        let c = match kani::any::<u8>() {
+            0 => 0xD7FF - offset,
+            1 => 0xE000 + offset,
+            2 => offset,
+            _ => char::MAX as u32 - offset,
+        };
+        char::from_u32(c).unwrap()
+    }
+
+    #[kani::proof_for_contract(<char as Step>::forward_checked)]
+    fn check_char_forward_checked() {
+        let _ = <char as Step>::forward_checked(kani::any(), kani::any());
+    }
+
+    #[kani::proof_for_contract(<char as Step>::backward_checked)]
+    fn check_char_backward_checked() {
+        let _ = <char as Step>::backward_checked(kani::any(), kani::any());
+    }
+
+    #[kani::proof]
+    #[kani::unwind(2052)]
+    fn check_char_forward_matches_naive() {
+        let start = any_char_near_edges();
+        let count = kani::any_where(|&count: &usize| count <= GAP_STEPS);
+        assert_eq!(Step::forward_checked(start, count), naive_forward(start, count));
+    }
+
+    #[kani::proof]
+    #[kani::unwind(2052)]
+    fn check_char_backward_matches_naive() {
+        let start = any_char_near_edges();
+        let count = kani::any_where(|&count: &usize| count <= GAP_STEPS);
+        assert_eq!(Step::backward_checked(start, count), naive_backward(start, count));
+    }
+
+    // A forward step never lands on a surrogate, spans `count` code points plus the gap if it
+    // crosses it, and is undone by the matching backward step.
+    #[kani::proof]
+    fn check_char_step_across_gap() {
+        let start: char = kani::any();
+        let count: usize = kani::any();
+        if let Some(end) = Step::forward_checked(start, count) {
+            assert!(!(0xD800..0xE000).contains(&(end as u32)));
+            let crossed = (start as u32) < 0xD800 && (end as u32) >= 0xE000;
+            let span = end as u32 - start as u32;
+            assert_eq!(span as usize, count + if crossed { SURROGATES as usize } else { 0 });
+            assert_eq!(Step::backward_checked(end, count), Some(start));
+        }
+    }
+}
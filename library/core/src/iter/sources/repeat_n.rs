@@ -1,5 +1,9 @@
+use safety::ensures;
+
 use crate::fmt;
 use crate::iter::{FusedIterator, TrustedLen, UncheckedIterator};
+#[cfg(kani)]
+use crate::kani;
 use crate::num::NonZero;
 use crate::ops::Try;
 
@@ -85,6 +89,7 @@ pub struct RepeatN<A> {
 
 impl<A> RepeatN<A> {
     /// If we haven't already dropped the element, return it in an option.
+    #[ensures(|element| element.is_some() == old(self.inner.is_some()) && self.inner.is_none())]
     #[inline]
     fn take_element(&mut self) -> Option<A> {
         self.inner.take().map(|inner| inner.element)
@@ -106,6 +111,9 @@ impl<A: fmt::Debug> fmt::Debug for RepeatN<A> {
 impl<A: Clone> Iterator for RepeatN<A> {
     type Item = A;
 
+    #[cfg_attr(kani, kani::modifies(self))]
+    #[ensures(|item| item.is_some() == (old(self.len()) > 0))]
+    #[ensures(|_| self.len() == old(self.len()).saturating_sub(1))]
     #[inline]
     fn next(&mut self) -> Option<A> {
         let inner = self.inner.as_mut()?;
@@ -127,6 +135,9 @@ impl<A: Clone> Iterator for RepeatN<A> {
         (len, Some(len))
     }
 
+    #[cfg_attr(kani, kani::modifies(self))]
+    #[ensures(|_| self.len() == old(self.len()).saturating_sub(skip))]
+    #[ensures(|result| result.is_ok() == (skip <= old(self.len())))]
     #[inline]
     fn advance_by(&mut self, skip: usize) -> Result<(), NonZero<usize>> {
         let Some(inner) = self.inner.as_mut() else {
@@ -204,3 +215,104 @@ impl<A: Clone> FusedIterator for RepeatN<A> {}
 unsafe impl<A: Clone> TrustedLen for RepeatN<A> {}
 #[stable(feature = "iter_repeat_n", since = "1.82.0")]
 impl<A: Clone> UncheckedIterator for RepeatN<A> {}
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::cell::Cell;
+
+    // `RepeatN` proofs
+
+    const MAX_COUNT: usize = 4;
+
+    struct Counted<'a> {
+        clones: &'a Cell<usize>,
+        drops: &'a Cell<usize>,
+    }
+
+    impl Clone for Counted<'_> {
+        fn clone(&self) -> Self {
+            self.clones.set(self.clones.get() + 1);
+            Counted { clones: self.clones, drops: self.drops }
+        }
+    }
+
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    fn any_repeat_n() -> RepeatN<u8> {
+        repeat_n(kani::any(), kani::any_where(|&count: &usize| count <= MAX_COUNT))
+    }
+
+    #[kani::proof_for_contract(<RepeatN<u8> as Iterator>::next)]
+    fn check_next() {
+        let mut iter = any_repeat_n();
+        let _ = iter.next();
+    }
+
+    #[kani::proof_for_contract(<RepeatN<u8> as Iterator>::advance_by)]
+    fn check_advance_by() {
+        let mut iter = any_repeat_n();
+        let _ = iter.advance_by(kani::any());
+    }
+
+    #[kani::proof_for_contract(RepeatN::<u8>::take_element)]
+    fn check_take_element() {
+        let mut iter = any_repeat_n();
+        let _ = iter.take_element();
+    }
+
+    #[kani::proof]
+    #[kani::unwind(6)]
+    fn check_take_all() {
+        let (clones, drops) = (Cell::new(0), Cell::new(0));
+        let count = kani::any_where(|&count: &usize| count <= MAX_COUNT);
+        let mut iter = repeat_n(Counted { clones: &clones, drops: &drops }, count);
+        let mut taken = 0;
+        while let Some(item) = iter.next() {
+            taken += 1;
+            drop(item);
+        }
+        assert_eq!(taken, count);
+        assert_eq!(clones.get(), count.saturating_sub(1));
+        assert_eq!(drops.get(), count.max(1));
+        drop(iter);
+        assert_eq!(drops.get(), count.max(1));
+    }
+
+    #[kani::proof]
+    #[kani::unwind(6)]
+    fn check_partial_take_then_drop() {
+        let (clones, drops) = (Cell::new(0), Cell::new(0));
+        let count = kani::any_where(|&count: &usize| count <= MAX_COUNT);
+        let take = kani::any_where(|&take: &usize| take <= count);
+        let mut iter = repeat_n(Counted { clones: &clones, drops: &drops }, count);
+        for _ in 0..take {
+            drop(iter.next().unwrap());
+        }
+        drop(iter);
+        // Every clone and the original element are dropped exactly once.
+        assert_eq!(drops.get(), clones.get() + 1);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(6)]
+    fn check_trusted_len() {
+        let mut iter = any_repeat_n();
+        loop {
+            let (lower, upper) = iter.size_hint();
+            assert_eq!(upper, Some(lower));
+            match iter.next() {
+                Some(_) => assert!(lower > 0),
+                None => {
+                    assert_eq!(lower, 0);
+                    break;
+                }
+            }
+        }
+    }
+}
@@ -114,3 +114,76 @@ pub unsafe trait InPlaceIterable {
 #[unstable(feature = "trusted_step", issue = "85731")]
 #[rustc_specialization_trait]
 pub unsafe trait TrustedStep: Step + Copy {}
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::iter::{Chain, Copied, Zip};
+    use crate::ops::Range;
+    use crate::{array, kani, slice};
+
+    // `TrustedLen` proofs
+    // `Vec::from_iter` and `Zip` rely on a `TrustedLen` iterator reporting a `size_hint` that is
+    // exact at every step and yielding exactly that many elements. The harnesses below check this
+    // for bounded constructions of the implementing types.
+
+    const MAX_LEN: usize = 4;
+
+    fn check_exact_size_hint<I: TrustedLen>(mut iter: I) {
+        loop {
+            let (lower, upper) = iter.size_hint();
+            assert_eq!(upper, Some(lower));
+            match iter.next() {
+                Some(_) => assert!(lower > 0),
+                None => {
+                    assert_eq!(lower, 0);
+                    break;
+                }
+            }
+        }
+    }
+
+    /// A possibly empty or reversed range of at most `MAX_LEN` elements.
+    fn any_range() -> Range<u8> {
+        let start: u8 = kani::any();
+        let end = kani::any_where(|&end: &u8| end <= start.saturating_add(MAX_LEN as u8));
+        start..end
+    }
+
+    fn any_into_iter(array: [u8; MAX_LEN]) -> array::IntoIter<u8, MAX_LEN> {
+        let mut iter = array.into_iter();
+        let _ = iter.advance_back_by(kani::any_where(|&n: &usize| n <= MAX_LEN));
+        iter
+    }
+
+    /// Generates a harness checking that the `TrustedLen` iterator `$iter`, built from the
+    /// symbolic arrays `$a` and `$b`, reports exact size hints until it is exhausted.
+    macro_rules! check_trusted_len {
+        ($($harness:ident($a:ident, $b:ident): $ty:ty = $iter:expr;)+) => {$(
+            #[kani::proof]
+            #[kani::unwind(10)]
+            fn $harness() {
+                #[allow(unused_variables)]
+                let $a: [u8; MAX_LEN] = kani::any();
+                #[allow(unused_variables)]
+                let $b: [u8; MAX_LEN] = kani::any();
+                let iter: $ty = $iter;
+                check_exact_size_hint(iter);
+            }
+        )+};
+    }
+
+    check_trusted_len! {
+        check_range(a, b): Range<u8> = any_range();
+        check_slice_iter(a, b): slice::Iter<'_, u8> = kani::slice::any_slice_of_array(&a).iter();
+        check_array_into_iter(a, b): array::IntoIter<u8, MAX_LEN> = any_into_iter(a);
+        check_chain_ranges(a, b): Chain<Range<u8>, Range<u8>> = any_range().chain(any_range());
+        check_chain_mixed(a, b): Chain<Copied<slice::Iter<'_, u8>>, array::IntoIter<u8, MAX_LEN>> =
+            kani::slice::any_slice_of_array(&a).iter().copied().chain(any_into_iter(b));
+        check_zip_slices(a, b): Zip<slice::Iter<'_, u8>, slice::Iter<'_, u8>> =
+            kani::slice::any_slice_of_array(&a).iter().zip(kani::slice::any_slice_of_array(&b));
+        check_zip_mixed(a, b): Zip<Range<u8>, array::IntoIter<u8, MAX_LEN>> =
+            any_range().zip(any_into_iter(b));
+    }
+}
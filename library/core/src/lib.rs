@@ -377,6 +377,10 @@ pub mod primitive;
 #[cfg(kani)]
 kani_core::kani_lib!(core);
 
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod harness_registry;
+
 // Pull in the `core_arch` crate directly into core. The contents of
 // `core_arch` are in a different repository: rust-lang/stdarch.
 //
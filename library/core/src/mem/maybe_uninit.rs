@@ -1,3 +1,5 @@
+use safety::ensures;
+
 use crate::any::type_name;
 use crate::mem::ManuallyDrop;
 use crate::{fmt, intrinsics, ptr, slice};
@@ -377,6 +379,11 @@ impl<T> MaybeUninit<T> {
     #[rustc_diagnostic_item = "maybe_uninit_zeroed"]
     #[stable(feature = "maybe_uninit", since = "1.36.0")]
     #[rustc_const_stable(feature = "const_maybe_uninit_zeroed", since = "1.75.0")]
+    #[ensures(|result: &MaybeUninit<T>| {
+        // SAFETY: every byte of the result, padding included, was just written.
+        let bytes = unsafe { slice::from_raw_parts(result.as_ptr().cast::<u8>(), size_of::<T>()) };
+        bytes.iter().all(|&byte| byte == 0)
+    })]
     pub const fn zeroed() -> MaybeUninit<T> {
         let mut u = MaybeUninit::<T>::uninit();
         // SAFETY: `u.as_mut_ptr()` points to allocated memory.
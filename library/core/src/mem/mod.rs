@@ -1441,4 +1441,97 @@ mod verify {
         forget(x);
         forget(y);
     }
+
+    // `zeroed`/`uninitialized` validity proofs
+
+    enum Void {}
+
+    #[allow(dead_code)]
+    enum NoZero {
+        A = 1,
+        B = 2,
+    }
+
+    macro_rules! check_zeroed {
+        ($($name:ident: $ty:ty => $valid:tt),+ $(,)?) => {$(
+            check_zeroed!(@harness $name, $ty, $valid);
+        )+};
+        (@harness $name:ident, $ty:ty, valid) => {
+            #[kani::proof]
+            fn $name() {
+                let _: $ty = unsafe { zeroed() };
+            }
+        };
+        (@harness $name:ident, $ty:ty, invalid) => {
+            #[kani::proof]
+            #[kani::should_panic]
+            fn $name() {
+                let _: $ty = unsafe { zeroed() };
+            }
+        };
+    }
+
+    check_zeroed! {
+        check_zeroed_u32: u32 => valid,
+        check_zeroed_i128: i128 => valid,
+        check_zeroed_bool: bool => valid,
+        check_zeroed_char: char => valid,
+        check_zeroed_raw_ptr: *const u8 => valid,
+        check_zeroed_option_ref: Option<&u8> => valid,
+        check_zeroed_option_non_zero: Option<crate::num::NonZero<u32>> => valid,
+        check_zeroed_array: [u16; 4] => valid,
+        check_zeroed_unit: () => valid,
+        check_zeroed_ref: &u8 => invalid,
+        check_zeroed_fn: fn() => invalid,
+        check_zeroed_non_zero: crate::num::NonZero<u32> => invalid,
+        check_zeroed_no_zero_enum: NoZero => invalid,
+        check_zeroed_ref_in_tuple: (u8, &u8) => invalid,
+        check_zeroed_void: Void => invalid,
+    }
+
+    #[kani::proof]
+    #[allow(deprecated)]
+    fn check_uninitialized_maybe_uninit() {
+        let _: MaybeUninit<u64> = unsafe { uninitialized() };
+        let _: [MaybeUninit<u8>; 4] = unsafe { uninitialized() };
+    }
+
+    /// `uninitialized` fills the value with `0x01` bytes, which is not a valid `char`.
+    #[kani::proof]
+    #[kani::should_panic]
+    #[allow(deprecated)]
+    fn check_uninitialized_char() {
+        let _: char = unsafe { uninitialized() };
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    #[allow(deprecated)]
+    fn check_uninitialized_void() {
+        let _: Void = unsafe { uninitialized() };
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_assume_init_uninhabited() {
+        let _: Void = unsafe { MaybeUninit::uninit().assume_init() };
+    }
+
+    #[kani::proof]
+    fn check_assume_init_inhabited() {
+        let x: u32 = kani::any();
+        assert_eq!(unsafe { MaybeUninit::new(x).assume_init() }, x);
+    }
+
+    #[kani::proof_for_contract(MaybeUninit::<(u8, u32)>::zeroed)]
+    #[kani::unwind(9)]
+    fn check_maybe_uninit_zeroed_padded() {
+        let _ = MaybeUninit::<(u8, u32)>::zeroed();
+    }
+
+    #[kani::proof_for_contract(MaybeUninit::<[u16; 4]>::zeroed)]
+    #[kani::unwind(9)]
+    fn check_maybe_uninit_zeroed_array() {
+        let _ = MaybeUninit::<[u16; 4]>::zeroed();
+    }
 }
@@ -11,7 +11,7 @@
 
 #![unstable(feature = "f128", issue = "116909")]
 
-use safety::requires;
+use safety::{ensures, requires};
 
 use crate::convert::FloatToInt;
 #[cfg(kani)]
@@ -438,6 +438,12 @@ impl f128 {
     /// ```
     #[inline]
     #[unstable(feature = "f128", issue = "116909")]
+    #[ensures(|result| (*result == FpCategory::Nan) == self.is_nan())]
+    #[ensures(|result| (*result == FpCategory::Infinite)
+        == (self == Self::INFINITY || self == Self::NEG_INFINITY))]
+    #[ensures(|result| (*result == FpCategory::Zero) == (self == 0.0))]
+    #[ensures(|result| (*result == FpCategory::Subnormal)
+        == (self != 0.0 && self < Self::MIN_POSITIVE && self > -Self::MIN_POSITIVE))]
     pub const fn classify(self) -> FpCategory {
         let bits = self.to_bits();
         match (bits & Self::MAN_MASK, bits & Self::EXP_MASK) {
@@ -1296,6 +1302,12 @@ impl f128 {
     #[unstable(feature = "f128", issue = "116909")]
     #[rustc_const_unstable(feature = "f128", issue = "116909")]
     #[must_use = "method returns a new number and does not mutate the original value"]
+    #[ensures(|result: &Self| !result.is_sign_negative())]
+    #[ensures(|result: &Self| if self.is_nan() {
+        result.is_nan()
+    } else {
+        *result == if self < 0.0 { -self } else { self }
+    })]
     pub const fn abs(self) -> Self {
         // FIXME(f16_f128): replace with `intrinsics::fabsf128` when available
         // We don't do this now because LLVM has lowering bugs for f128 math.
@@ -1825,3 +1837,86 @@ impl f128 {
         unsafe { intrinsics::sqrtf128(self) }
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+pub(crate) mod verify {
+    use super::*;
+    use crate::f64::math;
+    use crate::harness_registry::register_harness;
+
+    // An `f128` cannot be narrowed without loss, so the reference runs the other way: every
+    // `f64` widens exactly to an `f128`, and the results of the operations below on such
+    // values are `f64`s as well. Casts do not preserve the sign of NaNs, so NaN signs are
+    // checked without the reference.
+    fn same(result: f128, reference: f64) -> bool {
+        if reference.is_nan() {
+            result.is_nan()
+        } else {
+            result == reference as f128 && result.is_sign_negative() == reference.is_sign_negative()
+        }
+    }
+
+    register_harness! {
+        challenge = "0011-floats-ints",
+        module = "f128",
+
+        #[proof_for_contract(f128::classify)]
+        fn check_classify() {
+            let _ = kani::any::<f128>().classify();
+        }
+
+        #[proof_for_contract(f128::abs)]
+        fn check_abs() {
+            let _ = kani::any::<f128>().abs();
+        }
+
+        #[proof]
+        fn check_classify_widened() {
+            let x: f64 = kani::any();
+            let narrow = x.classify();
+            match (x as f128).classify() {
+                // Subnormal `f64`s are normal `f128`s.
+                FpCategory::Normal => {
+                    assert!(narrow == FpCategory::Normal || narrow == FpCategory::Subnormal)
+                }
+                category => assert_eq!(category, narrow),
+            }
+        }
+
+        #[proof]
+        fn check_sign_ops_widened() {
+            let (x, y): (f64, f64) = kani::any();
+            let (wx, wy) = (x as f128, y as f128);
+            assert!(same(wx.abs(), x.abs()));
+            assert!(same(wx.copysign(wy), x.copysign(y)) || y.is_nan());
+            assert_eq!(wx.copysign(wy).is_sign_negative(), wy.is_sign_negative());
+            assert!(same(wx.signum(), x.signum()));
+            assert!(x.is_nan() || wx.is_sign_negative() == x.is_sign_negative());
+        }
+
+        // `min` and `max` may return either zero when comparing `0.0` with `-0.0`, so only the
+        // value is compared.
+        #[proof]
+        fn check_min_max_widened() {
+            let (x, y): (f64, f64) = kani::any();
+            let (wx, wy) = (x as f128, y as f128);
+            let min = wx.min(wy);
+            let max = wx.max(wy);
+            assert!(if x.min(y).is_nan() { min.is_nan() } else { min == x.min(y) as f128 });
+            assert!(if x.max(y).is_nan() { max.is_nan() } else { max == x.max(y) as f128 });
+        }
+
+        #[proof]
+        fn check_rounding_widened() {
+            let x: f64 = kani::any();
+            kani::assume(!x.is_nan());
+            let wide = x as f128;
+            assert!(same(wide.floor(), math::floor(x)));
+            assert!(same(wide.ceil(), math::ceil(x)));
+            assert!(same(wide.round(), math::round(x)));
+            assert!(same(wide.round_ties_even(), math::round_ties_even(x)));
+            assert!(same(wide.trunc(), math::trunc(x)));
+        }
+    }
+}
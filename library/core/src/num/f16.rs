@@ -11,7 +11,7 @@
 
 #![unstable(feature = "f16", issue = "116909")]
 
-use safety::requires;
+use safety::{ensures, requires};
 
 use crate::convert::FloatToInt;
 #[cfg(kani)]
@@ -427,6 +427,12 @@ impl f16 {
     /// ```
     #[inline]
     #[unstable(feature = "f16", issue = "116909")]
+    #[ensures(|result| (*result == FpCategory::Nan) == self.is_nan())]
+    #[ensures(|result| (*result == FpCategory::Infinite)
+        == (self == Self::INFINITY || self == Self::NEG_INFINITY))]
+    #[ensures(|result| (*result == FpCategory::Zero) == (self == 0.0))]
+    #[ensures(|result| (*result == FpCategory::Subnormal)
+        == (self != 0.0 && self < Self::MIN_POSITIVE && self > -Self::MIN_POSITIVE))]
     pub const fn classify(self) -> FpCategory {
         let b = self.to_bits();
         match (b & Self::MAN_MASK, b & Self::EXP_MASK) {
@@ -1274,6 +1280,12 @@ impl f16 {
     #[unstable(feature = "f16", issue = "116909")]
     #[rustc_const_unstable(feature = "f16", issue = "116909")]
     #[must_use = "method returns a new number and does not mutate the original value"]
+    #[ensures(|result: &Self| !result.is_sign_negative())]
+    #[ensures(|result: &Self| if self.is_nan() {
+        result.is_nan()
+    } else {
+        *result == if self < 0.0 { -self } else { self }
+    })]
     pub const fn abs(self) -> Self {
         // FIXME(f16_f128): replace with `intrinsics::fabsf16` when available
         Self::from_bits(self.to_bits() & !(1 << 15))
@@ -1833,3 +1845,82 @@ impl f16 {
         libm::cbrtf(self as f32) as f16
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+pub(crate) mod verify {
+    use super::*;
+    use crate::f64::math;
+    use crate::harness_registry::register_harness;
+
+    // Every `f16` is exactly representable as an `f64`, and so is every `f16` result of the
+    // operations below, so the bit-level `f16` code can be checked against the `f64` one. Casts
+    // do not preserve the sign of NaNs, so NaN signs are checked without the reference.
+    fn same(result: f16, reference: f64) -> bool {
+        if reference.is_nan() {
+            result.is_nan()
+        } else {
+            result as f64 == reference && result.is_sign_negative() == reference.is_sign_negative()
+        }
+    }
+
+    register_harness! {
+        challenge = "0011-floats-ints",
+        module = "f16",
+
+        #[proof_for_contract(f16::classify)]
+        fn check_classify() {
+            let _ = kani::any::<f16>().classify();
+        }
+
+        #[proof_for_contract(f16::abs)]
+        fn check_abs() {
+            let _ = kani::any::<f16>().abs();
+        }
+
+        #[proof]
+        fn check_classify_widened() {
+            let x: f16 = kani::any();
+            let wide = (x as f64).classify();
+            match x.classify() {
+                // Subnormal `f16`s are normal `f64`s.
+                FpCategory::Subnormal => assert_eq!(wide, FpCategory::Normal),
+                category => assert_eq!(category, wide),
+            }
+        }
+
+        #[proof]
+        fn check_sign_ops_widened() {
+            let (x, y): (f16, f16) = kani::any();
+            assert!(same(x.abs(), (x as f64).abs()));
+            assert!(same(x.copysign(y), (x as f64).copysign(y as f64)) || y.is_nan());
+            assert_eq!(x.copysign(y).is_sign_negative(), y.is_sign_negative());
+            assert!(same(x.signum(), (x as f64).signum()));
+            assert!(x.is_nan() || x.is_sign_negative() == (x as f64).is_sign_negative());
+        }
+
+        // `min` and `max` may return either zero when comparing `0.0` with `-0.0`, so only the
+        // value is compared.
+        #[proof]
+        fn check_min_max_widened() {
+            let (x, y): (f16, f16) = kani::any();
+            let (wx, wy) = (x as f64, y as f64);
+            let min = x.min(y);
+            let max = x.max(y);
+            assert!(if wx.min(wy).is_nan() { min.is_nan() } else { min as f64 == wx.min(wy) });
+            assert!(if wx.max(wy).is_nan() { max.is_nan() } else { max as f64 == wx.max(wy) });
+        }
+
+        #[proof]
+        fn check_rounding_widened() {
+            let x: f16 = kani::any();
+            kani::assume(!x.is_nan());
+            let wide = x as f64;
+            assert!(same(x.floor(), math::floor(wide)));
+            assert!(same(x.ceil(), math::ceil(wide)));
+            assert!(same(x.round(), math::round(wide)));
+            assert!(same(x.round_ties_even(), math::round_ties_even(wide)));
+            assert!(same(x.trunc(), math::trunc(wide)));
+        }
+    }
+}
@@ -11,7 +11,7 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
-use safety::requires;
+use safety::{ensures, requires};
 
 use crate::convert::FloatToInt;
 #[cfg(kani)]
@@ -535,6 +535,7 @@ impl f32 {
     #[rustc_const_stable(feature = "const_float_classify", since = "1.83.0")]
     #[inline]
     #[allow(clippy::eq_op)] // > if you intended to check if the operand is NaN, use `.is_nan()` instead :)
+    #[ensures(|result| *result == matches!(self.classify(), FpCategory::Nan))]
     pub const fn is_nan(self) -> bool {
         self != self
     }
@@ -558,6 +559,7 @@ impl f32 {
     #[stable(feature = "rust1", since = "1.0.0")]
     #[rustc_const_stable(feature = "const_float_classify", since = "1.83.0")]
     #[inline]
+    #[ensures(|result| *result == matches!(self.classify(), FpCategory::Infinite))]
     pub const fn is_infinite(self) -> bool {
         // Getting clever with transmutation can result in incorrect answers on some FPUs
         // FIXME: alter the Rust <-> Rust calling convention to prevent this problem.
@@ -583,6 +585,7 @@ impl f32 {
     #[stable(feature = "rust1", since = "1.0.0")]
     #[rustc_const_stable(feature = "const_float_classify", since = "1.83.0")]
     #[inline]
+    #[ensures(|result| *result != matches!(self.classify(), FpCategory::Nan | FpCategory::Infinite))]
     pub const fn is_finite(self) -> bool {
         // There's no need to handle NaN separately: if self is NaN,
         // the comparison is not true, exactly as desired.
@@ -611,6 +614,7 @@ impl f32 {
     #[stable(feature = "is_subnormal", since = "1.53.0")]
     #[rustc_const_stable(feature = "const_float_classify", since = "1.83.0")]
     #[inline]
+    #[ensures(|result| *result == (self != 0.0 && self.abs() < Self::MIN_POSITIVE))]
     pub const fn is_subnormal(self) -> bool {
         matches!(self.classify(), FpCategory::Subnormal)
     }
@@ -638,6 +642,7 @@ impl f32 {
     #[stable(feature = "rust1", since = "1.0.0")]
     #[rustc_const_stable(feature = "const_float_classify", since = "1.83.0")]
     #[inline]
+    #[ensures(|result| *result == (self.abs() >= Self::MIN_POSITIVE && self.abs() < Self::INFINITY))]
     pub const fn is_normal(self) -> bool {
         matches!(self.classify(), FpCategory::Normal)
     }
@@ -756,6 +761,10 @@ impl f32 {
     #[doc(alias = "nextUp")]
     #[stable(feature = "float_next_up_down", since = "1.86.0")]
     #[rustc_const_stable(feature = "float_next_up_down", since = "1.86.0")]
+    #[ensures(|result: &Self| !self.is_nan() || result.to_bits() == self.to_bits())]
+    #[ensures(|result: &Self| self.is_nan() || !result.is_nan())]
+    #[ensures(|result: &Self| self.is_nan() || self == Self::INFINITY || *result > self)]
+    #[ensures(|result: &Self| self.is_nan() || self == Self::INFINITY || result.next_down() == self)]
     pub const fn next_up(self) -> Self {
         // Some targets violate Rust's assumption of IEEE semantics, e.g. by flushing
         // denormals to zero. This is in general unsound and unsupported, but here
@@ -807,6 +816,10 @@ impl f32 {
     #[doc(alias = "nextDown")]
     #[stable(feature = "float_next_up_down", since = "1.86.0")]
     #[rustc_const_stable(feature = "float_next_up_down", since = "1.86.0")]
+    #[ensures(|result: &Self| !self.is_nan() || result.to_bits() == self.to_bits())]
+    #[ensures(|result: &Self| self.is_nan() || !result.is_nan())]
+    #[ensures(|result: &Self| self.is_nan() || self == Self::NEG_INFINITY || *result < self)]
+    #[ensures(|result: &Self| self.is_nan() || self == Self::NEG_INFINITY || result.next_up() == self)]
     pub const fn next_down(self) -> Self {
         // Some targets violate Rust's assumption of IEEE semantics, e.g. by flushing
         // denormals to zero. This is in general unsound and unsupported, but here
@@ -1095,6 +1108,8 @@ impl f32 {
     #[rustc_const_stable(feature = "const_float_bits_conv", since = "1.83.0")]
     #[inline]
     #[allow(unnecessary_transmutes)]
+    #[ensures(|result| Self::from_bits(*result).to_bits() == *result)]
+    #[ensures(|result| self.is_nan() || Self::from_bits(*result) == self)]
     pub const fn to_bits(self) -> u32 {
         // SAFETY: `u32` is a plain old datatype so we can always transmute to it.
         unsafe { mem::transmute(self) }
@@ -1141,6 +1156,7 @@ impl f32 {
     #[must_use]
     #[inline]
     #[allow(unnecessary_transmutes)]
+    #[ensures(|result: &Self| result.to_bits() == v)]
     pub const fn from_bits(v: u32) -> Self {
         // It turns out the safety issues with sNaN were overblown! Hooray!
         // SAFETY: `u32` is a plain old datatype so we can always transmute from it.
@@ -1409,6 +1425,9 @@ impl f32 {
     #[stable(feature = "clamp", since = "1.50.0")]
     #[rustc_const_stable(feature = "const_float_methods", since = "1.85.0")]
     #[inline]
+    #[ensures(|result| result.is_nan() == self.is_nan())]
+    #[ensures(|result| self.is_nan() || (min <= *result && *result <= max))]
+    #[ensures(|result| !(min <= self && self <= max) || result.to_bits() == self.to_bits())]
     pub const fn clamp(mut self, min: f32, max: f32) -> f32 {
         const_assert!(
             min <= max,
@@ -2010,3 +2029,87 @@ pub mod math {
         libm::cbrtf(x)
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+pub(crate) mod verify {
+    use super::*;
+    use crate::harness_registry::register_harness;
+
+    register_harness! {
+        challenge = "0011-floats-ints",
+        module = "f32",
+
+        // `next_up` and `next_down` proofs
+        #[proof_for_contract(f32::next_up)]
+        fn check_next_up() {
+            let _ = kani::any::<f32>().next_up();
+        }
+
+        #[proof_for_contract(f32::next_down)]
+        fn check_next_down() {
+            let _ = kani::any::<f32>().next_down();
+        }
+
+        #[proof]
+        fn check_next_up_is_least_greater() {
+            let x: f32 = kani::any();
+            let y: f32 = kani::any();
+            let next = x.next_up();
+            assert!(!(x < y && y < next));
+        }
+
+        #[proof]
+        fn check_next_down_is_greatest_less() {
+            let x: f32 = kani::any();
+            let y: f32 = kani::any();
+            let next = x.next_down();
+            assert!(!(next < y && y < x));
+        }
+
+        // `classify` and the classification predicates
+        #[proof_for_contract(f32::is_nan)]
+        fn check_is_nan() {
+            let _ = kani::any::<f32>().is_nan();
+        }
+
+        #[proof_for_contract(f32::is_infinite)]
+        fn check_is_infinite() {
+            let _ = kani::any::<f32>().is_infinite();
+        }
+
+        #[proof_for_contract(f32::is_finite)]
+        fn check_is_finite() {
+            let _ = kani::any::<f32>().is_finite();
+        }
+
+        #[proof_for_contract(f32::is_normal)]
+        fn check_is_normal() {
+            let _ = kani::any::<f32>().is_normal();
+        }
+
+        #[proof_for_contract(f32::is_subnormal)]
+        fn check_is_subnormal() {
+            let _ = kani::any::<f32>().is_subnormal();
+        }
+
+        // The categories partition the bit patterns by magnitude, independently of the sign bit.
+        #[proof]
+        fn check_classify_bit_ranges() {
+            let bits: u32 = kani::any();
+            let magnitude = bits & !f32::SIGN_MASK;
+            let expected = if magnitude == 0 {
+                FpCategory::Zero
+            } else if magnitude < 0x0080_0000 {
+                FpCategory::Subnormal
+            } else if magnitude < 0x7f80_0000 {
+                FpCategory::Normal
+            } else if magnitude == 0x7f80_0000 {
+                FpCategory::Infinite
+            } else {
+                FpCategory::Nan
+            };
+            assert_eq!(f32::from_bits(bits).classify(), expected);
+        }
+    }
+}
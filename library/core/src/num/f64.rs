@@ -11,7 +11,7 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
-use safety::requires;
+use safety::{ensures, requires};
 
 use crate::convert::FloatToInt;
 #[cfg(kani)]
@@ -534,6 +534,7 @@ impl f64 {
     #[rustc_const_stable(feature = "const_float_classify", since = "1.83.0")]
     #[inline]
     #[allow(clippy::eq_op)] // > if you intended to check if the operand is NaN, use `.is_nan()` instead :)
+    #[ensures(|result| *result == matches!(self.classify(), FpCategory::Nan))]
     pub const fn is_nan(self) -> bool {
         self != self
     }
@@ -557,6 +558,7 @@ impl f64 {
     #[stable(feature = "rust1", since = "1.0.0")]
     #[rustc_const_stable(feature = "const_float_classify", since = "1.83.0")]
     #[inline]
+    #[ensures(|result| *result == matches!(self.classify(), FpCategory::Infinite))]
     pub const fn is_infinite(self) -> bool {
         // Getting clever with transmutation can result in incorrect answers on some FPUs
         // FIXME: alter the Rust <-> Rust calling convention to prevent this problem.
@@ -582,6 +584,7 @@ impl f64 {
     #[stable(feature = "rust1", since = "1.0.0")]
     #[rustc_const_stable(feature = "const_float_classify", since = "1.83.0")]
     #[inline]
+    #[ensures(|result| *result != matches!(self.classify(), FpCategory::Nan | FpCategory::Infinite))]
     pub const fn is_finite(self) -> bool {
         // There's no need to handle NaN separately: if self is NaN,
         // the comparison is not true, exactly as desired.
@@ -610,6 +613,7 @@ impl f64 {
     #[stable(feature = "is_subnormal", since = "1.53.0")]
     #[rustc_const_stable(feature = "const_float_classify", since = "1.83.0")]
     #[inline]
+    #[ensures(|result| *result == (self != 0.0 && self.abs() < Self::MIN_POSITIVE))]
     pub const fn is_subnormal(self) -> bool {
         matches!(self.classify(), FpCategory::Subnormal)
     }
@@ -637,6 +641,7 @@ impl f64 {
     #[stable(feature = "rust1", since = "1.0.0")]
     #[rustc_const_stable(feature = "const_float_classify", since = "1.83.0")]
     #[inline]
+    #[ensures(|result| *result == (self.abs() >= Self::MIN_POSITIVE && self.abs() < Self::INFINITY))]
     pub const fn is_normal(self) -> bool {
         matches!(self.classify(), FpCategory::Normal)
     }
@@ -773,6 +778,10 @@ impl f64 {
     #[doc(alias = "nextUp")]
     #[stable(feature = "float_next_up_down", since = "1.86.0")]
     #[rustc_const_stable(feature = "float_next_up_down", since = "1.86.0")]
+    #[ensures(|result: &Self| !self.is_nan() || result.to_bits() == self.to_bits())]
+    #[ensures(|result: &Self| self.is_nan() || !result.is_nan())]
+    #[ensures(|result: &Self| self.is_nan() || self == Self::INFINITY || *result > self)]
+    #[ensures(|result: &Self| self.is_nan() || self == Self::INFINITY || result.next_down() == self)]
     pub const fn next_up(self) -> Self {
         // Some targets violate Rust's assumption of IEEE semantics, e.g. by flushing
         // denormals to zero. This is in general unsound and unsupported, but here
@@ -824,6 +833,10 @@ impl f64 {
     #[doc(alias = "nextDown")]
     #[stable(feature = "float_next_up_down", since = "1.86.0")]
     #[rustc_const_stable(feature = "float_next_up_down", since = "1.86.0")]
+    #[ensures(|result: &Self| !self.is_nan() || result.to_bits() == self.to_bits())]
+    #[ensures(|result: &Self| self.is_nan() || !result.is_nan())]
+    #[ensures(|result: &Self| self.is_nan() || self == Self::NEG_INFINITY || *result < self)]
+    #[ensures(|result: &Self| self.is_nan() || self == Self::NEG_INFINITY || result.next_up() == self)]
     pub const fn next_down(self) -> Self {
         // Some targets violate Rust's assumption of IEEE semantics, e.g. by flushing
         // denormals to zero. This is in general unsound and unsupported, but here
@@ -1093,6 +1106,8 @@ impl f64 {
     #[rustc_const_stable(feature = "const_float_bits_conv", since = "1.83.0")]
     #[allow(unnecessary_transmutes)]
     #[inline]
+    #[ensures(|result| Self::from_bits(*result).to_bits() == *result)]
+    #[ensures(|result| self.is_nan() || Self::from_bits(*result) == self)]
     pub const fn to_bits(self) -> u64 {
         // SAFETY: `u64` is a plain old datatype so we can always transmute to it.
         unsafe { mem::transmute(self) }
@@ -1139,6 +1154,7 @@ impl f64 {
     #[must_use]
     #[inline]
     #[allow(unnecessary_transmutes)]
+    #[ensures(|result: &Self| result.to_bits() == v)]
     pub const fn from_bits(v: u64) -> Self {
         // It turns out the safety issues with sNaN were overblown! Hooray!
         // SAFETY: `u64` is a plain old datatype so we can always transmute from it.
@@ -1407,6 +1423,9 @@ impl f64 {
     #[stable(feature = "clamp", since = "1.50.0")]
     #[rustc_const_stable(feature = "const_float_methods", since = "1.85.0")]
     #[inline]
+    #[ensures(|result| result.is_nan() == self.is_nan())]
+    #[ensures(|result| self.is_nan() || (min <= *result && *result <= max))]
+    #[ensures(|result| !(min <= self && self <= max) || result.to_bits() == self.to_bits())]
     pub const fn clamp(mut self, min: f64, max: f64) -> f64 {
         const_assert!(
             min <= max,
@@ -2001,3 +2020,87 @@ pub mod math {
         libm::cbrt(x)
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+pub(crate) mod verify {
+    use super::*;
+    use crate::harness_registry::register_harness;
+
+    register_harness! {
+        challenge = "0011-floats-ints",
+        module = "f64",
+
+        // `next_up` and `next_down` proofs
+        #[proof_for_contract(f64::next_up)]
+        fn check_next_up() {
+            let _ = kani::any::<f64>().next_up();
+        }
+
+        #[proof_for_contract(f64::next_down)]
+        fn check_next_down() {
+            let _ = kani::any::<f64>().next_down();
+        }
+
+        #[proof]
+        fn check_next_up_is_least_greater() {
+            let x: f64 = kani::any();
+            let y: f64 = kani::any();
+            let next = x.next_up();
+            assert!(!(x < y && y < next));
+        }
+
+        #[proof]
+        fn check_next_down_is_greatest_less() {
+            let x: f64 = kani::any();
+            let y: f64 = kani::any();
+            let next = x.next_down();
+            assert!(!(next < y && y < x));
+        }
+
+        // `classify` and the classification predicates
+        #[proof_for_contract(f64::is_nan)]
+        fn check_is_nan() {
+            let _ = kani::any::<f64>().is_nan();
+        }
+
+        #[proof_for_contract(f64::is_infinite)]
+        fn check_is_infinite() {
+            let _ = kani::any::<f64>().is_infinite();
+        }
+
+        #[proof_for_contract(f64::is_finite)]
+        fn check_is_finite() {
+            let _ = kani::any::<f64>().is_finite();
+        }
+
+        #[proof_for_contract(f64::is_normal)]
+        fn check_is_normal() {
+            let _ = kani::any::<f64>().is_normal();
+        }
+
+        #[proof_for_contract(f64::is_subnormal)]
+        fn check_is_subnormal() {
+            let _ = kani::any::<f64>().is_subnormal();
+        }
+
+        // The categories partition the bit patterns by magnitude, independently of the sign bit.
+        #[proof]
+        fn check_classify_bit_ranges() {
+            let bits: u64 = kani::any();
+            let magnitude = bits & !f64::SIGN_MASK;
+            let expected = if magnitude == 0 {
+                FpCategory::Zero
+            } else if magnitude < 0x0010_0000_0000_0000 {
+                FpCategory::Subnormal
+            } else if magnitude < 0x7ff0_0000_0000_0000 {
+                FpCategory::Normal
+            } else if magnitude == 0x7ff0_0000_0000_0000 {
+                FpCategory::Infinite
+            } else {
+                FpCategory::Nan
+            };
+            assert_eq!(f64::from_bits(bits).classify(), expected);
+        }
+    }
+}
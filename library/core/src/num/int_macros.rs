@@ -8,6 +8,8 @@ macro_rules! int_impl {
         // As such, they're all passed as literals -- passing them as a string
         // literal is fine if they need to be multiple code tokens.
         // In non-comments, use the associated constants rather than these.
+        // The exception are the Flux specifications, which use `$Min` and `$Max`
+        // because refinements cannot refer to the associated constants.
         BITS = $BITS:literal,
         BITS_MINUS_ONE = $BITS_MINUS_ONE:literal,
         Min = $Min:literal,
@@ -452,6 +454,7 @@ macro_rules! int_impl {
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
         #[inline]
+        #[cfg_attr(flux, flux::spec(fn(Self[@a], Self[@b]) -> Option<Self[a + b]>))]
         pub const fn checked_add(self, rhs: Self) -> Option<Self> {
             let (a, b) = self.overflowing_add(rhs);
             if intrinsics::unlikely(b) { None } else { Some(a) }
@@ -514,6 +517,7 @@ macro_rules! int_impl {
         #[inline(always)]
         #[track_caller]
         #[requires(!self.overflowing_add(rhs).1)]
+        #[cfg_attr(flux, flux::spec(fn(Self[@a], Self{b: $Min <= a + b && a + b <= $Max}) -> Self[a + b]))]
         pub const unsafe fn unchecked_add(self, rhs: Self) -> Self {
             assert_unsafe_precondition!(
                 check_language_ub,
@@ -595,6 +599,7 @@ macro_rules! int_impl {
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
         #[inline]
+        #[cfg_attr(flux, flux::spec(fn(Self[@a], Self[@b]) -> Option<Self[a - b]>))]
         pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
             let (a, b) = self.overflowing_sub(rhs);
             if intrinsics::unlikely(b) { None } else { Some(a) }
@@ -657,6 +662,7 @@ macro_rules! int_impl {
         #[inline(always)]
         #[track_caller]
         #[requires(!self.overflowing_sub(rhs).1)] // Preconditions: No overflow should occur
+        #[cfg_attr(flux, flux::spec(fn(Self[@a], Self{b: $Min <= a - b && a - b <= $Max}) -> Self[a - b]))]
         pub const unsafe fn unchecked_sub(self, rhs: Self) -> Self {
             assert_unsafe_precondition!(
                 check_language_ub,
@@ -738,6 +744,7 @@ macro_rules! int_impl {
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
         #[inline]
+        #[cfg_attr(flux, flux::spec(fn(Self[@a], Self[@b]) -> Option<Self[a * b]>))]
         pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
             let (a, b) = self.overflowing_mul(rhs);
             if intrinsics::unlikely(b) { None } else { Some(a) }
@@ -800,6 +807,7 @@ macro_rules! int_impl {
         #[inline(always)]
         #[track_caller]
         #[requires(!self.overflowing_mul(rhs).1)]
+        #[cfg_attr(flux, flux::spec(fn(Self[@a], Self{b: $Min <= a * b && a * b <= $Max}) -> Self[a * b]))]
         pub const unsafe fn unchecked_mul(self, rhs: Self) -> Self {
             assert_unsafe_precondition!(
                 check_language_ub,
@@ -1587,6 +1595,7 @@ macro_rules! int_impl {
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
         #[inline]
+        #[cfg_attr(kani, ensures(|result| *result == self.pow_reference(exp)))]
         pub const fn checked_pow(self, mut exp: u32) -> Option<Self> {
             if exp == 0 {
                 return Some(1);
@@ -1594,7 +1603,9 @@ macro_rules! int_impl {
             let mut base = self;
             let mut acc: Self = 1;
 
-            #[safety::loop_invariant(true)]
+            // `acc * base^exp` is the power being computed.
+            #[safety::loop_invariant(exp >= 1
+                && base.pow_reference(exp).and_then(|power| acc.checked_mul(power)) == self.pow_reference(on_entry(exp)))]
             loop {
                 if (exp & 1) == 1 {
                     acc = try_opt!(acc.checked_mul(base));
@@ -1608,6 +1619,31 @@ macro_rules! int_impl {
             }
         }
 
+        // Returns `self^exp` computed by repeated multiplication, or `None` if
+        // it does not fit in `Self`. Reference for the exponentiation
+        // contracts, which must not depend on exponentiation by squaring.
+        //
+        // The bases 0, 1 and -1 are handled up front, so the loop runs at
+        // most `BITS` times: any other base overflows before then.
+        #[cfg(kani)]
+        fn pow_reference(self, exp: u32) -> Option<Self> {
+            match self {
+                0 | 1 => return Some(if exp == 0 { 1 } else { self }),
+                -1 => return Some(if exp % 2 == 0 { 1 } else { -1 }),
+                _ => {}
+            }
+            let mut power: Self = 1;
+            let mut i = 0;
+            while i < exp {
+                power = match power.checked_mul(self) {
+                    Some(power) => power,
+                    None => return None,
+                };
+                i += 1;
+            }
+            Some(power)
+        }
+
         /// Strict exponentiation. Computes `self.pow(exp)`, panicking if
         /// overflow occurred.
         ///
@@ -1719,6 +1755,7 @@ macro_rules! int_impl {
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
         #[inline(always)]
+        #[cfg_attr(flux, flux::spec(fn(Self[@a], Self[@b]) -> Self{v: v == if a + b < $Min { $Min } else if a + b > $Max { $Max } else { a + b }}))]
         pub const fn saturating_add(self, rhs: Self) -> Self {
             intrinsics::saturating_add(self, rhs)
         }
@@ -1761,6 +1798,7 @@ macro_rules! int_impl {
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
         #[inline(always)]
+        #[cfg_attr(flux, flux::spec(fn(Self[@a], Self[@b]) -> Self{v: v == if a - b < $Min { $Min } else if a - b > $Max { $Max } else { a - b }}))]
         pub const fn saturating_sub(self, rhs: Self) -> Self {
             intrinsics::saturating_sub(self, rhs)
         }
@@ -1849,6 +1887,7 @@ macro_rules! int_impl {
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
         #[inline]
+        #[cfg_attr(flux, flux::spec(fn(Self[@a], Self[@b]) -> Self{v: v == if a * b < $Min { $Min } else if a * b > $Max { $Max } else { a * b }}))]
         pub const fn saturating_mul(self, rhs: Self) -> Self {
             match self.checked_mul(rhs) {
                 Some(x) => x,
@@ -1924,6 +1963,7 @@ macro_rules! int_impl {
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
         #[inline(always)]
+        #[cfg_attr(flux, flux::spec(fn(Self[@a], Self[@b]) -> Self{v: ($Min <= a + b && a + b <= $Max) => v == a + b}))]
         pub const fn wrapping_add(self, rhs: Self) -> Self {
             intrinsics::wrapping_add(self, rhs)
         }
@@ -1960,6 +2000,7 @@ macro_rules! int_impl {
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
         #[inline(always)]
+        #[cfg_attr(flux, flux::spec(fn(Self[@a], Self[@b]) -> Self{v: ($Min <= a - b && a - b <= $Max) => v == a - b}))]
         pub const fn wrapping_sub(self, rhs: Self) -> Self {
             intrinsics::wrapping_sub(self, rhs)
         }
@@ -1996,6 +2037,7 @@ macro_rules! int_impl {
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
         #[inline(always)]
+        #[cfg_attr(flux, flux::spec(fn(Self[@a], Self[@b]) -> Self{v: ($Min <= a * b && a * b <= $Max) => v == a * b}))]
         pub const fn wrapping_mul(self, rhs: Self) -> Self {
             intrinsics::wrapping_mul(self, rhs)
         }
@@ -2253,7 +2295,7 @@ macro_rules! int_impl {
             let mut base = self;
             let mut acc: Self = 1;
 
-            if intrinsics::is_val_statically_known(exp) {
+            if intrinsics::is_val_statically_known_or_any(exp) {
                 #[safety::loop_invariant(exp>=1)]
                 while exp > 1 {
                     if (exp & 1) == 1 {
@@ -2842,6 +2884,10 @@ macro_rules! int_impl {
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
         #[inline]
+        #[cfg_attr(kani, ensures(|result| result.1 == self.pow_reference(exp).is_none()))]
+        #[cfg_attr(kani, ensures(|result| {
+            self.pow_reference(exp).is_none_or(|power| power == result.0)
+        }))]
         pub const fn overflowing_pow(self, mut exp: u32) -> (Self, bool) {
             if exp == 0 {
                 return (1,false);
@@ -2852,6 +2898,11 @@ macro_rules! int_impl {
             // Scratch space for storing results of overflowing_mul.
             let mut r;
 
+            // Until the first overflow, `acc * base^exp` is the power being
+            // computed; once it happened, the power does not fit.
+            #[safety::loop_invariant(exp >= 1
+                && (overflown || base.pow_reference(exp).and_then(|power| acc.checked_mul(power)) == self.pow_reference(on_entry(exp)))
+                && (!overflown || self.pow_reference(on_entry(exp)).is_none()))]
             loop {
                 if (exp & 1) == 1 {
                     r = acc.overflowing_mul(base);
@@ -2885,6 +2936,7 @@ macro_rules! int_impl {
                       without modifying the original"]
         #[inline]
         #[rustc_inherit_overflow_checks]
+        #[cfg_attr(kani, ensures(|result| self.pow_reference(exp) == Some(*result)))]
         pub const fn pow(self, mut exp: u32) -> Self {
             if exp == 0 {
                 return 1;
@@ -2892,7 +2944,9 @@ macro_rules! int_impl {
             let mut base = self;
             let mut acc = 1;
 
-            if intrinsics::is_val_statically_known(exp) {
+            if intrinsics::is_val_statically_known_or_any(exp) {
+                #[safety::loop_invariant(exp >= 1
+                    && base.pow_reference(exp).and_then(|power| acc.checked_mul(power)) == self.pow_reference(on_entry(exp)))]
                 while exp > 1 {
                     if (exp & 1) == 1 {
                         acc = acc * base;
@@ -2911,6 +2965,8 @@ macro_rules! int_impl {
                 // at compile time. We can't use the same code for the constant
                 // exponent case because LLVM is currently unable to unroll
                 // this loop.
+                #[safety::loop_invariant(exp >= 1
+                    && base.pow_reference(exp).and_then(|power| acc.checked_mul(power)) == self.pow_reference(on_entry(exp)))]
                 loop {
                     if (exp & 1) == 1 {
                         acc = acc * base;
@@ -2983,6 +3039,13 @@ macro_rules! int_impl {
                       without modifying the original"]
         #[inline]
         #[track_caller]
+        // Postcondition: `self == result * rhs + r` with `0 <= r < abs(rhs)`, where the identity
+        // is checked on the double-width product so that it cannot overflow
+        #[ensures(|result| {
+            let r = self.rem_euclid(rhs);
+            r >= 0 && (r as $UnsignedT) < rhs.unsigned_abs()
+                && result.carrying_mul_add(rhs, 0, r) == (self as $UnsignedT, self >> ($BITS - 1))
+        })]
         pub const fn div_euclid(self, rhs: Self) -> Self {
             let q = self / rhs;
             if self % rhs < 0 {
@@ -3026,6 +3089,11 @@ macro_rules! int_impl {
                       without modifying the original"]
         #[inline]
         #[track_caller]
+        // Postcondition: `0 <= result < abs(rhs)` and `self == q * rhs + result` for the
+        // Euclidean quotient `q`, again checked on the double-width product
+        #[ensures(|result| *result >= 0 && (*result as $UnsignedT) < rhs.unsigned_abs()
+            && self.div_euclid(rhs).carrying_mul_add(rhs, 0, *result)
+                == (self as $UnsignedT, self >> ($BITS - 1)))]
         pub const fn rem_euclid(self, rhs: Self) -> Self {
             let r = self % rhs;
             if r < 0 {
@@ -154,6 +154,10 @@ macro_rules! midpoint_impl {
         #[doc(alias = "average_floor")]
         #[doc(alias = "average")]
         #[inline]
+        // Postcondition: the result is the floor of the average, which lies between both inputs
+        #[ensures(|result| *result == (self >> 1) + (rhs >> 1) + (self & rhs & 1)
+            && *result >= if self < rhs { self } else { rhs }
+            && *result <= if self < rhs { rhs } else { self })]
         pub const fn midpoint(self, rhs: $SelfT) -> $SelfT {
             // Use the well known branchless algorithm from Hacker's Delight to compute
             // `(a + b) / 2` without overflowing: `((a ^ b) >> 1) + (a & b)`.
@@ -184,6 +188,12 @@ macro_rules! midpoint_impl {
         #[doc(alias = "average_ceil")]
         #[doc(alias = "average")]
         #[inline]
+        // Postcondition: the result is the exact average rounded towards zero: the floor of the
+        // average, plus one when the sum is negative and odd
+        #[ensures(|result| {
+            let floor = (self >> 1) + (rhs >> 1) + (self & rhs & 1);
+            *result == floor + if floor < 0 && (self ^ rhs) & 1 != 0 { 1 } else { 0 }
+        })]
         pub const fn midpoint(self, rhs: Self) -> Self {
             // Use the well known branchless algorithm from Hacker's Delight to compute
             // `(a + b) / 2` without overflowing: `((a ^ b) >> 1) + (a & b)`.
@@ -213,6 +223,10 @@ macro_rules! midpoint_impl {
         #[doc(alias = "average_floor")]
         #[doc(alias = "average")]
         #[inline]
+        // Postcondition: the result is the floor of the average, which lies between both inputs
+        #[ensures(|result| *result == (self >> 1) + (rhs >> 1) + (self & rhs & 1)
+            && *result >= if self < rhs { self } else { rhs }
+            && *result <= if self < rhs { rhs } else { self })]
         pub const fn midpoint(self, rhs: $SelfT) -> $SelfT {
             ((self as $WideT + rhs as $WideT) / 2) as $SelfT
         }
@@ -241,6 +255,12 @@ macro_rules! midpoint_impl {
         #[doc(alias = "average_ceil")]
         #[doc(alias = "average")]
         #[inline]
+        // Postcondition: the result is the exact average rounded towards zero: the floor of the
+        // average, plus one when the sum is negative and odd
+        #[ensures(|result| {
+            let floor = (self >> 1) + (rhs >> 1) + (self & rhs & 1);
+            *result == floor + if floor < 0 && (self ^ rhs) & 1 != 0 { 1 } else { 0 }
+        })]
         pub const fn midpoint(self, rhs: $SelfT) -> $SelfT {
             ((self as $WideT + rhs as $WideT) / 2) as $SelfT
         }
@@ -1363,6 +1383,11 @@ pub enum FpCategory {
 #[doc(hidden)]
 #[inline(always)]
 #[unstable(issue = "none", feature = "std_internals")]
+// Postcondition: if the fast path is taken, every number with `digits.len()` digits in `radix`
+// is below `2^(len * ceil(log2(radix)))`, which fits in the value bits of `T`
+#[ensures(|result| !*result
+    || digits.len() * radix.next_power_of_two().ilog2() as usize
+        <= size_of::<T>() * 8 - is_signed_ty as usize)]
 pub const fn can_not_overflow<T>(radix: u32, is_signed_ty: bool, digits: &[u8]) -> bool {
     radix <= 16 && digits.len() <= size_of::<T>() * 2 - is_signed_ty as usize
 }
@@ -1637,6 +1662,7 @@ from_str_int_impl! { unsigned usize u8 u16 u32 u64 u128 }
 #[unstable(feature = "kani", issue = "none")]
 mod verify {
     use super::*;
+    use crate::cmp::Ordering;
 
     // Verify `unchecked_{add, sub, mul}`
     macro_rules! generate_unchecked_math_harness {
@@ -1721,6 +1747,138 @@ mod verify {
         }
     }
 
+    // Verify `midpoint` against the exact average computed in a wider type
+    macro_rules! generate_midpoint_harness {
+        ($type:ty, $wide_type:ty, $harness_name:ident) => {
+            #[kani::proof_for_contract($type::midpoint)]
+            pub fn $harness_name() {
+                let a: $type = kani::any();
+                let b: $type = kani::any();
+                let result = a.midpoint(b);
+                assert_eq!(result as $wide_type, (a as $wide_type + b as $wide_type) / 2);
+            }
+        };
+    }
+
+    // There is no wider type for 128-bit integers, so we only check the contract.
+    macro_rules! generate_midpoint_contract_harness {
+        ($type:ty, $harness_name:ident) => {
+            #[kani::proof_for_contract($type::midpoint)]
+            pub fn $harness_name() {
+                let a: $type = kani::any();
+                let b: $type = kani::any();
+                a.midpoint(b);
+            }
+        };
+    }
+
+    // Verify `isqrt` and `checked_ilog{,2,10}` of unsigned integers against their contracts.
+    // `ilog`, `ilog2` and `ilog10` are thin wrappers around the checked variants.
+    macro_rules! generate_isqrt_ilog_harnesses {
+        ($type:ty, $unwind:literal, $isqrt:ident, $ilog:ident, $ilog2:ident, $ilog10:ident) => {
+            #[kani::proof_for_contract($type::isqrt)]
+            pub fn $isqrt() {
+                let x: $type = kani::any();
+                x.isqrt();
+            }
+
+            #[kani::proof_for_contract($type::checked_ilog)]
+            #[kani::unwind($unwind)]
+            pub fn $ilog() {
+                let x: $type = kani::any();
+                let base: $type = kani::any();
+                x.checked_ilog(base);
+            }
+
+            #[kani::proof_for_contract($type::checked_ilog2)]
+            pub fn $ilog2() {
+                let x: $type = kani::any();
+                x.checked_ilog2();
+            }
+
+            #[kani::proof_for_contract($type::checked_ilog10)]
+            #[kani::unwind($unwind)]
+            pub fn $ilog10() {
+                let x: $type = kani::any();
+                x.checked_ilog10();
+            }
+        };
+    }
+
+    // Verify `next_power_of_two`, `checked_next_power_of_two` and the helper they share
+    macro_rules! generate_next_power_of_two_harnesses {
+        ($type:ty, $helper:ident, $next:ident, $checked:ident, $overflow:ident) => {
+            #[kani::proof_for_contract($type::one_less_than_next_power_of_two)]
+            pub fn $helper() {
+                let x: $type = kani::any();
+                x.one_less_than_next_power_of_two();
+            }
+
+            #[kani::proof_for_contract($type::next_power_of_two)]
+            pub fn $next() {
+                let x: $type = kani::any();
+                kani::assume(x <= 1 << (<$type>::BITS - 1));
+                x.next_power_of_two();
+            }
+
+            #[kani::proof_for_contract($type::checked_next_power_of_two)]
+            pub fn $checked() {
+                let x: $type = kani::any();
+                x.checked_next_power_of_two();
+            }
+
+            // Above the largest power of two the addition overflows, which panics
+            // with overflow checks enabled.
+            #[kani::proof]
+            #[kani::should_panic]
+            pub fn $overflow() {
+                let x: $type = kani::any();
+                kani::assume(x > 1 << (<$type>::BITS - 1));
+                x.next_power_of_two();
+            }
+        };
+    }
+
+    // Verify `div_euclid` and `rem_euclid`, and that both panic on a zero divisor and on
+    // `MIN / -1`
+    macro_rules! generate_euclid_harnesses {
+        ($type:ty, $div:ident, $rem:ident, $div_panic:ident, $rem_panic:ident) => {
+            #[kani::proof_for_contract($type::div_euclid)]
+            pub fn $div() {
+                let x: $type = kani::any();
+                let rhs: $type = kani::any();
+                kani::assume(rhs != 0 && !(x == <$type>::MIN && rhs == -1));
+                x.div_euclid(rhs);
+            }
+
+            #[kani::proof_for_contract($type::rem_euclid)]
+            pub fn $rem() {
+                let x: $type = kani::any();
+                let rhs: $type = kani::any();
+                kani::assume(rhs != 0 && !(x == <$type>::MIN && rhs == -1));
+                x.rem_euclid(rhs);
+            }
+
+            #[kani::proof]
+            #[kani::should_panic]
+            pub fn $div_panic() {
+                let x: $type = kani::any();
+                let rhs: $type = kani::any();
+                kani::assume(rhs == 0 || (x == <$type>::MIN && rhs == -1));
+                x.div_euclid(rhs);
+            }
+
+            #[kani::proof]
+            #[kani::should_panic]
+            pub fn $rem_panic() {
+                let x: $type = kani::any();
+                let rhs: $type = kani::any();
+                kani::assume(rhs == 0 || (x == <$type>::MIN && rhs == -1));
+                x.rem_euclid(rhs);
+            }
+        };
+    }
+
     // Part 2 : Nested unsafe functions Generation Macros --> https://github.com/verify-rust-std/blob/main/doc/src/challenges/0011-floats-ints.md
 
     // Verify `widening_mul`, which internally uses `unchecked_mul`
@@ -1763,6 +1921,139 @@ mod verify {
         };
     }
 
+    // Verify the `to_bits`/`from_bits` round trip and that `total_cmp` is a total order
+    macro_rules! generate_float_bits_harnesses {
+        ($floatType:ty, $bitsType:ty, $to_bits:ident, $from_bits:ident, $total_cmp:ident) => {
+            #[kani::proof_for_contract($floatType::to_bits)]
+            pub fn $to_bits() {
+                let x: $floatType = kani::any();
+                x.to_bits();
+            }
+
+            #[kani::proof_for_contract($floatType::from_bits)]
+            pub fn $from_bits() {
+                let v: $bitsType = kani::any();
+                <$floatType>::from_bits(v);
+            }
+
+            #[kani::proof]
+            pub fn $total_cmp() {
+                let a: $floatType = kani::any();
+                let b: $floatType = kani::any();
+                let c: $floatType = kani::any();
+                let ab = a.total_cmp(&b);
+
+                // Antisymmetry, and only identical bit patterns compare equal.
+                assert_eq!(ab, b.total_cmp(&a).reverse());
+                assert_eq!(ab == Ordering::Equal, a.to_bits() == b.to_bits());
+
+                // Transitivity.
+                if ab.is_le() && b.total_cmp(&c).is_le() {
+                    assert!(a.total_cmp(&c).is_le());
+                }
+
+                // Consistency with `PartialOrd`, except that `-0.0` is ordered before `+0.0`.
+                if !a.is_nan() && !b.is_nan() {
+                    match a.partial_cmp(&b) {
+                        Some(Ordering::Equal) => assert!(ab.is_eq() || a == 0.0),
+                        Some(ord) => assert_eq!(ab, ord),
+                        None => unreachable!(),
+                    }
+                }
+            }
+        };
+    }
+
+    // Verify `clamp`, and that it panics when the bounds are out of order or NaN
+    macro_rules! generate_float_clamp_harnesses {
+        ($floatType:ty, $clamp:ident, $clamp_panic:ident) => {
+            #[kani::proof_for_contract($floatType::clamp)]
+            pub fn $clamp() {
+                let x: $floatType = kani::any();
+                let min: $floatType = kani::any();
+                let max: $floatType = kani::any();
+                kani::assume(min <= max);
+                x.clamp(min, max);
+            }
+
+            #[kani::proof]
+            #[kani::should_panic]
+            pub fn $clamp_panic() {
+                let x: $floatType = kani::any();
+                let min: $floatType = kani::any();
+                let max: $floatType = kani::any();
+                kani::assume(!(min <= max));
+                x.clamp(min, max);
+            }
+        };
+    }
+
+    // Verify that `from_str_radix` parses every string of at most `MAX_DIGITS` digits (with an
+    // optional sign) to the value those digits denote, or reports an overflow when that value does
+    // not fit, and that `from_ascii_radix` never panics on arbitrary bytes with a valid radix.
+    macro_rules! generate_from_str_radix_harnesses {
+        ($type:ty, $overflow_harness:ident, $round_trip_harness:ident, $no_panic_harness:ident) => {
+            #[kani::proof_for_contract(can_not_overflow::<$type>)]
+            pub fn $overflow_harness() {
+                const MAX_DIGITS: usize = 40;
+                let arr: [u8; MAX_DIGITS] = kani::any();
+                let digits = kani::slice::any_slice_of_array(&arr);
+                #[allow(unused_comparisons)]
+                let is_signed_ty = 0 > <$type>::MIN;
+                can_not_overflow::<$type>(kani::any(), is_signed_ty, digits);
+            }
+
+            #[kani::proof]
+            #[kani::unwind(6)]
+            pub fn $round_trip_harness() {
+                const MAX_DIGITS: usize = 4;
+                let radix: u32 = kani::any_where(|r| (2..=36).contains(r));
+                let len: usize = kani::any_where(|l| (1..=MAX_DIGITS).contains(l));
+                #[allow(unused_comparisons)]
+                let negative = 0 > <$type>::MIN && kani::any();
+
+                let mut buf = [0u8; MAX_DIGITS + 1];
+                let mut pos = 0;
+                if negative {
+                    buf[0] = b'-';
+                    pos = 1;
+                }
+                // At most 36^4 in magnitude, so the reference value cannot overflow.
+                let mut expected: i128 = 0;
+                for _ in 0..len {
+                    let digit: u32 = kani::any_where(|d| *d < radix);
+                    let c = char::from_digit(digit, radix).unwrap() as u8;
+                    buf[pos] = if kani::any() { c.to_ascii_uppercase() } else { c };
+                    pos += 1;
+                    expected = expected * radix as i128 + digit as i128;
+                }
+                if negative {
+                    expected = -expected;
+                }
+
+                // SAFETY: the buffer only holds ASCII signs and digits.
+                let src = unsafe { crate::str::from_utf8_unchecked(&buf[..pos]) };
+                let parsed = <$type>::from_str_radix(src, radix);
+                match <$type>::try_from(expected) {
+                    Ok(value) => assert_eq!(parsed, Ok(value)),
+                    Err(_) => assert!(matches!(
+                        parsed.map_err(|e| e.kind().clone()),
+                        Err(IntErrorKind::PosOverflow | IntErrorKind::NegOverflow)
+                    )),
+                }
+            }
+
+            #[kani::proof]
+            #[kani::unwind(6)]
+            pub fn $no_panic_harness() {
+                let arr: [u8; 5] = kani::any();
+                let src = kani::slice::any_slice_of_array(&arr);
+                let radix: u32 = kani::any_where(|r| (2..=36).contains(r));
+                let _ = <$type>::from_ascii_radix(src, radix);
+            }
+        };
+    }
+
     // Part 3: Float to Integer Conversion function Harness Generation Macro
     macro_rules! generate_to_int_unchecked_harness {
         ($floatType:ty, $($intType:ty, $harness_name:ident),+) => {
@@ -1935,6 +2226,185 @@ mod verify {
         usize::MAX
     );
 
+    // `midpoint` proofs
+    generate_midpoint_harness!(i8, i128, checked_midpoint_i8);
+    generate_midpoint_harness!(i16, i128, checked_midpoint_i16);
+    generate_midpoint_harness!(i32, i128, checked_midpoint_i32);
+    generate_midpoint_harness!(i64, i128, checked_midpoint_i64);
+    generate_midpoint_harness!(isize, i128, checked_midpoint_isize);
+    generate_midpoint_contract_harness!(i128, checked_midpoint_i128);
+    generate_midpoint_harness!(u8, u128, checked_midpoint_u8);
+    generate_midpoint_harness!(u16, u128, checked_midpoint_u16);
+    generate_midpoint_harness!(u32, u128, checked_midpoint_u32);
+    generate_midpoint_harness!(u64, u128, checked_midpoint_u64);
+    generate_midpoint_harness!(usize, u128, checked_midpoint_usize);
+    generate_midpoint_contract_harness!(u128, checked_midpoint_u128);
+
+    // Corner cases of the signed adjustment, where the sum is odd and negative.
+    #[kani::proof]
+    pub fn checked_midpoint_signed_corner_cases() {
+        assert_eq!((-7i8).midpoint(0), -3);
+        assert_eq!((-1i8).midpoint(0), 0);
+        assert_eq!(i8::MIN.midpoint(i8::MAX), 0);
+        assert_eq!(i8::MIN.midpoint(i8::MIN + 1), -127);
+        assert_eq!(i64::MIN.midpoint(i64::MAX), 0);
+        assert_eq!(i64::MIN.midpoint(-1), -(1 << 62));
+        assert_eq!(i128::MIN.midpoint(i128::MAX), 0);
+        assert_eq!(i128::MIN.midpoint(i128::MIN), i128::MIN);
+        assert_eq!(i128::MAX.midpoint(i128::MAX), i128::MAX);
+        assert_eq!((-3i128).midpoint(0), -1);
+        assert_eq!(u128::MAX.midpoint(u128::MAX - 1), u128::MAX - 1);
+    }
+
+    // `isqrt`, `checked_ilog`, `checked_ilog2` and `checked_ilog10` proofs
+    generate_isqrt_ilog_harnesses!(
+        u8,
+        10,
+        checked_isqrt_u8,
+        checked_ilog_u8,
+        checked_ilog2_u8,
+        checked_ilog10_u8
+    );
+    generate_isqrt_ilog_harnesses!(
+        u16,
+        18,
+        checked_isqrt_u16,
+        checked_ilog_u16,
+        checked_ilog2_u16,
+        checked_ilog10_u16
+    );
+    generate_isqrt_ilog_harnesses!(
+        u32,
+        34,
+        checked_isqrt_u32,
+        checked_ilog_u32,
+        checked_ilog2_u32,
+        checked_ilog10_u32
+    );
+    generate_isqrt_ilog_harnesses!(
+        u64,
+        66,
+        checked_isqrt_u64,
+        checked_ilog_u64,
+        checked_ilog2_u64,
+        checked_ilog10_u64
+    );
+    generate_isqrt_ilog_harnesses!(
+        u128,
+        130,
+        checked_isqrt_u128,
+        checked_ilog_u128,
+        checked_ilog2_u128,
+        checked_ilog10_u128
+    );
+    generate_isqrt_ilog_harnesses!(
+        usize,
+        66,
+        checked_isqrt_usize,
+        checked_ilog_usize,
+        checked_ilog2_usize,
+        checked_ilog10_usize
+    );
+
+    // `ilog` panics on a zero argument or a base below 2, independently of the checked variant.
+    #[kani::proof]
+    #[kani::should_panic]
+    pub fn check_ilog_invalid_argument_panics() {
+        let x: u32 = kani::any();
+        let base: u32 = kani::any();
+        kani::assume(x == 0 || base < 2);
+        x.ilog(base);
+    }
+
+    // `next_power_of_two`, `checked_next_power_of_two` and `one_less_than_next_power_of_two`
+    // proofs
+    generate_next_power_of_two_harnesses!(
+        u8,
+        check_one_less_than_next_power_of_two_u8,
+        check_next_power_of_two_u8,
+        check_checked_next_power_of_two_u8,
+        check_next_power_of_two_overflow_u8
+    );
+    generate_next_power_of_two_harnesses!(
+        u16,
+        check_one_less_than_next_power_of_two_u16,
+        check_next_power_of_two_u16,
+        check_checked_next_power_of_two_u16,
+        check_next_power_of_two_overflow_u16
+    );
+    generate_next_power_of_two_harnesses!(
+        u32,
+        check_one_less_than_next_power_of_two_u32,
+        check_next_power_of_two_u32,
+        check_checked_next_power_of_two_u32,
+        check_next_power_of_two_overflow_u32
+    );
+    generate_next_power_of_two_harnesses!(
+        u64,
+        check_one_less_than_next_power_of_two_u64,
+        check_next_power_of_two_u64,
+        check_checked_next_power_of_two_u64,
+        check_next_power_of_two_overflow_u64
+    );
+    generate_next_power_of_two_harnesses!(
+        u128,
+        check_one_less_than_next_power_of_two_u128,
+        check_next_power_of_two_u128,
+        check_checked_next_power_of_two_u128,
+        check_next_power_of_two_overflow_u128
+    );
+    generate_next_power_of_two_harnesses!(
+        usize,
+        check_one_less_than_next_power_of_two_usize,
+        check_next_power_of_two_usize,
+        check_checked_next_power_of_two_usize,
+        check_next_power_of_two_overflow_usize
+    );
+
+    // `div_euclid` and `rem_euclid` proofs
+    generate_euclid_harnesses!(
+        i8,
+        check_div_euclid_i8,
+        check_rem_euclid_i8,
+        check_div_euclid_panics_i8,
+        check_rem_euclid_panics_i8
+    );
+    generate_euclid_harnesses!(
+        i16,
+        check_div_euclid_i16,
+        check_rem_euclid_i16,
+        check_div_euclid_panics_i16,
+        check_rem_euclid_panics_i16
+    );
+    generate_euclid_harnesses!(
+        i32,
+        check_div_euclid_i32,
+        check_rem_euclid_i32,
+        check_div_euclid_panics_i32,
+        check_rem_euclid_panics_i32
+    );
+    generate_euclid_harnesses!(
+        i64,
+        check_div_euclid_i64,
+        check_rem_euclid_i64,
+        check_div_euclid_panics_i64,
+        check_rem_euclid_panics_i64
+    );
+    generate_euclid_harnesses!(
+        i128,
+        check_div_euclid_i128,
+        check_rem_euclid_i128,
+        check_div_euclid_panics_i128,
+        check_rem_euclid_panics_i128
+    );
+    generate_euclid_harnesses!(
+        isize,
+        check_div_euclid_isize,
+        check_rem_euclid_isize,
+        check_div_euclid_panics_isize,
+        check_rem_euclid_panics_isize
+    );
+
     // Part_2 `carrying_mul` proofs
     //
     // ====================== u8 Harnesses ======================
@@ -2149,4 +2619,310 @@ mod verify {
         usize,
         checked_f128_to_int_unchecked_usize
     );
+
+    // `to_bits`, `from_bits` and `total_cmp` proofs
+    generate_float_bits_harnesses!(
+        f32,
+        u32,
+        checked_f32_to_bits,
+        checked_f32_from_bits,
+        check_f32_total_cmp_total_order
+    );
+    generate_float_bits_harnesses!(
+        f64,
+        u64,
+        checked_f64_to_bits,
+        checked_f64_from_bits,
+        check_f64_total_cmp_total_order
+    );
+
+    // `clamp` proofs
+    generate_float_clamp_harnesses!(f32, checked_f32_clamp, check_f32_clamp_invalid_bounds);
+    generate_float_clamp_harnesses!(f64, checked_f64_clamp, check_f64_clamp_invalid_bounds);
+
+    // `from_str_radix` proofs
+    generate_from_str_radix_harnesses!(
+        i8,
+        check_can_not_overflow_i8,
+        check_from_str_radix_round_trip_i8,
+        check_from_ascii_radix_no_panic_i8
+    );
+    generate_from_str_radix_harnesses!(
+        i16,
+        check_can_not_overflow_i16,
+        check_from_str_radix_round_trip_i16,
+        check_from_ascii_radix_no_panic_i16
+    );
+    generate_from_str_radix_harnesses!(
+        i32,
+        check_can_not_overflow_i32,
+        check_from_str_radix_round_trip_i32,
+        check_from_ascii_radix_no_panic_i32
+    );
+    generate_from_str_radix_harnesses!(
+        i64,
+        check_can_not_overflow_i64,
+        check_from_str_radix_round_trip_i64,
+        check_from_ascii_radix_no_panic_i64
+    );
+    generate_from_str_radix_harnesses!(
+        i128,
+        check_can_not_overflow_i128,
+        check_from_str_radix_round_trip_i128,
+        check_from_ascii_radix_no_panic_i128
+    );
+    generate_from_str_radix_harnesses!(
+        isize,
+        check_can_not_overflow_isize,
+        check_from_str_radix_round_trip_isize,
+        check_from_ascii_radix_no_panic_isize
+    );
+    generate_from_str_radix_harnesses!(
+        u8,
+        check_can_not_overflow_u8,
+        check_from_str_radix_round_trip_u8,
+        check_from_ascii_radix_no_panic_u8
+    );
+    generate_from_str_radix_harnesses!(
+        u16,
+        check_can_not_overflow_u16,
+        check_from_str_radix_round_trip_u16,
+        check_from_ascii_radix_no_panic_u16
+    );
+    generate_from_str_radix_harnesses!(
+        u32,
+        check_can_not_overflow_u32,
+        check_from_str_radix_round_trip_u32,
+        check_from_ascii_radix_no_panic_u32
+    );
+    generate_from_str_radix_harnesses!(
+        u64,
+        check_can_not_overflow_u64,
+        check_from_str_radix_round_trip_u64,
+        check_from_ascii_radix_no_panic_u64
+    );
+    generate_from_str_radix_harnesses!(
+        u128,
+        check_can_not_overflow_u128,
+        check_from_str_radix_round_trip_u128,
+        check_from_ascii_radix_no_panic_u128
+    );
+    generate_from_str_radix_harnesses!(
+        usize,
+        check_can_not_overflow_usize,
+        check_from_str_radix_round_trip_usize,
+        check_from_ascii_radix_no_panic_usize
+    );
+
+    // `pow` fast path proofs
+    // `pow` picks between two exponentiation loops on `is_val_statically_known(exp)`, so both
+    // loops must agree wherever `pow` does not overflow. Past `exp == BITS` every base other than
+    // 0, 1 and -1 overflows, so the domain stops there to keep the reference loop bounded.
+    // `wrapping_pow` goes through the same switch, but its loops carry loop invariants that
+    // abstract the loop state, so equality of results cannot be observed through it.
+    macro_rules! generate_pow_branches_harness {
+        ($($mod_name:ident: $type:ty, $unwind:literal);+ $(;)?) => {$(
+            mod $mod_name {
+                use super::*;
+                use crate::intrinsics::check_statically_known_branches;
+
+                fn fits(base: $type, exp: u32) -> bool {
+                    let mut acc: $type = 1;
+                    for _ in 0..exp {
+                        match acc.checked_mul(base) {
+                            Some(next) => acc = next,
+                            None => return false,
+                        }
+                    }
+                    true
+                }
+
+                check_statically_known_branches!(
+                    #[kani::unwind($unwind)]
+                    check_pow_branches,
+                    <$type>::pow,
+                    { base: $type = kani::any(), exp: u32 = kani::any() }
+                        where exp <= <$type>::BITS && fits(base, exp)
+                );
+            }
+        )+};
+    }
+
+    generate_pow_branches_harness! {
+        pow_branches_u8: u8, 10;
+        pow_branches_i8: i8, 10;
+        pow_branches_u16: u16, 18;
+        pow_branches_i16: i16, 18;
+        pow_branches_u32: u32, 34;
+        pow_branches_i32: i32, 34;
+        pow_branches_u64: u64, 66;
+        pow_branches_i64: i64, 66;
+    }
+
+    // `pow`, `checked_pow` and `overflowing_pow` proofs
+    macro_rules! generate_pow_harnesses {
+        ($($mod_name:ident: $type:ty, $unwind:literal);+ $(;)?) => {$(
+            mod $mod_name {
+                use super::*;
+
+                #[kani::proof_for_contract($type::checked_pow)]
+                #[kani::unwind($unwind)]
+                fn check_checked_pow() {
+                    let base: $type = kani::any();
+                    let _ = base.checked_pow(kani::any());
+                }
+
+                #[kani::proof_for_contract($type::overflowing_pow)]
+                #[kani::unwind($unwind)]
+                fn check_overflowing_pow() {
+                    let base: $type = kani::any();
+                    let _ = base.overflowing_pow(kani::any());
+                }
+
+                #[kani::proof_for_contract($type::pow)]
+                #[kani::unwind($unwind)]
+                fn check_pow() {
+                    let base: $type = kani::any();
+                    let exp: u32 = kani::any();
+                    kani::assume(base.pow_reference(exp).is_some());
+                    let _ = base.pow(exp);
+                }
+
+                #[kani::proof]
+                #[kani::should_panic]
+                #[kani::unwind($unwind)]
+                fn check_pow_overflow() {
+                    let base: $type = kani::any();
+                    let exp: u32 = kani::any();
+                    kani::assume(base.pow_reference(exp).is_none());
+                    let _ = base.pow(exp);
+                }
+
+                #[kani::proof]
+                #[kani::unwind($unwind)]
+                fn check_overflowing_pow_wraps() {
+                    let base: $type = kani::any();
+                    let exp: u32 = kani::any_where(|exp| *exp <= 2 * <$type>::BITS);
+                    let mut wrapped: $type = 1;
+                    for _ in 0..exp {
+                        wrapped = wrapped.wrapping_mul(base);
+                    }
+                    assert_eq!(base.overflowing_pow(exp).0, wrapped);
+                }
+            }
+        )+};
+    }
+
+    generate_pow_harnesses! {
+        pow_u8: u8, 18;
+        pow_i8: i8, 18;
+        pow_u16: u16, 34;
+        pow_i16: i16, 34;
+        pow_u32: u32, 66;
+        pow_i32: i32, 66;
+        pow_u64: u64, 130;
+        pow_i64: i64, 130;
+    }
+
+    // `widening_mul`, `carrying_mul` and `carrying_mul_add` proofs
+    // Up to 64 bits the double-word result is checked against the next wider type. For 128 bits
+    // there is no wider type, and the manual double-word algorithm of the fallback is instead
+    // checked against builtin 128-bit arithmetic on the inputs where the exact result can be
+    // computed without it: factors that fit in 64 bits, and factors that are powers of two.
+    macro_rules! generate_widening_mul_harnesses {
+        ($($mod_name:ident: $type:ty, $unsigned:ty, $wide:ty);+ $(;)?) => {$(
+            mod $mod_name {
+                use super::*;
+
+                fn reference(a: $type, b: $type, c: $type, d: $type) -> ($unsigned, $type) {
+                    let wide = (a as $wide) * (b as $wide) + (c as $wide) + (d as $wide);
+                    (wide as $unsigned, (wide >> <$type>::BITS) as $type)
+                }
+
+                #[kani::proof]
+                fn check_carrying_mul_add() {
+                    let (a, b, c, d): ($type, $type, $type, $type) = kani::any();
+                    assert_eq!(a.carrying_mul_add(b, c, d), reference(a, b, c, d));
+                }
+
+                #[kani::proof]
+                fn check_widening_carrying_mul() {
+                    let (a, b, c): ($type, $type, $type) = kani::any();
+                    assert_eq!(a.widening_mul(b), reference(a, b, 0, 0));
+                    assert_eq!(a.carrying_mul(b, c), reference(a, b, c, 0));
+                }
+            }
+        )+};
+    }
+
+    generate_widening_mul_harnesses! {
+        widening_mul_u8: u8, u8, u16;
+        widening_mul_u16: u16, u16, u32;
+        widening_mul_u32: u32, u32, u64;
+        widening_mul_u64: u64, u64, u128;
+        widening_mul_i8: i8, u8, i16;
+        widening_mul_i16: i16, u16, i32;
+        widening_mul_i32: i32, u32, i64;
+        widening_mul_i64: i64, u64, i128;
+    }
+
+    // The low word is the wrapping result for every input.
+    #[kani::proof]
+    fn check_carrying_mul_add_u128_low() {
+        let (a, b, c, d): (u128, u128, u128, u128) = kani::any();
+        let (low, _) = a.carrying_mul_add(b, c, d);
+        assert_eq!(low, a.wrapping_mul(b).wrapping_add(c).wrapping_add(d));
+        assert_eq!(a.widening_mul(b), b.widening_mul(a));
+    }
+
+    #[kani::proof]
+    fn check_carrying_mul_add_u128_narrow_factors() {
+        let a: u128 = kani::any::<u64>() as u128;
+        let b: u128 = kani::any::<u64>() as u128;
+        let (c, d): (u128, u128) = kani::any();
+        let (low, carry1) = (a * b).overflowing_add(c);
+        let (low, carry2) = low.overflowing_add(d);
+        assert_eq!(a.carrying_mul_add(b, c, d), (low, carry1 as u128 + carry2 as u128));
+        let (low, carry) = (a * b).overflowing_add(c);
+        assert_eq!(a.carrying_mul(b, c), (low, carry as u128));
+    }
+
+    #[kani::proof]
+    fn check_widening_mul_u128_power_of_two() {
+        let a: u128 = kani::any();
+        let shift: u32 = kani::any_where(|shift| *shift < u128::BITS);
+        let high = if shift == 0 { 0 } else { a >> (u128::BITS - shift) };
+        assert_eq!(a.widening_mul(1 << shift), (a << shift, high));
+    }
+
+    #[kani::proof]
+    fn check_carrying_mul_add_i128_low() {
+        let (a, b, c, d): (i128, i128, i128, i128) = kani::any();
+        let (low, _) = a.carrying_mul_add(b, c, d);
+        assert_eq!(low, a.wrapping_mul(b).wrapping_add(c).wrapping_add(d) as u128);
+        assert_eq!(a.widening_mul(b), b.widening_mul(a));
+    }
+
+    #[kani::proof]
+    fn check_carrying_mul_add_i128_narrow_factors() {
+        // Adds a sign-extended `i128` to a double word.
+        fn add(low: u128, high: i128, x: i128) -> (u128, i128) {
+            let (low, carry) = low.overflowing_add(x as u128);
+            (low, high + (x >> 127) + carry as i128)
+        }
+        let a: i128 = kani::any::<i64>() as i128;
+        let b: i128 = kani::any::<i64>() as i128;
+        let (c, d): (i128, i128) = kani::any();
+        let product = a * b;
+        let (low, high) = add(product as u128, product >> 127, c);
+        assert_eq!(a.carrying_mul_add(b, c, d), add(low, high, d));
+    }
+
+    #[kani::proof]
+    fn check_widening_mul_i128_power_of_two() {
+        let a: i128 = kani::any();
+        let shift: u32 = kani::any_where(|shift| *shift < i128::BITS - 1);
+        let high = if shift == 0 { a >> 127 } else { a >> (i128::BITS - shift) };
+        assert_eq!(a.widening_mul(1 << shift), ((a << shift) as u128, high));
+    }
 }
@@ -1106,7 +1106,8 @@ macro_rules! nonzero_integer {
                 self.get().checked_mul(other.get()).is_some()
             })]
             #[ensures(|result: &Self| {
-                self.get().checked_mul(other.get()).is_some_and(|product| product == result.get())
+                result.get() != 0
+                    && self.get().checked_mul(other.get()).is_some_and(|product| product == result.get())
             })]
             pub const unsafe fn unchecked_mul(self, other: Self) -> Self {
                 // SAFETY: The caller ensures there is no overflow.
@@ -1514,8 +1515,8 @@ macro_rules! nonzero_integer_signedness_dependent_methods {
             self.get().checked_add(other).is_some()
         })]
         #[ensures(|result: &Self| {
-            // Postcondition: the result matches the expected addition
-            self.get().checked_add(other).is_some_and(|sum| sum == result.get())
+            // Postcondition: the result is non-zero and matches the expected addition
+            result.get() != 0 && self.get().checked_add(other).is_some_and(|sum| sum == result.get())
         })]
         pub const unsafe fn unchecked_add(self, other: $Int) -> Self {
             // SAFETY: The caller ensures there is no overflow.
@@ -2947,4 +2948,94 @@ mod verify {
     nonzero_check_add!(u64, core::num::NonZeroU64, nonzero_check_unchecked_add_for_u64);
     nonzero_check_add!(u128, core::num::NonZeroU128, nonzero_check_unchecked_add_for_u128);
     nonzero_check_add!(usize, core::num::NonZeroUsize, nonzero_check_unchecked_add_for_usize);
+
+    // The following harnesses violate the preconditions, and are expected to fail.
+    macro_rules! nonzero_check_new_unchecked_zero {
+        ($t:ty, $nonzero_type:ty, $harness_name:ident) => {
+            #[kani::proof]
+            #[kani::should_panic]
+            pub fn $harness_name() {
+                let _ = unsafe { <$nonzero_type>::new_unchecked(0 as $t) };
+            }
+        };
+    }
+
+    nonzero_check_new_unchecked_zero!(i8, NonZeroI8, nonzero_check_new_unchecked_zero_for_i8);
+    nonzero_check_new_unchecked_zero!(i16, NonZeroI16, nonzero_check_new_unchecked_zero_for_i16);
+    nonzero_check_new_unchecked_zero!(i32, NonZeroI32, nonzero_check_new_unchecked_zero_for_i32);
+    nonzero_check_new_unchecked_zero!(i64, NonZeroI64, nonzero_check_new_unchecked_zero_for_i64);
+    nonzero_check_new_unchecked_zero!(i128, NonZeroI128, nonzero_check_new_unchecked_zero_for_i128);
+    nonzero_check_new_unchecked_zero!(
+        isize,
+        NonZeroIsize,
+        nonzero_check_new_unchecked_zero_for_isize
+    );
+    nonzero_check_new_unchecked_zero!(u8, NonZeroU8, nonzero_check_new_unchecked_zero_for_u8);
+    nonzero_check_new_unchecked_zero!(u16, NonZeroU16, nonzero_check_new_unchecked_zero_for_u16);
+    nonzero_check_new_unchecked_zero!(u32, NonZeroU32, nonzero_check_new_unchecked_zero_for_u32);
+    nonzero_check_new_unchecked_zero!(u64, NonZeroU64, nonzero_check_new_unchecked_zero_for_u64);
+    nonzero_check_new_unchecked_zero!(u128, NonZeroU128, nonzero_check_new_unchecked_zero_for_u128);
+    nonzero_check_new_unchecked_zero!(
+        usize,
+        NonZeroUsize,
+        nonzero_check_new_unchecked_zero_for_usize
+    );
+
+    macro_rules! nonzero_check_add_overflow {
+        ($t:ty, $nonzero_type:ty, $harness_name:ident) => {
+            #[kani::proof]
+            #[kani::should_panic]
+            pub fn $harness_name() {
+                let x: $nonzero_type = kani::any();
+                let y: $t = kani::any();
+                kani::assume(x.get().checked_add(y).is_none());
+                let _ = unsafe { x.unchecked_add(y) };
+            }
+        };
+    }
+
+    nonzero_check_add_overflow!(u8, NonZeroU8, nonzero_check_unchecked_add_overflow_for_u8);
+    nonzero_check_add_overflow!(u16, NonZeroU16, nonzero_check_unchecked_add_overflow_for_u16);
+    nonzero_check_add_overflow!(u32, NonZeroU32, nonzero_check_unchecked_add_overflow_for_u32);
+    nonzero_check_add_overflow!(u64, NonZeroU64, nonzero_check_unchecked_add_overflow_for_u64);
+    nonzero_check_add_overflow!(u128, NonZeroU128, nonzero_check_unchecked_add_overflow_for_u128);
+    nonzero_check_add_overflow!(
+        usize,
+        NonZeroUsize,
+        nonzero_check_unchecked_add_overflow_for_usize
+    );
+
+    macro_rules! nonzero_check_mul_overflow {
+        ($t:ty, $nonzero_type:ty, $harness_name:ident) => {
+            #[kani::proof]
+            #[kani::should_panic]
+            pub fn $harness_name() {
+                let x: $nonzero_type = kani::any();
+                let y: $nonzero_type = kani::any();
+                kani::assume(x.get().checked_mul(y.get()).is_none());
+                let _ = unsafe { x.unchecked_mul(y) };
+            }
+        };
+    }
+
+    nonzero_check_mul_overflow!(i8, NonZeroI8, nonzero_check_unchecked_mul_overflow_for_i8);
+    nonzero_check_mul_overflow!(i16, NonZeroI16, nonzero_check_unchecked_mul_overflow_for_i16);
+    nonzero_check_mul_overflow!(i32, NonZeroI32, nonzero_check_unchecked_mul_overflow_for_i32);
+    nonzero_check_mul_overflow!(i64, NonZeroI64, nonzero_check_unchecked_mul_overflow_for_i64);
+    nonzero_check_mul_overflow!(i128, NonZeroI128, nonzero_check_unchecked_mul_overflow_for_i128);
+    nonzero_check_mul_overflow!(
+        isize,
+        NonZeroIsize,
+        nonzero_check_unchecked_mul_overflow_for_isize
+    );
+    nonzero_check_mul_overflow!(u8, NonZeroU8, nonzero_check_unchecked_mul_overflow_for_u8);
+    nonzero_check_mul_overflow!(u16, NonZeroU16, nonzero_check_unchecked_mul_overflow_for_u16);
+    nonzero_check_mul_overflow!(u32, NonZeroU32, nonzero_check_unchecked_mul_overflow_for_u32);
+    nonzero_check_mul_overflow!(u64, NonZeroU64, nonzero_check_unchecked_mul_overflow_for_u64);
+    nonzero_check_mul_overflow!(u128, NonZeroU128, nonzero_check_unchecked_mul_overflow_for_u128);
+    nonzero_check_mul_overflow!(
+        usize,
+        NonZeroUsize,
+        nonzero_check_unchecked_mul_overflow_for_usize
+    );
 }
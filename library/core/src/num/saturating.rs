@@ -1,5 +1,7 @@
 //! Definitions of `Saturating<T>`.
 
+use safety::ensures;
+
 use crate::fmt;
 #[cfg(kani)]
 use crate::kani;
@@ -223,6 +225,7 @@ macro_rules! saturating_impl {
             type Output = Saturating<$t>;
 
             #[inline]
+            #[ensures(|result: &Saturating<$t>| result.0 == self.0.saturating_add(other.0))]
             fn add(self, other: Saturating<$t>) -> Saturating<$t> {
                 Saturating(self.0.saturating_add(other.0))
             }
@@ -253,6 +256,7 @@ macro_rules! saturating_impl {
             type Output = Saturating<$t>;
 
             #[inline]
+            #[ensures(|result: &Saturating<$t>| result.0 == self.0.saturating_sub(other.0))]
             fn sub(self, other: Saturating<$t>) -> Saturating<$t> {
                 Saturating(self.0.saturating_sub(other.0))
             }
@@ -283,6 +287,7 @@ macro_rules! saturating_impl {
             type Output = Saturating<$t>;
 
             #[inline]
+            #[ensures(|result: &Saturating<$t>| result.0 == self.0.saturating_mul(other.0))]
             fn mul(self, other: Saturating<$t>) -> Saturating<$t> {
                 Saturating(self.0.saturating_mul(other.0))
             }
@@ -944,6 +949,7 @@ macro_rules! saturating_int_impl_signed {
         impl Neg for Saturating<$t> {
             type Output = Self;
             #[inline]
+            #[ensures(|result: &Self| result.0 == self.0.saturating_neg())]
             fn neg(self) -> Self {
                 Saturating(self.0.saturating_neg())
             }
@@ -1039,3 +1045,66 @@ saturating_int_impl_unsigned! { usize u8 u16 u32 u64 u128 }
 //     pub const u128: u32 = i128;
 //     pub use self::platform::usize;
 // }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // Operator impls of `Saturating<T>`
+    macro_rules! generate_saturating_op_harnesses {
+        ($($mod_name:ident: $t:ty),+ $(,)?) => {$(
+            mod $mod_name {
+                use super::*;
+
+                #[kani::proof_for_contract(<Saturating<$t> as Add>::add)]
+                fn check_add() {
+                    let _ = kani::any::<Saturating<$t>>() + kani::any::<Saturating<$t>>();
+                }
+
+                #[kani::proof_for_contract(<Saturating<$t> as Sub>::sub)]
+                fn check_sub() {
+                    let _ = kani::any::<Saturating<$t>>() - kani::any::<Saturating<$t>>();
+                }
+
+                #[kani::proof_for_contract(<Saturating<$t> as Mul>::mul)]
+                fn check_mul() {
+                    let _ = kani::any::<Saturating<$t>>() * kani::any::<Saturating<$t>>();
+                }
+            }
+        )+};
+    }
+
+    macro_rules! generate_saturating_neg_harnesses {
+        ($($harness:ident: $t:ty),+ $(,)?) => {$(
+            #[kani::proof_for_contract(<Saturating<$t> as Neg>::neg)]
+            fn $harness() {
+                let _ = -kani::any::<Saturating<$t>>();
+            }
+        )+};
+    }
+
+    generate_saturating_op_harnesses! {
+        saturating_u8: u8,
+        saturating_u16: u16,
+        saturating_u32: u32,
+        saturating_u64: u64,
+        saturating_u128: u128,
+        saturating_usize: usize,
+        saturating_i8: i8,
+        saturating_i16: i16,
+        saturating_i32: i32,
+        saturating_i64: i64,
+        saturating_i128: i128,
+        saturating_isize: isize,
+    }
+
+    generate_saturating_neg_harnesses! {
+        check_neg_i8: i8,
+        check_neg_i16: i16,
+        check_neg_i32: i32,
+        check_neg_i64: i64,
+        check_neg_i128: i128,
+        check_neg_isize: isize,
+    }
+}
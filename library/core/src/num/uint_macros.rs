@@ -1426,6 +1426,8 @@ macro_rules! uint_impl {
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
         #[inline]
+        #[ensures(|result| result.is_none() == (self == 0 || base < 2))]
+        #[cfg_attr(kani, ensures(|result| result.is_none_or(|log| self.is_ilog(base, log))))]
         pub const fn checked_ilog(self, base: Self) -> Option<u32> {
             if self <= 0 || base <= 1 {
                 None
@@ -1472,6 +1474,8 @@ macro_rules! uint_impl {
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
         #[inline]
+        #[ensures(|result| result.is_none() == (self == 0))]
+        #[ensures(|result| result.is_none_or(|log| self.checked_shr(log) == Some(1)))]
         pub const fn checked_ilog2(self) -> Option<u32> {
             match NonZero::new(self) {
                 Some(x) => Some(x.ilog2()),
@@ -1493,6 +1497,8 @@ macro_rules! uint_impl {
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
         #[inline]
+        #[ensures(|result| result.is_none() == (self == 0))]
+        #[cfg_attr(kani, ensures(|result| result.is_none_or(|log| self.is_ilog(10, log))))]
         pub const fn checked_ilog10(self) -> Option<u32> {
             match NonZero::new(self) {
                 Some(x) => Some(x.ilog10()),
@@ -1500,6 +1506,28 @@ macro_rules! uint_impl {
             }
         }
 
+        // Returns whether `log` is the integer logarithm of `self` with
+        // respect to `base`, i.e. whether `base^log <= self < base^(log + 1)`.
+        // The second bound is checked as `self / base < base^log` so that
+        // `base^(log + 1)` never has to be computed.
+        //
+        // The power is accumulated with a plain loop rather than with
+        // `checked_pow`, whose loop is abstracted by its (trivial) loop
+        // invariant when loop contracts are enabled.
+        #[cfg(kani)]
+        fn is_ilog(self, base: Self, log: u32) -> bool {
+            let mut power: Self = 1;
+            let mut i = 0;
+            while i < log {
+                power = match power.checked_mul(base) {
+                    Some(power) => power,
+                    None => return false,
+                };
+                i += 1;
+            }
+            power <= self && self / base < power
+        }
+
         /// Checked negation. Computes `-self`, returning `None` unless `self ==
         /// 0`.
         ///
@@ -1804,6 +1832,7 @@ macro_rules! uint_impl {
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
         #[inline]
+        #[cfg_attr(kani, ensures(|result| *result == self.pow_reference(exp)))]
         pub const fn checked_pow(self, mut exp: u32) -> Option<Self> {
             if exp == 0 {
                 return Some(1);
@@ -1811,7 +1840,9 @@ macro_rules! uint_impl {
             let mut base = self;
             let mut acc: Self = 1;
 
-            #[safety::loop_invariant(true)]
+            // `acc * base^exp` is the power being computed.
+            #[safety::loop_invariant(exp >= 1
+                && base.pow_reference(exp).and_then(|power| acc.checked_mul(power)) == self.pow_reference(on_entry(exp)))]
             loop {
                 if (exp & 1) == 1 {
                     acc = try_opt!(acc.checked_mul(base));
@@ -1825,6 +1856,29 @@ macro_rules! uint_impl {
             }
         }
 
+        // Returns `self^exp` computed by repeated multiplication, or `None` if
+        // it does not fit in `Self`. Reference for the exponentiation
+        // contracts, which must not depend on exponentiation by squaring.
+        //
+        // The bases 0 and 1 are handled up front, so the loop runs at
+        // most `BITS` times: any other base overflows before then.
+        #[cfg(kani)]
+        fn pow_reference(self, exp: u32) -> Option<Self> {
+            if self <= 1 {
+                return Some(if exp == 0 { 1 } else { self });
+            }
+            let mut power: Self = 1;
+            let mut i = 0;
+            while i < exp {
+                power = match power.checked_mul(self) {
+                    Some(power) => power,
+                    None => return None,
+                };
+                i += 1;
+            }
+            Some(power)
+        }
+
         /// Strict exponentiation. Computes `self.pow(exp)`, panicking if
         /// overflow occurred.
         ///
@@ -2338,7 +2392,7 @@ macro_rules! uint_impl {
             let mut base = self;
             let mut acc: Self = 1;
 
-            if intrinsics::is_val_statically_known(exp) {
+            if intrinsics::is_val_statically_known_or_any(exp) {
                 #[safety::loop_invariant(exp>=1)]
                 while exp > 1 {
                     if (exp & 1) == 1 {
@@ -2992,6 +3046,10 @@ macro_rules! uint_impl {
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
         #[inline]
+        #[cfg_attr(kani, ensures(|result| result.1 == self.pow_reference(exp).is_none()))]
+        #[cfg_attr(kani, ensures(|result| {
+            self.pow_reference(exp).is_none_or(|power| power == result.0)
+        }))]
         pub const fn overflowing_pow(self, mut exp: u32) -> (Self, bool) {
             if exp == 0{
                 return (1,false);
@@ -3002,7 +3060,11 @@ macro_rules! uint_impl {
             // Scratch space for storing results of overflowing_mul.
             let mut r;
 
-            #[safety::loop_invariant(true)]
+            // Until the first overflow, `acc * base^exp` is the power being
+            // computed; once it happened, the power does not fit.
+            #[safety::loop_invariant(exp >= 1
+                && (overflown || base.pow_reference(exp).and_then(|power| acc.checked_mul(power)) == self.pow_reference(on_entry(exp)))
+                && (!overflown || self.pow_reference(on_entry(exp)).is_none()))]
             loop {
                 if (exp & 1) == 1 {
                     r = acc.overflowing_mul(base);
@@ -3034,6 +3096,7 @@ macro_rules! uint_impl {
                       without modifying the original"]
         #[inline]
         #[rustc_inherit_overflow_checks]
+        #[cfg_attr(kani, ensures(|result| self.pow_reference(exp) == Some(*result)))]
         pub const fn pow(self, mut exp: u32) -> Self {
             if exp == 0 {
                 return 1;
@@ -3041,7 +3104,9 @@ macro_rules! uint_impl {
             let mut base = self;
             let mut acc = 1;
 
-            if intrinsics::is_val_statically_known(exp) {
+            if intrinsics::is_val_statically_known_or_any(exp) {
+                #[safety::loop_invariant(exp >= 1
+                    && base.pow_reference(exp).and_then(|power| acc.checked_mul(power)) == self.pow_reference(on_entry(exp)))]
                 while exp > 1 {
                     if (exp & 1) == 1 {
                         acc = acc * base;
@@ -3060,6 +3125,8 @@ macro_rules! uint_impl {
                 // at compile time. We can't use the same code for the constant
                 // exponent case because LLVM is currently unable to unroll
                 // this loop.
+                #[safety::loop_invariant(exp >= 1
+                    && base.pow_reference(exp).and_then(|power| acc.checked_mul(power)) == self.pow_reference(on_entry(exp)))]
                 loop {
                     if (exp & 1) == 1 {
                         acc = acc * base;
@@ -3086,6 +3153,8 @@ macro_rules! uint_impl {
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
         #[inline]
+        #[ensures(|result| result.checked_mul(*result).is_some_and(|sq| sq <= self))]
+        #[ensures(|result| (*result + 1).checked_mul(*result + 1).is_none_or(|sq| self < sq))]
         pub const fn isqrt(self) -> Self {
             let result = crate::num::int_sqrt::$ActualT(self as $ActualT) as $SelfT;
 
@@ -3318,6 +3387,11 @@ macro_rules! uint_impl {
         // overflow cases it instead ends up returning the maximum value
         // of the type, and can return 0 for 0.
         #[inline]
+        // Postcondition: the result is a mask of trailing ones, large enough to hold `self - 1`
+        // and minimal (dropping its top bit would make it too small), or 0 for 0 and 1
+        #[ensures(|result| *result & result.wrapping_add(1) == 0)]
+        #[ensures(|result| (*result == 0) == (self <= 1))]
+        #[ensures(|result| self <= 1 || (*result >= self - 1 && *result >> 1 < self - 1))]
         const fn one_less_than_next_power_of_two(self) -> Self {
             if self <= 1 { return 0; }
 
@@ -3349,6 +3423,8 @@ macro_rules! uint_impl {
                       without modifying the original"]
         #[inline]
         #[rustc_inherit_overflow_checks]
+        #[ensures(|result| result.is_power_of_two() && *result >= self)]
+        #[ensures(|result| *result == 1 || *result >> 1 < self)]
         pub const fn next_power_of_two(self) -> Self {
             self.one_less_than_next_power_of_two() + 1
         }
@@ -3369,6 +3445,9 @@ macro_rules! uint_impl {
         #[rustc_const_stable(feature = "const_int_pow", since = "1.50.0")]
         #[must_use = "this returns the result of the operation, \
                       without modifying the original"]
+        #[ensures(|result| result.is_none() == (self > 1 << (Self::BITS - 1)))]
+        #[ensures(|result| result.is_none_or(|p| p.is_power_of_two() && p >= self
+            && (p == 1 || p >> 1 < self)))]
         pub const fn checked_next_power_of_two(self) -> Option<Self> {
             self.one_less_than_next_power_of_two().checked_add(1)
         }
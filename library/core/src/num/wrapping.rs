@@ -1,5 +1,7 @@
 //! Definitions of `Wrapping<T>`.
 
+use safety::ensures;
+
 use crate::fmt;
 #[cfg(kani)]
 use crate::kani;
@@ -156,6 +158,7 @@ macro_rules! sh_impl_unsigned {
             type Output = Wrapping<$t>;
 
             #[inline]
+            #[ensures(|result: &Wrapping<$t>| result.0 == self.0.wrapping_shl(other as u32))]
             fn shl(self, other: $f) -> Wrapping<$t> {
                 Wrapping(self.0.wrapping_shl((other & self::shift_max::$t as $f) as u32))
             }
@@ -224,6 +227,7 @@ macro_rules! wrapping_impl {
             type Output = Wrapping<$t>;
 
             #[inline]
+            #[ensures(|result: &Wrapping<$t>| result.0 == self.0.wrapping_add(other.0))]
             fn add(self, other: Wrapping<$t>) -> Wrapping<$t> {
                 Wrapping(self.0.wrapping_add(other.0))
             }
@@ -254,6 +258,7 @@ macro_rules! wrapping_impl {
             type Output = Wrapping<$t>;
 
             #[inline]
+            #[ensures(|result: &Wrapping<$t>| result.0 == self.0.wrapping_sub(other.0))]
             fn sub(self, other: Wrapping<$t>) -> Wrapping<$t> {
                 Wrapping(self.0.wrapping_sub(other.0))
             }
@@ -284,6 +289,7 @@ macro_rules! wrapping_impl {
             type Output = Wrapping<$t>;
 
             #[inline]
+            #[ensures(|result: &Wrapping<$t>| result.0 == self.0.wrapping_mul(other.0))]
             fn mul(self, other: Wrapping<$t>) -> Wrapping<$t> {
                 Wrapping(self.0.wrapping_mul(other.0))
             }
@@ -475,6 +481,7 @@ macro_rules! wrapping_impl {
         impl Neg for Wrapping<$t> {
             type Output = Self;
             #[inline]
+            #[ensures(|result: &Self| result.0 == self.0.wrapping_neg())]
             fn neg(self) -> Self {
                 Wrapping(0) - self
             }
@@ -1098,3 +1105,58 @@ mod shift_max {
     pub(super) const u128: u32 = i128;
     pub(super) use self::platform::usize;
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // Operator impls of `Wrapping<T>`
+    macro_rules! generate_wrapping_op_harnesses {
+        ($($mod_name:ident: $t:ty),+ $(,)?) => {$(
+            mod $mod_name {
+                use super::*;
+
+                #[kani::proof_for_contract(<Wrapping<$t> as Add>::add)]
+                fn check_add() {
+                    let _ = kani::any::<Wrapping<$t>>() + kani::any::<Wrapping<$t>>();
+                }
+
+                #[kani::proof_for_contract(<Wrapping<$t> as Sub>::sub)]
+                fn check_sub() {
+                    let _ = kani::any::<Wrapping<$t>>() - kani::any::<Wrapping<$t>>();
+                }
+
+                #[kani::proof_for_contract(<Wrapping<$t> as Mul>::mul)]
+                fn check_mul() {
+                    let _ = kani::any::<Wrapping<$t>>() * kani::any::<Wrapping<$t>>();
+                }
+
+                #[kani::proof_for_contract(<Wrapping<$t> as Neg>::neg)]
+                fn check_neg() {
+                    let _ = -kani::any::<Wrapping<$t>>();
+                }
+
+                #[kani::proof_for_contract(<Wrapping<$t> as Shl<usize>>::shl)]
+                fn check_shl() {
+                    let _ = kani::any::<Wrapping<$t>>() << kani::any::<usize>();
+                }
+            }
+        )+};
+    }
+
+    generate_wrapping_op_harnesses! {
+        wrapping_u8: u8,
+        wrapping_u16: u16,
+        wrapping_u32: u32,
+        wrapping_u64: u64,
+        wrapping_u128: u128,
+        wrapping_usize: usize,
+        wrapping_i8: i8,
+        wrapping_i16: i16,
+        wrapping_i32: i32,
+        wrapping_i64: i64,
+        wrapping_i128: i128,
+        wrapping_isize: isize,
+    }
+}
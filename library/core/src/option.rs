@@ -576,10 +576,14 @@
 
 #![stable(feature = "rust1", since = "1.0.0")]
 
+use safety::ensures;
+
 use crate::iter::{self, FusedIterator, TrustedLen};
 use crate::ops::{self, ControlFlow, Deref, DerefMut};
 use crate::panicking::{panic, panic_display};
 use crate::pin::Pin;
+#[cfg(kani)]
+use crate::ptr;
 use crate::{cmp, convert, hint, mem, slice};
 
 /// The `Option` type. See [the module level documentation](self) for more.
@@ -797,6 +801,7 @@ impl<T> Option<T> {
     }
 
     #[inline]
+    #[ensures(|&len| len == self.is_some() as usize)]
     const fn len(&self) -> usize {
         // Using the intrinsic avoids emitting a branch to get the 0 or 1.
         let discriminant: isize = crate::intrinsics::discriminant_value(self);
@@ -831,6 +836,13 @@ impl<T> Option<T> {
     #[must_use]
     #[stable(feature = "option_as_slice", since = "1.75.0")]
     #[rustc_const_stable(feature = "const_option_ext", since = "1.84.0")]
+    // `Some.0` sits at the same aligned offset whatever the variant, so the slice start is
+    // aligned even for `None`, and for `Some` it is exactly the payload.
+    #[ensures(|slice: &&[T]| {
+        slice.len() == self.is_some() as usize
+            && slice.as_ptr().is_aligned()
+            && self.as_ref().is_none_or(|payload| ptr::eq(slice.as_ptr(), payload))
+    })]
     pub const fn as_slice(&self) -> &[T] {
         // SAFETY: When the `Option` is `Some`, we're using the actual pointer
         // to the payload, with a length of 1, so this is equivalent to
@@ -886,6 +898,11 @@ impl<T> Option<T> {
     #[must_use]
     #[stable(feature = "option_as_slice", since = "1.75.0")]
     #[rustc_const_stable(feature = "const_option_ext", since = "1.84.0")]
+    #[ensures(|slice: &&mut [T]| {
+        slice.len() == old(self.is_some() as usize)
+            && slice.as_ptr().is_aligned()
+            && old(self.as_ref().map(ptr::from_ref)).is_none_or(|payload| slice.as_ptr() == payload)
+    })]
     pub const fn as_mut_slice(&mut self) -> &mut [T] {
         // SAFETY: When the `Option` is `Some`, we're using the actual pointer
         // to the payload, with a length of 1, so this is equivalent to
@@ -2645,6 +2662,7 @@ impl<T, const N: usize> [Option<T>; N] {
 #[unstable(feature = "kani", issue = "none")]
 mod verify {
     use crate::kani;
+    use crate::num::NonZero;
     use crate::option::Option;
 
     #[kani::proof]
@@ -2666,4 +2684,71 @@ mod verify {
             assert!(empty_slice.is_empty()); // Explicit check for emptiness
         }
     }
+
+    // `as_slice` layout proofs
+
+    #[derive(Clone, Copy, PartialEq, kani::Arbitrary)]
+    #[repr(align(16))]
+    struct OverAligned(u8);
+
+    macro_rules! check_as_slice {
+        ($($mod_name:ident: $ty:ty),+ $(,)?) => {$(
+            mod $mod_name {
+                use super::*;
+
+                #[kani::proof_for_contract(Option::<$ty>::len)]
+                fn check_len() {
+                    let option: Option<$ty> = kani::any();
+                    let _ = option.len();
+                }
+
+                #[kani::proof_for_contract(Option::<$ty>::as_slice)]
+                fn check_as_slice() {
+                    let option: Option<$ty> = kani::any();
+                    let _ = option.as_slice();
+                }
+
+                #[kani::proof_for_contract(Option::<$ty>::as_mut_slice)]
+                fn check_as_mut_slice() {
+                    let mut option: Option<$ty> = kani::any();
+                    let _ = option.as_mut_slice();
+                }
+
+                #[kani::proof]
+                fn check_as_mut_slice_write() {
+                    let mut option: Option<$ty> = kani::any();
+                    let was_some = option.is_some();
+                    let value: $ty = kani::any();
+                    if let [payload] = option.as_mut_slice() {
+                        *payload = value;
+                    }
+                    assert!(option.is_some() == was_some);
+                    assert!(option.is_none() || option == Some(value));
+                }
+            }
+        )+};
+    }
+
+    check_as_slice! {
+        check_u8: u8,
+        check_u64: u64,
+        check_bool: bool,
+        check_char: char,
+        check_non_zero_u32: NonZero<u32>,
+        check_nested: Option<u16>,
+        check_padded: (u8, u32),
+        check_over_aligned: OverAligned,
+        check_unit: (),
+    }
+
+    #[kani::proof]
+    fn check_as_slice_reference() {
+        let value: u8 = kani::any();
+        let option: Option<&u8> = if kani::any() { Some(&value) } else { None };
+        let slice = option.as_slice();
+        assert_eq!(slice.len(), option.is_some() as usize);
+        if let [payload] = slice {
+            assert!(crate::ptr::eq(*payload, &value));
+        }
+    }
 }
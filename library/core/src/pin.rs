@@ -920,6 +920,9 @@
 
 #![stable(feature = "pin", since = "1.33.0")]
 
+#[cfg(kani)]
+use safety::{ensures, requires};
+
 use crate::hash::{Hash, Hasher};
 use crate::ops::{CoerceUnsized, Deref, DerefMut, DerefPure, DispatchFromDyn, LegacyReceiver};
 #[allow(unused_imports)]
@@ -933,6 +936,46 @@ use crate::{cmp, fmt};
 
 mod unsafe_pinned;
 
+/// Ghost state for the pinning contract of [`Pin::new_unchecked`].
+///
+/// The contract requires that the pointee is never moved again until it is dropped. Moves are
+/// not observable by Kani, so the obligation is tracked explicitly instead: a place is declared
+/// pinned with [`pin`](ghost::pin) before a `Pin` to it is created, stays pinned until its
+/// destructor calls [`unpin`](ghost::unpin), and code that moves out of a place first checks
+/// [`assert_movable`](ghost::assert_movable).
+///
+/// A single slot is enough for the harnesses in this module, which pin one place at a time.
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+pub(crate) mod ghost {
+    static mut PINNED: usize = 0;
+
+    /// Declares that the place at `pointee` will not move until it is dropped.
+    pub(crate) fn pin<T: ?Sized>(pointee: *const T) {
+        // SAFETY: Kani harnesses are single-threaded.
+        unsafe { PINNED = pointee.addr() };
+    }
+
+    /// Ends the pinning obligation for `pointee`; called once its destructor has run.
+    pub(crate) fn unpin<T: ?Sized>(pointee: *const T) {
+        if is_pinned(pointee) {
+            // SAFETY: Kani harnesses are single-threaded.
+            unsafe { PINNED = 0 };
+        }
+    }
+
+    /// Returns whether the place at `pointee` has been declared pinned.
+    pub(crate) fn is_pinned<T: ?Sized>(pointee: *const T) -> bool {
+        // SAFETY: Kani harnesses are single-threaded.
+        pointee.addr() != 0 && unsafe { PINNED } == pointee.addr()
+    }
+
+    /// Checks that the value at `place` may be moved out of.
+    pub(crate) fn assert_movable<T: ?Sized>(place: *const T) {
+        assert!(!is_pinned(place), "moved out of a pinned place");
+    }
+}
+
 #[unstable(feature = "unsafe_pinned", issue = "125735")]
 pub use self::unsafe_pinned::UnsafePinned;
 
@@ -1346,6 +1389,7 @@ impl<Ptr: Deref> Pin<Ptr> {
     #[inline(always)]
     #[rustc_const_stable(feature = "const_pin", since = "1.84.0")]
     #[stable(feature = "pin", since = "1.33.0")]
+    #[cfg_attr(kani, requires(ghost::is_pinned::<Ptr::Target>(&*pointer)))]
     pub const unsafe fn new_unchecked(pointer: Ptr) -> Pin<Ptr> {
         Pin { pointer }
     }
@@ -1499,6 +1543,8 @@ impl<Ptr: Deref> Pin<Ptr> {
     #[rustc_allow_const_fn_unstable(const_precise_live_drops)]
     #[rustc_const_stable(feature = "const_pin", since = "1.84.0")]
     #[stable(feature = "pin_into_inner", since = "1.39.0")]
+    #[cfg_attr(kani, requires(ghost::is_pinned::<Ptr::Target>(&*pin.pointer)))]
+    #[cfg_attr(kani, ensures(|result: &Ptr| ghost::is_pinned::<Ptr::Target>(&**result)))]
     pub const unsafe fn into_inner_unchecked(pin: Pin<Ptr>) -> Ptr {
         pin.pointer
     }
@@ -1945,3 +1991,81 @@ pub macro pin($value:expr $(,)?) {
         unsafe { $crate::pin::Pin::new_unchecked(&mut pinned) }
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::kani;
+
+    /// A `!Unpin` value that releases its pinning obligation when dropped.
+    struct SelfRef {
+        value: u32,
+        _pinned: PhantomPinned,
+    }
+
+    impl SelfRef {
+        fn new(value: u32) -> Self {
+            SelfRef { value, _pinned: PhantomPinned }
+        }
+    }
+
+    impl Drop for SelfRef {
+        fn drop(&mut self) {
+            ghost::unpin(self);
+        }
+    }
+
+    #[kani::proof_for_contract(<Pin<&mut SelfRef>>::new_unchecked)]
+    fn check_new_unchecked() {
+        let mut val = SelfRef::new(kani::any());
+        ghost::pin(&val);
+        let _ = unsafe { Pin::new_unchecked(&mut val) };
+    }
+
+    #[kani::proof_for_contract(<Pin<&mut SelfRef>>::into_inner_unchecked)]
+    fn check_into_inner_unchecked() {
+        let mut val = SelfRef::new(kani::any());
+        ghost::pin(&val);
+        let pinned = unsafe { Pin::new_unchecked(&mut val) };
+        let _ = unsafe { Pin::into_inner_unchecked(pinned) };
+    }
+
+    // For `Unpin` pointees the obligation is vacuous: `Pin::new` needs no ghost declaration and
+    // the value can be moved out again once the pin is unwrapped.
+    #[kani::proof]
+    fn check_new_unpin() {
+        let mut val: u32 = kani::any();
+        let original = val;
+        let pinned = Pin::new(&mut val);
+        assert_eq!(*pinned, original);
+        let inner = Pin::into_inner(pinned);
+        ghost::assert_movable(inner);
+        assert_eq!(mem::replace(inner, 0), original);
+    }
+
+    // The obligation ends when the pinned value is dropped, so its storage can be reused.
+    #[kani::proof]
+    fn check_pinned_until_drop() {
+        let mut slot = Some(SelfRef::new(kani::any()));
+        let val = slot.as_mut().unwrap();
+        ghost::pin(val);
+        let pinned = unsafe { Pin::new_unchecked(val) };
+        assert!(ghost::is_pinned(&*pinned));
+        slot = None;
+        ghost::assert_movable(&slot);
+    }
+
+    // Moving a `!Unpin` value out from under a pin breaks the contract of `Pin::new_unchecked`.
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_move_out_of_pinned() {
+        let mut val = SelfRef::new(kani::any());
+        ghost::pin(&val);
+        let pinned = unsafe { Pin::new_unchecked(&mut val) };
+        let inner = unsafe { Pin::into_inner_unchecked(pinned) };
+        ghost::assert_movable(&*inner);
+        let moved = mem::replace(inner, SelfRef::new(0));
+        assert_eq!(moved.value, inner.value);
+    }
+}
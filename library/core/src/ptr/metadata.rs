@@ -1,8 +1,12 @@
 #![unstable(feature = "ptr_metadata", issue = "81513")]
 
+use safety::ensures;
+
 use crate::fmt;
 use crate::hash::{Hash, Hasher};
 use crate::intrinsics::{aggregate_raw_ptr, ptr_metadata};
+#[cfg(kani)]
+use crate::kani;
 use crate::marker::{Freeze, PointeeSized};
 use crate::ptr::NonNull;
 
@@ -109,6 +113,10 @@ pub const fn metadata<T: PointeeSized>(ptr: *const T) -> <T as Pointee>::Metadat
 /// [`slice::from_raw_parts`]: crate::slice::from_raw_parts
 #[unstable(feature = "ptr_metadata", issue = "81513")]
 #[inline]
+// `aggregate_raw_ptr` cannot carry a contract itself (see
+// https://github.com/model-checking/kani/issues/3325), so its behavior is specified here.
+#[ensures(|result: &*const T| result.cast::<()>() == data_pointer.cast::<()>())]
+#[ensures(|result: &*const T| crate::ptr::metadata(*result) == metadata)]
 pub const fn from_raw_parts<T: PointeeSized>(
     data_pointer: *const impl Thin,
     metadata: <T as Pointee>::Metadata,
@@ -122,6 +130,8 @@ pub const fn from_raw_parts<T: PointeeSized>(
 /// See the documentation of [`from_raw_parts`] for more details.
 #[unstable(feature = "ptr_metadata", issue = "81513")]
 #[inline]
+#[ensures(|result: &*mut T| result.cast::<()>() == data_pointer.cast::<()>())]
+#[ensures(|result: &*mut T| crate::ptr::metadata(*result) == metadata)]
 pub const fn from_raw_parts_mut<T: PointeeSized>(
     data_pointer: *mut impl Thin,
     metadata: <T as Pointee>::Metadata,
@@ -182,6 +192,7 @@ impl<Dyn: PointeeSized> DynMetadata<Dyn> {
 
     /// Returns the size of the type associated with this vtable.
     #[inline]
+    #[ensures(|result| *result <= isize::MAX as usize && *result % self.align_of() == 0)]
     pub fn size_of(self) -> usize {
         // Note that "size stored in vtable" is *not* the same as "result of size_of_val_raw".
         // Consider a reference like `&(i32, dyn Send)`: the vtable will only store the size of the
@@ -192,6 +203,7 @@ impl<Dyn: PointeeSized> DynMetadata<Dyn> {
 
     /// Returns the alignment of the type associated with this vtable.
     #[inline]
+    #[ensures(|result| result.is_power_of_two())]
     pub fn align_of(self) -> usize {
         // SAFETY: DynMetadata always contains a valid vtable pointer
         unsafe { crate::intrinsics::vtable_align(self.vtable_ptr() as *const ()) }
@@ -199,6 +211,7 @@ impl<Dyn: PointeeSized> DynMetadata<Dyn> {
 
     /// Returns the size and alignment together as a `Layout`
     #[inline]
+    #[ensures(|result| result.size() == self.size_of() && result.align() == self.align_of())]
     pub fn layout(self) -> crate::alloc::Layout {
         // SAFETY: the compiler emitted this vtable for a concrete Rust type which
         // is known to have a valid layout. Same rationale as in `Layout::for_value`.
@@ -258,3 +271,165 @@ impl<Dyn: PointeeSized> Hash for DynMetadata<Dyn> {
         crate::ptr::hash::<VTable, _>(self.vtable_ptr(), hasher)
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    trait Shape {
+        fn area(&self) -> u32;
+    }
+
+    struct Rect {
+        width: u16,
+        height: u16,
+    }
+
+    impl Shape for Rect {
+        fn area(&self) -> u32 {
+            self.width as u32 * self.height as u32
+        }
+    }
+
+    // Implemented by every type, so that any concrete type can be viewed as a trait object.
+    trait Erased {}
+
+    impl<T> Erased for T {}
+
+    #[repr(align(32))]
+    struct OverAligned(#[allow(dead_code)] u8);
+
+    impl kani::Arbitrary for OverAligned {
+        fn any() -> Self {
+            OverAligned(kani::any())
+        }
+    }
+
+    const ARRAY_LEN: usize = 8;
+
+    #[kani::proof_for_contract(from_raw_parts)]
+    pub fn check_from_raw_parts_slice() {
+        let arr: [u32; ARRAY_LEN] = kani::any();
+        let len: usize = kani::any();
+        let _ = from_raw_parts::<[u32]>(arr.as_ptr(), len);
+    }
+
+    #[kani::proof_for_contract(from_raw_parts_mut)]
+    pub fn check_from_raw_parts_mut_slice() {
+        let mut arr: [u32; ARRAY_LEN] = kani::any();
+        let len: usize = kani::any();
+        let _ = from_raw_parts_mut::<[u32]>(arr.as_mut_ptr(), len);
+    }
+
+    #[kani::proof_for_contract(from_raw_parts)]
+    pub fn check_from_raw_parts_dyn() {
+        let rect = Rect { width: kani::any(), height: kani::any() };
+        let meta = metadata(&rect as &dyn Shape);
+        let _ = from_raw_parts::<dyn Shape>(&rect as *const Rect, meta);
+    }
+
+    #[kani::proof_for_contract(from_raw_parts_mut)]
+    pub fn check_from_raw_parts_mut_dyn() {
+        let mut rect = Rect { width: kani::any(), height: kani::any() };
+        let meta = metadata(&rect as &dyn Shape);
+        let _ = from_raw_parts_mut::<dyn Shape>(&mut rect as *mut Rect, meta);
+    }
+
+    // `ptr_metadata` returns the length used to build a slice pointer, and the pointer can be
+    // used as a slice of that length.
+    #[kani::proof]
+    pub fn check_slice_metadata_round_trip() {
+        let arr: [u32; ARRAY_LEN] = kani::any();
+        let len: usize = kani::any_where(|l| *l <= ARRAY_LEN);
+        let ptr = from_raw_parts::<[u32]>(arr.as_ptr(), len);
+        assert_eq!(metadata(ptr), len);
+        assert_eq!(ptr.len(), len);
+        assert_eq!(ptr.to_raw_parts(), (arr.as_ptr().cast::<()>(), len));
+
+        let slice = unsafe { &*ptr };
+        assert_eq!(slice, &arr[..len]);
+    }
+
+    // Rebuilding a trait object from its parts yields the same vtable, so dynamic dispatch
+    // through the new pointer reaches the original implementation.
+    #[kani::proof]
+    pub fn check_dyn_metadata_round_trip() {
+        let rect = Rect { width: kani::any(), height: kani::any() };
+        let original: *const dyn Shape = &rect;
+        let (data, meta) = original.to_raw_parts();
+        let ptr = from_raw_parts::<dyn Shape>(data, meta);
+        assert_eq!(metadata(ptr), meta);
+        assert_eq!(meta.size_of(), size_of::<Rect>());
+        assert_eq!(meta.align_of(), align_of::<Rect>());
+        assert_eq!(unsafe { (*ptr).area() }, rect.area());
+    }
+
+    /// Generates harnesses checking the `DynMetadata` accessors of `dyn Erased` built from a
+    /// concrete type against `size_of_val`/`align_of_val` and the static layout of that type.
+    macro_rules! generate_dyn_metadata_harnesses {
+        ($ty:ty, $size_harness:ident, $align_harness:ident, $layout_harness:ident) => {
+            #[kani::proof_for_contract(<DynMetadata<dyn Erased>>::size_of)]
+            pub fn $size_harness() {
+                let val: $ty = kani::any();
+                let obj: &dyn Erased = &val;
+                let size = metadata(obj).size_of();
+                assert_eq!(size, crate::mem::size_of_val(obj));
+                assert_eq!(size, size_of::<$ty>());
+            }
+
+            #[kani::proof_for_contract(<DynMetadata<dyn Erased>>::align_of)]
+            pub fn $align_harness() {
+                let val: $ty = kani::any();
+                let obj: &dyn Erased = &val;
+                let align = metadata(obj).align_of();
+                assert_eq!(align, crate::mem::align_of_val(obj));
+                assert_eq!(align, align_of::<$ty>());
+            }
+
+            #[kani::proof_for_contract(<DynMetadata<dyn Erased>>::layout)]
+            pub fn $layout_harness() {
+                let val: $ty = kani::any();
+                let obj: &dyn Erased = &val;
+                assert_eq!(metadata(obj).layout(), crate::alloc::Layout::new::<$ty>());
+            }
+        };
+    }
+
+    generate_dyn_metadata_harnesses!(
+        (),
+        check_dyn_size_of_unit,
+        check_dyn_align_of_unit,
+        check_dyn_layout_unit
+    );
+    generate_dyn_metadata_harnesses!(
+        u8,
+        check_dyn_size_of_u8,
+        check_dyn_align_of_u8,
+        check_dyn_layout_u8
+    );
+    generate_dyn_metadata_harnesses!(
+        u64,
+        check_dyn_size_of_u64,
+        check_dyn_align_of_u64,
+        check_dyn_layout_u64
+    );
+    generate_dyn_metadata_harnesses!(
+        (u8, u32),
+        check_dyn_size_of_tuple,
+        check_dyn_align_of_tuple,
+        check_dyn_layout_tuple
+    );
+    generate_dyn_metadata_harnesses!(
+        [u16; 3],
+        check_dyn_size_of_array,
+        check_dyn_align_of_array,
+        check_dyn_layout_array
+    );
+    generate_dyn_metadata_harnesses!(
+        OverAligned,
+        check_dyn_size_of_over_aligned,
+        check_dyn_align_of_over_aligned,
+        check_dyn_layout_over_aligned
+    );
+}
@@ -520,6 +520,12 @@ mod mut_ptr;
 #[inline(always)]
 #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
 #[rustc_diagnostic_item = "ptr_copy_nonoverlapping"]
+#[cfg_attr(kani, kani::modifies(crate::ptr::slice_from_raw_parts(dst, count)))]
+#[safety::requires(!count.overflowing_mul(size_of::<T>()).1
+  && ub_checks::can_dereference(core::ptr::slice_from_raw_parts(src as *const crate::mem::MaybeUninit<T>, count))
+  && ub_checks::can_write(core::ptr::slice_from_raw_parts_mut(dst, count))
+  && ub_checks::maybe_is_nonoverlapping(src as *const (), dst as *const (), size_of::<T>(), count))]
+#[safety::ensures(|_| intrinsics::check_copy_untyped(src, dst, count))]
 pub const unsafe fn copy_nonoverlapping<T>(src: *const T, dst: *mut T, count: usize) {
     ub_checks::assert_unsafe_precondition!(
         check_language_ub,
@@ -617,6 +623,14 @@ pub const unsafe fn copy_nonoverlapping<T>(src: *const T, dst: *mut T, count: us
 #[inline(always)]
 #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
 #[rustc_diagnostic_item = "ptr_copy"]
+#[cfg_attr(kani, kani::modifies(crate::ptr::slice_from_raw_parts(dst, count)))]
+#[safety::requires(!count.overflowing_mul(size_of::<T>()).1
+  && ub_checks::can_dereference(core::ptr::slice_from_raw_parts(src as *const crate::mem::MaybeUninit<T>, count))
+  && ub_checks::can_write(core::ptr::slice_from_raw_parts_mut(dst, count)))]
+// The source region may be overwritten by an overlapping copy, so the initialization state of
+// the two regions can only be compared afterwards when they do not overlap.
+#[safety::ensures(|_| !ub_checks::maybe_is_nonoverlapping(src as *const (), dst as *const (), size_of::<T>(), count)
+  || intrinsics::check_copy_untyped(src, dst, count))]
 pub const unsafe fn copy<T>(src: *const T, dst: *mut T, count: usize) {
     // SAFETY: the safety contract for `copy` must be upheld by the caller.
     unsafe {
@@ -873,6 +887,7 @@ pub const fn null_mut<T: PointeeSized + Thin>() -> *mut T {
 #[must_use]
 #[stable(feature = "strict_provenance", since = "1.84.0")]
 #[rustc_const_stable(feature = "strict_provenance", since = "1.84.0")]
+#[safety::ensures(|result: &*const T| result.addr() == addr)]
 pub const fn without_provenance<T>(addr: usize) -> *const T {
     without_provenance_mut(addr)
 }
@@ -890,6 +905,7 @@ pub const fn without_provenance<T>(addr: usize) -> *const T {
 #[must_use]
 #[stable(feature = "strict_provenance", since = "1.84.0")]
 #[rustc_const_stable(feature = "strict_provenance", since = "1.84.0")]
+#[safety::ensures(|result: &*const T| result.addr() == align_of::<T>() && !result.is_null())]
 pub const fn dangling<T>() -> *const T {
     dangling_mut()
 }
@@ -911,6 +927,7 @@ pub const fn dangling<T>() -> *const T {
 #[must_use]
 #[stable(feature = "strict_provenance", since = "1.84.0")]
 #[rustc_const_stable(feature = "strict_provenance", since = "1.84.0")]
+#[safety::ensures(|result: &*mut T| result.addr() == addr)]
 pub const fn without_provenance_mut<T>(addr: usize) -> *mut T {
     // An int-to-pointer transmute currently has exactly the intended semantics: it creates a
     // pointer without provenance. Note that this is *not* a stable guarantee about transmute
@@ -933,6 +950,7 @@ pub const fn without_provenance_mut<T>(addr: usize) -> *mut T {
 #[must_use]
 #[stable(feature = "strict_provenance", since = "1.84.0")]
 #[rustc_const_stable(feature = "strict_provenance", since = "1.84.0")]
+#[safety::ensures(|result: &*mut T| result.addr() == align_of::<T>() && !result.is_null())]
 pub const fn dangling_mut<T>() -> *mut T {
     NonNull::dangling().as_ptr()
 }
@@ -973,6 +991,7 @@ pub const fn dangling_mut<T>() -> *mut T {
 #[stable(feature = "exposed_provenance", since = "1.84.0")]
 #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
 #[allow(fuzzy_provenance_casts)] // this *is* the explicit provenance API one should use instead
+#[safety::ensures(|result: &*const T| result.addr() == addr)]
 pub fn with_exposed_provenance<T>(addr: usize) -> *const T {
     addr as *const T
 }
@@ -1013,6 +1032,7 @@ pub fn with_exposed_provenance<T>(addr: usize) -> *const T {
 #[stable(feature = "exposed_provenance", since = "1.84.0")]
 #[cfg_attr(miri, track_caller)] // even without panics, this helps for Miri backtraces
 #[allow(fuzzy_provenance_casts)] // this *is* the explicit provenance API one should use instead
+#[safety::ensures(|result: &*mut T| result.addr() == addr)]
 pub fn with_exposed_provenance_mut<T>(addr: usize) -> *mut T {
     addr as *mut T
 }
@@ -1284,6 +1304,9 @@ pub const fn slice_from_raw_parts_mut<T>(data: *mut T, len: usize) -> *mut [T] {
 #[stable(feature = "rust1", since = "1.0.0")]
 #[rustc_const_stable(feature = "const_swap", since = "1.85.0")]
 #[rustc_diagnostic_item = "ptr_swap"]
+#[safety::requires(ub_checks::can_dereference(x as *const MaybeUninit<T>) && ub_checks::can_write(x)
+  && ub_checks::can_dereference(y as *const MaybeUninit<T>) && ub_checks::can_write(y))]
+#[cfg_attr(kani, kani::modifies(x, y))]
 pub const unsafe fn swap<T>(x: *mut T, y: *mut T) {
     // Give ourselves some scratch space to work with.
     // We do not have to worry about drops: `MaybeUninit` does nothing when dropped.
@@ -1382,6 +1405,14 @@ pub const unsafe fn swap<T>(x: *mut T, y: *mut T) {
 #[rustc_diagnostic_item = "ptr_swap_nonoverlapping"]
 #[rustc_allow_const_fn_unstable(const_eval_select)] // both implementations behave the same
 #[track_caller]
+#[safety::requires(!count.overflowing_mul(size_of::<T>()).1
+  && ub_checks::can_dereference(core::ptr::slice_from_raw_parts(x as *const MaybeUninit<T>, count))
+  && ub_checks::can_write(core::ptr::slice_from_raw_parts_mut(x, count))
+  && ub_checks::can_dereference(core::ptr::slice_from_raw_parts(y as *const MaybeUninit<T>, count))
+  && ub_checks::can_write(core::ptr::slice_from_raw_parts_mut(y, count))
+  && ub_checks::maybe_is_nonoverlapping(x as *const (), y as *const (), size_of::<T>(), count))]
+#[cfg_attr(kani, kani::modifies(core::ptr::slice_from_raw_parts(x, count)))]
+#[cfg_attr(kani, kani::modifies(core::ptr::slice_from_raw_parts(y, count)))]
 pub const unsafe fn swap_nonoverlapping<T>(x: *mut T, y: *mut T, count: usize) {
     ub_checks::assert_unsafe_precondition!(
         check_library_ub,
@@ -1563,6 +1594,11 @@ unsafe fn swap_nonoverlapping_bytes(x: *mut u8, y: *mut u8, bytes: NonZero<usize
 #[rustc_const_stable(feature = "const_replace", since = "1.83.0")]
 #[rustc_diagnostic_item = "ptr_replace"]
 #[track_caller]
+#[safety::requires(ub_checks::can_dereference(dst) && ub_checks::can_write(dst))]
+#[cfg_attr(kani, kani::modifies(dst))]
+#[cfg_attr(kani, safety::ensures(|previous: &T| unsafe {
+    same_bytes(previous, &*old(mem::ManuallyDrop::new(dst.read())))
+}))]
 pub const unsafe fn replace<T>(dst: *mut T, src: T) -> T {
     // SAFETY: the caller must guarantee that `dst` is valid to be
     // cast to a mutable reference (valid for writes, aligned, initialized),
@@ -1810,6 +1846,8 @@ pub const unsafe fn read<T>(src: *const T) -> T {
 #[rustc_const_stable(feature = "const_ptr_read", since = "1.71.0")]
 #[track_caller]
 #[rustc_diagnostic_item = "ptr_read_unaligned"]
+#[safety::requires(ub_checks::can_read_unaligned(src))]
+#[cfg_attr(kani, safety::ensures(|result: &T| unsafe { same_bytes(result, src) }))]
 pub const unsafe fn read_unaligned<T>(src: *const T) -> T {
     let mut tmp = MaybeUninit::<T>::uninit();
     // SAFETY: the caller must guarantee that `src` is valid for reads.
@@ -1824,6 +1862,17 @@ pub const unsafe fn read_unaligned<T>(src: *const T) -> T {
     }
 }
 
+/// Ghost predicate: the `size_of::<T>()` bytes at `a` and at `b` are identical.
+///
+/// # Safety
+///
+/// Both pointers must be valid for reads of `size_of::<T>()` bytes.
+#[cfg(kani)]
+unsafe fn same_bytes<T>(a: *const T, b: *const T) -> bool {
+    let (a, b) = (a as *const u8, b as *const u8);
+    kani::forall!(|i in (0, size_of::<T>())| unsafe { *a.wrapping_add(i) == *b.wrapping_add(i) })
+}
+
 /// Overwrites a memory location with the given value without reading or
 /// dropping the old value.
 ///
@@ -2012,6 +2061,8 @@ pub const unsafe fn write<T>(dst: *mut T, src: T) {
 #[rustc_const_stable(feature = "const_ptr_write", since = "1.83.0")]
 #[rustc_diagnostic_item = "ptr_write_unaligned"]
 #[track_caller]
+#[safety::requires(ub_checks::can_write_unaligned(dst))]
+#[cfg_attr(kani, kani::modifies(dst))]
 pub const unsafe fn write_unaligned<T>(dst: *mut T, src: T) {
     // SAFETY: the caller must guarantee that `dst` is valid for writes.
     // `dst` cannot overlap `src` because the caller has mutable access
@@ -2225,7 +2276,7 @@ pub unsafe fn write_volatile<T>(dst: *mut T, src: T) {
     // requires computing gcd(a, stride), which is too expensive without
     // quantifiers (https://model-checking.github.io/kani/rfc/rfcs/0010-quantifiers.html).
     // This should be updated once quantifiers are available.
-    if (a % stride != 0 && *result == usize::MAX) {
+    if a % stride != 0 && *result == usize::MAX {
         return true;
     }
 
@@ -2869,4 +2920,209 @@ mod verify {
         let p = kani::any::<usize>() as *const [char; 5];
         check_align_offset(p);
     }
+
+    #[kani::proof_for_contract(with_exposed_provenance)]
+    pub fn check_with_exposed_provenance() {
+        let _ = with_exposed_provenance::<u32>(kani::any());
+    }
+
+    #[kani::proof_for_contract(with_exposed_provenance_mut)]
+    pub fn check_with_exposed_provenance_mut() {
+        let _ = with_exposed_provenance_mut::<u32>(kani::any());
+    }
+
+    // Exposing a pointer and converting the address back yields a pointer to the same address.
+    #[kani::proof]
+    pub fn check_expose_provenance_round_trip() {
+        let val: u32 = kani::any();
+        let ptr = &val as *const u32;
+        let addr = ptr.expose_provenance();
+        assert_eq!(addr, ptr.addr());
+        assert_eq!(with_exposed_provenance::<u32>(addr), ptr);
+    }
+
+    // `dangling`/`without_provenance` proofs
+
+    #[repr(align(64))]
+    struct Align64(#[allow(dead_code)] u8);
+
+    #[repr(C, align(4096))]
+    struct AlignPage;
+
+    macro_rules! check_dangling {
+        ($($mod_name:ident: $ty:ty),+ $(,)?) => {$(
+            mod $mod_name {
+                use super::*;
+
+                #[kani::proof_for_contract(without_provenance)]
+                fn check_without_provenance() {
+                    let _ = without_provenance::<$ty>(kani::any());
+                }
+
+                #[kani::proof_for_contract(without_provenance_mut)]
+                fn check_without_provenance_mut() {
+                    let _ = without_provenance_mut::<$ty>(kani::any());
+                }
+
+                #[kani::proof]
+                fn check_dangling() {
+                    let ptr = dangling::<$ty>();
+                    assert_eq!(ptr.addr(), align_of::<$ty>());
+                    assert!(!ptr.is_null() && ptr.is_aligned());
+                    assert_eq!(dangling_mut::<$ty>().cast_const(), ptr);
+                    assert_eq!(NonNull::<$ty>::dangling().as_ptr().cast_const(), ptr);
+                    // A dangling pointer is always usable for an empty slice.
+                    let empty = unsafe { crate::slice::from_raw_parts(ptr, 0) };
+                    assert!(empty.is_empty());
+                }
+            }
+        )+};
+    }
+
+    check_dangling! {
+        dangling_u8: u8,
+        dangling_u16: u16,
+        dangling_u32: u32,
+        dangling_u64: u64,
+        dangling_u128: u128,
+        dangling_usize: usize,
+        dangling_i8: i8,
+        dangling_i16: i16,
+        dangling_i32: i32,
+        dangling_i64: i64,
+        dangling_i128: i128,
+        dangling_isize: isize,
+        dangling_f32: f32,
+        dangling_f64: f64,
+        dangling_bool: bool,
+        dangling_char: char,
+        dangling_unit: (),
+        dangling_align64: Align64,
+        dangling_align_page: AlignPage,
+    }
+
+    #[kani::proof_for_contract(NonNull::without_provenance)]
+    fn check_non_null_without_provenance() {
+        let _ = NonNull::<u32>::without_provenance(kani::any());
+    }
+
+    // `swap`/`swap_nonoverlapping` proofs
+
+    const SWAP_BUF: usize = 8;
+    type Chunk = [u8; 3];
+
+    fn any_chunk_ptrs() -> (*mut Chunk, *mut Chunk) {
+        let mut generator = kani::PointerGenerator::<SWAP_BUF>::new();
+        let x: *mut Chunk = generator.any_in_bounds().ptr;
+        let y: *mut Chunk = generator.any_in_bounds().ptr;
+        (x, y)
+    }
+
+    #[kani::proof_for_contract(swap)]
+    fn check_swap() {
+        let (x, y) = any_chunk_ptrs();
+        unsafe { swap(x, y) };
+    }
+
+    /// `swap` copies `y` into `x` and then the old `x` into `y`, so `y` always ends up holding
+    /// the old `x`, and the bytes of `x` outside of `y` hold the matching bytes of the old `y`.
+    #[kani::proof]
+    #[kani::unwind(4)]
+    fn check_swap_overlapping_result() {
+        let (x, y) = any_chunk_ptrs();
+        let (old_x, old_y) = unsafe { (x.read(), y.read()) };
+        let distance = unsafe { y.byte_offset_from(x) };
+        unsafe { swap(x, y) };
+        let (new_x, new_y) = unsafe { (x.read(), y.read()) };
+        assert_eq!(new_y, old_x);
+        for i in 0..size_of::<Chunk>() {
+            if !(0..size_of::<Chunk>() as isize).contains(&(i as isize - distance)) {
+                assert_eq!(new_x[i], old_y[i]);
+            }
+        }
+    }
+
+    #[kani::proof_for_contract(swap_nonoverlapping)]
+    fn check_swap_nonoverlapping() {
+        // The precondition rules out `count`s that run past the end of either buffer.
+        let mut generator1 = kani::PointerGenerator::<SWAP_BUF>::new();
+        let mut generator2 = kani::PointerGenerator::<SWAP_BUF>::new();
+        let count = kani::any_where(|&count: &usize| count <= 2);
+        let x: *mut Chunk = generator1.any_in_bounds().ptr;
+        let y: *mut Chunk = generator2.any_in_bounds().ptr;
+        unsafe { swap_nonoverlapping(x, y, count) };
+    }
+
+    /// The same overlapping inputs that `swap` accepts violate the precondition of
+    /// `swap_nonoverlapping`.
+    #[kani::proof]
+    #[kani::should_panic]
+    #[kani::stub_verified(swap_nonoverlapping)]
+    fn check_swap_nonoverlapping_rejects_overlap() {
+        let (x, y) = any_chunk_ptrs();
+        let distance = unsafe { y.byte_offset_from(x) };
+        kani::assume(distance.unsigned_abs() < size_of::<Chunk>());
+        unsafe { swap_nonoverlapping(x, y, 1) };
+    }
+
+    // `replace`/`read_unaligned`/`write_unaligned` proofs
+
+    macro_rules! check_replace_unaligned {
+        ($($mod_name:ident: $ty:ty),+ $(,)?) => {$(
+            mod $mod_name {
+                use super::*;
+
+                const BUF: usize = 2 * size_of::<$ty>();
+
+                #[kani::proof_for_contract(replace)]
+                fn check_replace() {
+                    let mut generator = kani::PointerGenerator::<BUF>::new();
+                    let dst: *mut $ty = generator.any_in_bounds().ptr;
+                    let _ = unsafe { replace(dst, kani::any()) };
+                }
+
+                #[kani::proof]
+                fn check_replace_stores() {
+                    let mut value: $ty = kani::any();
+                    let old_value = value;
+                    let new_value: $ty = kani::any();
+                    assert!(unsafe { replace(&mut value, new_value) } == old_value);
+                    assert!(value == new_value);
+                }
+
+                #[kani::proof_for_contract(read_unaligned)]
+                fn check_read_unaligned() {
+                    let mut generator = kani::PointerGenerator::<BUF>::new();
+                    let src: *const $ty = generator.any_in_bounds().ptr;
+                    let _ = unsafe { read_unaligned(src) };
+                }
+
+                #[kani::proof_for_contract(write_unaligned)]
+                fn check_write_unaligned() {
+                    let mut generator = kani::PointerGenerator::<BUF>::new();
+                    let dst: *mut $ty = generator.any_in_bounds().ptr;
+                    unsafe { write_unaligned(dst, kani::any()) };
+                }
+
+                #[kani::proof]
+                fn check_unaligned_round_trip() {
+                    let mut generator = kani::PointerGenerator::<BUF>::new();
+                    let dst: *mut $ty = generator.any_in_bounds().ptr;
+                    let value: $ty = kani::any();
+                    unsafe { write_unaligned(dst, value) };
+                    assert!(unsafe { read_unaligned(dst) } == value);
+                }
+            }
+        )+};
+    }
+
+    check_replace_unaligned! {
+        replace_unaligned_u8: u8,
+        replace_unaligned_u16: u16,
+        replace_unaligned_u32: u32,
+        replace_unaligned_u64: u64,
+        replace_unaligned_u128: u128,
+        replace_unaligned_i16_pair: (i16, i16),
+        replace_unaligned_array: [u32; 4],
+    }
 }
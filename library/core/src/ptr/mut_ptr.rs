@@ -196,6 +196,7 @@ impl<T: PointeeSized> *mut T {
     /// [`with_exposed_provenance_mut`]: with_exposed_provenance_mut
     #[inline(always)]
     #[stable(feature = "exposed_provenance", since = "1.84.0")]
+    #[ensures(|result| *result == self.addr())]
     pub fn expose_provenance(self) -> usize {
         self.cast::<()>() as usize
     }
@@ -214,6 +215,7 @@ impl<T: PointeeSized> *mut T {
     #[must_use]
     #[inline]
     #[stable(feature = "strict_provenance", since = "1.84.0")]
+    #[ensures(|result: &Self| result.addr() == addr)]
     pub fn with_addr(self, addr: usize) -> Self {
         // This should probably be an intrinsic to avoid doing any sort of arithmetic, but
         // meanwhile, we can implement it with `wrapping_offset`, which preserves the pointer's
@@ -3107,4 +3109,42 @@ mod verify {
     //         ptr_caller.byte_offset_from(ptr_input);
     //     }
     // }
+
+    // Strict provenance: `with_addr` and `expose_provenance` only change or report the address,
+    // and `with_addr`/`map_addr` keep the provenance of the original pointer.
+    #[kani::proof_for_contract(<*mut u32>::with_addr)]
+    pub fn check_mut_with_addr() {
+        let mut generator = PointerGenerator::<64>::new();
+        let ptr: *mut u32 = generator.any_alloc_status().ptr;
+        let _ = ptr.with_addr(kani::any());
+    }
+
+    #[kani::proof_for_contract(<*mut u32>::expose_provenance)]
+    pub fn check_mut_expose_provenance() {
+        let mut generator = PointerGenerator::<64>::new();
+        let ptr: *mut u32 = generator.any_alloc_status().ptr;
+        let _ = ptr.expose_provenance();
+    }
+
+    #[kani::proof]
+    pub fn check_mut_with_addr_keeps_provenance() {
+        let mut arr: [u32; 8] = kani::any();
+        let base = arr.as_mut_ptr();
+        let i: usize = kani::any_where(|i| *i < 8);
+        let ptr = base.with_addr(base.addr() + i * mem::size_of::<u32>());
+        assert!(crate::ub_checks::can_dereference(ptr));
+        assert_eq!(unsafe { *ptr }, arr[i]);
+    }
+
+    #[kani::proof]
+    pub fn check_mut_map_addr_round_trip() {
+        let mut generator = PointerGenerator::<64>::new();
+        let ptr: *mut u8 = generator.any_in_bounds().ptr;
+        let tag: usize = kani::any();
+        let tagged = ptr.map_addr(|addr| addr ^ tag);
+        assert_eq!(tagged.addr(), ptr.addr() ^ tag);
+        let untagged = tagged.map_addr(|addr| addr ^ tag);
+        assert_eq!(untagged, ptr);
+        assert!(crate::ub_checks::can_dereference(untagged));
+    }
 }
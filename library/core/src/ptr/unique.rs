@@ -1,4 +1,4 @@
-use safety::{ensures, requires};
+use safety::{ensures, invariant, requires};
 
 use crate::fmt;
 #[cfg(kani)]
@@ -38,6 +38,7 @@ use crate::ptr::NonNull;
 #[repr(transparent)]
 // Lang item used experimentally by Miri to define the semantics of `Unique`.
 #[lang = "ptr_unique"]
+#[invariant(!self.pointer.as_ptr().is_null())]
 pub struct Unique<T: PointeeSized> {
     pointer: NonNull<T>,
     // NOTE: this marker has no consequences for variance, but is necessary
@@ -63,6 +64,7 @@ unsafe impl<T: Send + PointeeSized> Send for Unique<T> {}
 unsafe impl<T: Sync + PointeeSized> Sync for Unique<T> {}
 
 #[unstable(feature = "ptr_internals", issue = "none")]
+#[invariant]
 impl<T: Sized> Unique<T> {
     /// Creates a new `Unique` that is dangling, but well-aligned.
     ///
@@ -82,6 +84,7 @@ impl<T: Sized> Unique<T> {
 }
 
 #[unstable(feature = "ptr_internals", issue = "none")]
+#[invariant]
 impl<T: PointeeSized> Unique<T> {
     /// Creates a new `Unique`.
     ///
@@ -227,6 +230,9 @@ impl<T: PointeeSized> From<NonNull<T>> for Unique<T> {
 mod verify {
     use super::*;
 
+    // `Unique` is generic and takes raw pointers, so its contracts are not eligible for
+    // autoharness runs and are checked by the hand-written harnesses below.
+
     // pub const unsafe fn new_unchecked(ptr: *mut T) -> Self
     #[kani::proof_for_contract(Unique::new_unchecked)]
     pub fn check_new_unchecked() {
@@ -513,6 +513,17 @@ const fn is_ascii(bytes: &[u8]) -> bool {
 #[unstable(feature = "kani", issue = "none")]
 pub mod verify {
     use super::*;
+    #[cfg(not(all(target_arch = "x86_64", target_feature = "sse2")))]
+    use crate::intrinsics::check_const_eval_select_arms;
+
+    #[cfg(not(all(target_arch = "x86_64", target_feature = "sse2")))]
+    check_const_eval_select_arms!(
+        #[kani::unwind(25)]
+        check_is_ascii_arms,
+        is_ascii_simple,
+        is_ascii,
+        { s: &[u8] = &kani::any::<[u8; 24]>() }
+    );
 
     #[kani::proof]
     #[kani::unwind(8)]
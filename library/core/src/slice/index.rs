@@ -1,6 +1,10 @@
 //! Indexing implementations for `[T]`.
 
+use safety::{ensures, requires};
+
 use crate::intrinsics::slice_get_unchecked;
+#[cfg(kani)]
+use crate::kani;
 use crate::panic::const_panic;
 use crate::ub_checks::assert_unsafe_precondition;
 use crate::{ops, range};
@@ -382,6 +386,9 @@ unsafe impl<T> SliceIndex<[T]> for ops::Range<usize> {
         }
     }
 
+    #[requires(self.start <= self.end && self.end <= slice.len())]
+    #[ensures(|result| result.len() == self.end - self.start)]
+    #[ensures(|result| result.cast::<T>() == slice.cast::<T>().wrapping_add(self.start))]
     #[inline]
     #[track_caller]
     unsafe fn get_unchecked(self, slice: *const [T]) -> *const [T] {
@@ -407,6 +414,9 @@ unsafe impl<T> SliceIndex<[T]> for ops::Range<usize> {
         }
     }
 
+    #[requires(self.start <= self.end && self.end <= slice.len())]
+    #[ensures(|result| result.len() == self.end - self.start)]
+    #[ensures(|result| result.cast::<T>() == slice.cast::<T>().wrapping_add(self.start))]
     #[inline]
     #[track_caller]
     unsafe fn get_unchecked_mut(self, slice: *mut [T]) -> *mut [T] {
@@ -504,12 +514,16 @@ unsafe impl<T> SliceIndex<[T]> for ops::RangeTo<usize> {
         (0..self.end).get_mut(slice)
     }
 
+    #[requires(self.end <= slice.len())]
+    #[ensures(|result| result.len() == self.end && result.cast::<T>() == slice.cast::<T>())]
     #[inline]
     unsafe fn get_unchecked(self, slice: *const [T]) -> *const [T] {
         // SAFETY: the caller has to uphold the safety contract for `get_unchecked`.
         unsafe { (0..self.end).get_unchecked(slice) }
     }
 
+    #[requires(self.end <= slice.len())]
+    #[ensures(|result| result.len() == self.end && result.cast::<T>() == slice.cast::<T>())]
     #[inline]
     unsafe fn get_unchecked_mut(self, slice: *mut [T]) -> *mut [T] {
         // SAFETY: the caller has to uphold the safety contract for `get_unchecked_mut`.
@@ -542,12 +556,18 @@ unsafe impl<T> SliceIndex<[T]> for ops::RangeFrom<usize> {
         (self.start..slice.len()).get_mut(slice)
     }
 
+    #[requires(self.start <= slice.len())]
+    #[ensures(|result| result.len() == slice.len() - self.start)]
+    #[ensures(|result| result.cast::<T>() == slice.cast::<T>().wrapping_add(self.start))]
     #[inline]
     unsafe fn get_unchecked(self, slice: *const [T]) -> *const [T] {
         // SAFETY: the caller has to uphold the safety contract for `get_unchecked`.
         unsafe { (self.start..slice.len()).get_unchecked(slice) }
     }
 
+    #[requires(self.start <= slice.len())]
+    #[ensures(|result| result.len() == slice.len() - self.start)]
+    #[ensures(|result| result.cast::<T>() == slice.cast::<T>().wrapping_add(self.start))]
     #[inline]
     unsafe fn get_unchecked_mut(self, slice: *mut [T]) -> *mut [T] {
         // SAFETY: the caller has to uphold the safety contract for `get_unchecked_mut`.
@@ -663,12 +683,22 @@ unsafe impl<T> SliceIndex<[T]> for ops::RangeInclusive<usize> {
         if *self.end() == usize::MAX { None } else { self.into_slice_range().get_mut(slice) }
     }
 
+    #[requires(*self.end() < slice.len() && (self.exhausted || *self.start() <= *self.end() + 1))]
+    #[ensures(|result| {
+        let end = slice.cast::<T>().wrapping_add(*self.end() + 1);
+        result.cast::<T>().wrapping_add(result.len()) == end
+    })]
     #[inline]
     unsafe fn get_unchecked(self, slice: *const [T]) -> *const [T] {
         // SAFETY: the caller has to uphold the safety contract for `get_unchecked`.
         unsafe { self.into_slice_range().get_unchecked(slice) }
     }
 
+    #[requires(*self.end() < slice.len() && (self.exhausted || *self.start() <= *self.end() + 1))]
+    #[ensures(|result| {
+        let end = slice.cast::<T>().wrapping_add(*self.end() + 1);
+        result.cast::<T>().wrapping_add(result.len()) == end
+    })]
     #[inline]
     unsafe fn get_unchecked_mut(self, slice: *mut [T]) -> *mut [T] {
         // SAFETY: the caller has to uphold the safety contract for `get_unchecked_mut`.
@@ -1026,3 +1056,85 @@ unsafe impl<T> SliceIndex<[T]> for (ops::Bound<usize>, ops::Bound<usize>) {
         into_slice_range(slice.len(), self).index_mut(slice)
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // Range `SliceIndex` proofs
+
+    const INDEX_LEN: usize = 8;
+
+    fn any_range_inclusive() -> ops::RangeInclusive<usize> {
+        let mut range = kani::any::<usize>()..=kani::any::<usize>();
+        if kani::any() {
+            // Drive the range into its exhausted state.
+            let _ = range.nth(kani::any());
+        }
+        range
+    }
+
+    macro_rules! check_range_get_unchecked {
+        ($($module:ident: $range:ty = $any:expr;)+) => {$(
+            mod $module {
+                use super::*;
+
+                #[kani::proof_for_contract(<$range as SliceIndex<[u8]>>::get_unchecked)]
+                fn check_get_unchecked() {
+                    let array: [u8; INDEX_LEN] = kani::any();
+                    let slice = kani::slice::any_slice_of_array(&array);
+                    let range: $range = $any;
+                    let _ = unsafe { range.get_unchecked(slice as *const [u8]) };
+                }
+
+                #[kani::proof_for_contract(<$range as SliceIndex<[u8]>>::get_unchecked_mut)]
+                fn check_get_unchecked_mut() {
+                    let mut array: [u8; INDEX_LEN] = kani::any();
+                    let slice = kani::slice::any_slice_of_array_mut(&mut array);
+                    let range: $range = $any;
+                    let _ = unsafe { range.get_unchecked_mut(slice as *mut [u8]) };
+                }
+            }
+        )+};
+    }
+
+    check_range_get_unchecked! {
+        range: ops::Range<usize> = kani::any::<usize>()..kani::any::<usize>();
+        range_to: ops::RangeTo<usize> = ..kani::any::<usize>();
+        range_from: ops::RangeFrom<usize> = kani::any::<usize>()..;
+        range_inclusive: ops::RangeInclusive<usize> = any_range_inclusive();
+    }
+
+    // `..=usize::MAX` cannot be turned into an exclusive range, so it is rejected before the
+    // end bound is ever incremented.
+    #[kani::proof]
+    fn check_range_inclusive_max_end() {
+        let array: [u8; INDEX_LEN] = kani::any();
+        let slice = kani::slice::any_slice_of_array(&array);
+        let start: usize = kani::any();
+        assert!(slice.get(start..=usize::MAX).is_none());
+        assert!(slice.get(..=usize::MAX).is_none());
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_range_inclusive_max_end_index() {
+        let array: [u8; INDEX_LEN] = kani::any();
+        let slice = kani::slice::any_slice_of_array(&array);
+        let _ = &slice[kani::any::<usize>()..=usize::MAX];
+    }
+
+    // An exhausted range indexes the empty slice just past its end.
+    #[kani::proof]
+    fn check_range_inclusive_exhausted() {
+        let array: [u8; INDEX_LEN] = kani::any();
+        let slice = kani::slice::any_slice_of_array(&array);
+        let end = kani::any_where(|&end: &usize| end < slice.len());
+        let mut range = kani::any_where(|&start: &usize| start <= end)..=end;
+        let _ = range.nth(end);
+        let rest = slice.get(range).unwrap();
+        assert!(rest.is_empty());
+        assert_eq!(rest.as_ptr(), slice.as_ptr().wrapping_add(end + 1));
+    }
+}
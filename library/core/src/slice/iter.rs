@@ -3,6 +3,8 @@
 #[macro_use] // import iterator! and forward_iterator!
 mod macros;
 
+use safety::{ensures, requires};
+
 use super::{from_raw_parts, from_raw_parts_mut};
 use crate::hint::assert_unchecked;
 use crate::iter::{
@@ -137,6 +139,7 @@ impl<'a, T> Iter<'a, T> {
     #[must_use]
     #[stable(feature = "iter_to_slice", since = "1.4.0")]
     #[inline]
+    #[ensures(|slice| slice.len() == len!(self) && slice.as_ptr() == self.ptr.as_ptr())]
     pub fn as_slice(&self) -> &'a [T] {
         self.make_slice()
     }
@@ -362,6 +365,7 @@ impl<'a, T> IterMut<'a, T> {
     #[must_use]
     #[stable(feature = "slice_iter_mut_as_slice", since = "1.53.0")]
     #[inline]
+    #[ensures(|slice| slice.len() == len!(self) && slice.as_ptr() == self.ptr.as_ptr())]
     pub fn as_slice(&self) -> &[T] {
         self.make_slice()
     }
@@ -1913,8 +1917,23 @@ pub struct ChunksExact<'a, T: 'a> {
     chunk_size: usize,
 }
 
+#[unstable(feature = "ub_checks", issue = "none")]
+impl<T> Invariant for ChunksExact<'_, T> {
+    /// The chunks left to yield split exactly into `chunk_size` elements, and the
+    /// remainder is shorter than a chunk.
+    fn is_safe(&self) -> bool {
+        self.chunk_size != 0
+            && self.v.len() % self.chunk_size == 0
+            && self.rem.len() < self.chunk_size
+    }
+}
+
 impl<'a, T> ChunksExact<'a, T> {
     #[inline]
+    #[requires(chunk_size != 0)]
+    #[ensures(|iter| iter.is_safe() && iter.v.len() + iter.rem.len() == slice.len())]
+    #[ensures(|iter| iter.v.as_ptr() == slice.as_ptr())]
+    #[ensures(|iter| iter.rem.as_ptr() == slice.as_ptr().wrapping_add(iter.v.len()))]
     pub(super) const fn new(slice: &'a [T], chunk_size: usize) -> Self {
         let rem = slice.len() % chunk_size;
         let fst_len = slice.len() - rem;
@@ -2388,6 +2407,8 @@ pub struct ArrayChunks<'a, T: 'a, const N: usize> {
 impl<'a, T, const N: usize> ArrayChunks<'a, T, N> {
     #[rustc_const_unstable(feature = "const_slice_make_iter", issue = "137737")]
     #[inline]
+    #[requires(N != 0)]
+    #[ensures(|chunks| chunks.rem.len() < N && chunks.iter.len() * N + chunks.rem.len() == slice.len())]
     pub(super) const fn new(slice: &'a [T]) -> Self {
         let (array_slice, rem) = slice.as_chunks();
         Self { iter: array_slice.iter(), rem }
@@ -3671,4 +3692,199 @@ mod verify {
     check_iter_with_ty!(verify_u8, u8, u32::MAX as usize);
     check_iter_with_ty!(verify_char, char, 50);
     check_iter_with_ty!(verify_tup, (char, u8), 50);
+
+    // `Iter` / `IterMut` traversal proofs
+
+    const TRAVERSE_LEN: usize = 8;
+    const TRAVERSE_STEPS: usize = 4;
+
+    /// Whether `elem` is a valid element position in the slice `start..end`.
+    fn in_bounds<T>(elem: *const T, start: *const T, end: *const T) -> bool {
+        start <= elem && elem.wrapping_add(1) <= end
+    }
+
+    /// Advances `iter` through a symbolic mix of `next`, `next_back` and `advance_by`, checking
+    /// that every element handed out and the remaining slice stay inside `start..end`.
+    macro_rules! check_traversal {
+        ($iter:ident, $start:ident, $end:ident) => {
+            for _ in 0..TRAVERSE_STEPS {
+                let item = match kani::any::<u8>() {
+                    0 => $iter.next_back(),
+                    1 => {
+                        let _ = $iter.advance_by(kani::any());
+                        None
+                    }
+                    _ => $iter.next(),
+                };
+                if let Some(item) = item {
+                    assert!(in_bounds(&*item, $start, $end));
+                }
+                let rest = $iter.as_slice();
+                assert!($start <= rest.as_ptr());
+                assert!(rest.as_ptr().wrapping_add(rest.len()) <= $end);
+            }
+        };
+    }
+
+    macro_rules! check_iter_contracts {
+        ($module:ident, $ty:ty) => {
+            mod $module {
+                use super::*;
+
+                #[kani::proof_for_contract(<Iter<'_, $ty> as Iterator>::next)]
+                fn check_iter_next() {
+                    let array: [$ty; TRAVERSE_LEN] = kani::any();
+                    let mut iter = Iter::new(kani::slice::any_slice_of_array(&array));
+                    let _ = iter.next();
+                }
+
+                #[kani::proof_for_contract(<Iter<'_, $ty> as DoubleEndedIterator>::next_back)]
+                fn check_iter_next_back() {
+                    let array: [$ty; TRAVERSE_LEN] = kani::any();
+                    let mut iter = Iter::new(kani::slice::any_slice_of_array(&array));
+                    let _ = iter.next_back();
+                }
+
+                #[kani::proof_for_contract(<Iter<'_, $ty> as Iterator>::advance_by)]
+                fn check_iter_advance_by() {
+                    let array: [$ty; TRAVERSE_LEN] = kani::any();
+                    let mut iter = Iter::new(kani::slice::any_slice_of_array(&array));
+                    let _ = iter.advance_by(kani::any());
+                }
+
+                #[kani::proof_for_contract(Iter::<'_, $ty>::as_slice)]
+                fn check_iter_as_slice() {
+                    let array: [$ty; TRAVERSE_LEN] = kani::any();
+                    let iter = Iter::new(kani::slice::any_slice_of_array(&array));
+                    let _ = iter.as_slice();
+                }
+
+                #[kani::proof_for_contract(<IterMut<'_, $ty> as Iterator>::next)]
+                fn check_iter_mut_next() {
+                    let mut array: [$ty; TRAVERSE_LEN] = kani::any();
+                    let mut iter = IterMut::new(kani::slice::any_slice_of_array_mut(&mut array));
+                    let _ = iter.next();
+                }
+
+                #[kani::proof_for_contract(<IterMut<'_, $ty> as DoubleEndedIterator>::next_back)]
+                fn check_iter_mut_next_back() {
+                    let mut array: [$ty; TRAVERSE_LEN] = kani::any();
+                    let mut iter = IterMut::new(kani::slice::any_slice_of_array_mut(&mut array));
+                    let _ = iter.next_back();
+                }
+
+                #[kani::proof_for_contract(<IterMut<'_, $ty> as Iterator>::advance_by)]
+                fn check_iter_mut_advance_by() {
+                    let mut array: [$ty; TRAVERSE_LEN] = kani::any();
+                    let mut iter = IterMut::new(kani::slice::any_slice_of_array_mut(&mut array));
+                    let _ = iter.advance_by(kani::any());
+                }
+
+                #[kani::proof_for_contract(IterMut::<'_, $ty>::as_slice)]
+                fn check_iter_mut_as_slice() {
+                    let mut array: [$ty; TRAVERSE_LEN] = kani::any();
+                    let iter = IterMut::new(kani::slice::any_slice_of_array_mut(&mut array));
+                    let _ = iter.as_slice();
+                }
+
+                #[kani::proof]
+                #[kani::unwind(5)]
+                fn check_iter_traversal() {
+                    let array: [$ty; TRAVERSE_LEN] = kani::any();
+                    let slice = kani::slice::any_slice_of_array(&array);
+                    let crate::ops::Range { start, end } = slice.as_ptr_range();
+                    let mut iter = slice.iter();
+                    check_traversal!(iter, start, end);
+                }
+
+                #[kani::proof]
+                #[kani::unwind(5)]
+                fn check_iter_mut_traversal() {
+                    let mut array: [$ty; TRAVERSE_LEN] = kani::any();
+                    let slice = kani::slice::any_slice_of_array_mut(&mut array);
+                    let crate::ops::Range { start, end } = slice.as_ptr_range();
+                    let mut iter = slice.iter_mut();
+                    check_traversal!(iter, start, end);
+                }
+            }
+        };
+    }
+
+    check_iter_contracts!(iter_contracts_unit, ());
+    check_iter_contracts!(iter_contracts_u8, u8);
+    check_iter_contracts!(iter_contracts_tup, (char, u8));
+
+    // Chunk iterator proofs
+
+    const CHUNK_LEN: usize = 12;
+
+    #[kani::proof_for_contract(ChunksExact::new)]
+    fn check_chunks_exact_new() {
+        let array: [u8; CHUNK_LEN] = kani::any();
+        let slice = any_slice(&array);
+        let _ = ChunksExact::new(slice, kani::any());
+    }
+
+    // Iterating from both ends yields back-to-back chunks of `chunk_size`
+    // elements that cover everything but the remainder, which stays the tail.
+    #[kani::proof]
+    #[kani::unwind(14)]
+    fn check_chunks_exact_cover() {
+        let array: [u8; CHUNK_LEN] = kani::any();
+        let slice = any_slice(&array);
+        let chunk_size = kani::any_where(|&n: &usize| n != 0 && n <= CHUNK_LEN);
+        let mut iter = slice.chunks_exact(chunk_size);
+        let rem = iter.remainder();
+        assert_eq!(rem.len(), slice.len() % chunk_size);
+        assert_eq!(rem.as_ptr(), slice.as_ptr().wrapping_add(slice.len() - rem.len()));
+
+        let (mut front, mut back) = (0, slice.len() - rem.len());
+        for _ in 0..=CHUNK_LEN {
+            let chunk = if kani::any() { iter.next() } else { iter.next_back() };
+            let Some(chunk) = chunk else { break };
+            assert_eq!(chunk.len(), chunk_size);
+            if chunk.as_ptr() == slice.as_ptr().wrapping_add(front) {
+                front += chunk_size;
+            } else {
+                back -= chunk_size;
+                assert_eq!(chunk.as_ptr(), slice.as_ptr().wrapping_add(back));
+            }
+            assert!(iter.is_safe());
+        }
+        assert_eq!(front, back);
+        assert_eq!(iter.remainder(), rem);
+    }
+
+    macro_rules! check_array_chunks {
+        ($($contract:ident, $cover:ident: $n:literal;)+) => {$(
+            #[kani::proof_for_contract(ArrayChunks::<u8, $n>::new)]
+            fn $contract() {
+                let array: [u8; CHUNK_LEN] = kani::any();
+                let _ = ArrayChunks::<u8, $n>::new(any_slice(&array));
+            }
+
+            // The chunks tile the slice in order and the remainder is its tail.
+            #[kani::proof]
+            #[kani::unwind(14)]
+            fn $cover() {
+                let array: [u8; CHUNK_LEN] = kani::any();
+                let slice = any_slice(&array);
+                let mut iter = slice.array_chunks::<$n>();
+                let mut offset = 0;
+                for chunk in iter.by_ref() {
+                    assert_eq!(chunk.as_ptr(), slice.as_ptr().wrapping_add(offset));
+                    offset += $n;
+                }
+                assert_eq!(iter.remainder(), &slice[offset..]);
+                assert!(iter.remainder().len() < $n);
+            }
+        )+};
+    }
+
+    check_array_chunks! {
+        check_array_chunks_new_1, check_array_chunks_cover_1: 1;
+        check_array_chunks_new_2, check_array_chunks_cover_2: 2;
+        check_array_chunks_new_3, check_array_chunks_cover_3: 3;
+        check_array_chunks_new_5, check_array_chunks_cover_5: 5;
+    }
 }
@@ -161,6 +161,13 @@ macro_rules! iterator {
         impl<'a, T> Iterator for $name<'a, T> {
             type Item = $elem;
 
+            #[cfg_attr(kani, kani::modifies(self))]
+            #[safety::ensures(|_| self.is_safe())]
+            #[safety::ensures(|_| len!(self) == old(len!(self)).saturating_sub(1))]
+            #[safety::ensures(|_| T::IS_ZST || self.end_or_len == old(self.end_or_len))]
+            #[safety::ensures(|item| item.as_ref().is_none_or(|item| {
+                crate::ptr::eq(&**item, old(self.ptr.as_ptr()))
+            }))]
             #[inline]
             fn next(&mut self) -> Option<$elem> {
                 // intentionally not using the helpers because this is
@@ -227,6 +234,11 @@ macro_rules! iterator {
                 }
             }
 
+            #[cfg_attr(kani, kani::modifies(self))]
+            #[safety::ensures(|_| self.is_safe())]
+            #[safety::ensures(|_| len!(self) == old(len!(self)).saturating_sub(n))]
+            #[safety::ensures(|_| T::IS_ZST || self.end_or_len == old(self.end_or_len))]
+            #[safety::ensures(|result| result.is_ok() == (n <= old(len!(self))))]
             #[inline]
             fn advance_by(&mut self, n: usize) -> Result<(), NonZero<usize>> {
                 let advance = cmp::min(len!(self), n);
@@ -421,6 +433,14 @@ macro_rules! iterator {
 
         #[stable(feature = "rust1", since = "1.0.0")]
         impl<'a, T> DoubleEndedIterator for $name<'a, T> {
+            #[cfg_attr(kani, kani::modifies(self))]
+            #[safety::ensures(|_| self.is_safe())]
+            #[safety::ensures(|_| len!(self) == old(len!(self)).saturating_sub(1))]
+            #[safety::ensures(|_| self.ptr == old(self.ptr))]
+            #[safety::ensures(|item| item.as_ref().is_none_or(|item| {
+                let last = old(self.ptr.as_ptr().wrapping_add(len!(self).wrapping_sub(1)));
+                crate::ptr::eq(&**item, last)
+            }))]
             #[inline]
             fn next_back(&mut self) -> Option<$elem> {
                 // could be implemented with slices, but this avoids bounds checks
@@ -159,3 +159,20 @@ pub fn memrchr(x: u8, text: &[u8]) -> Option<usize> {
     // Find the byte before the point the body loop stopped.
     text[..offset].iter().rposition(|elt| *elt == x)
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::intrinsics::check_const_eval_select_arms;
+    use crate::kani;
+
+    // `memchr_aligned` is only used for slices of at least `2 * USIZE_BYTES` bytes.
+    check_const_eval_select_arms!(
+        #[kani::unwind(25)]
+        check_memchr_aligned_arms,
+        memchr_naive,
+        memchr_aligned,
+        { x: u8 = kani::any(), text: &[u8] = &kani::any::<[u8; 3 * USIZE_BYTES]>() }
+    );
+}
@@ -328,6 +328,8 @@ impl<T> [T] {
     #[inline]
     #[stable(feature = "slice_first_last_chunk", since = "1.77.0")]
     #[rustc_const_stable(feature = "slice_first_last_chunk", since = "1.77.0")]
+    #[ensures(|chunk| chunk.is_some() == (self.len() >= N))]
+    #[ensures(|chunk| chunk.is_none_or(|chunk| chunk.as_ptr() == self.as_ptr()))]
     pub const fn first_chunk<const N: usize>(&self) -> Option<&[T; N]> {
         if self.len() < N {
             None
@@ -358,6 +360,8 @@ impl<T> [T] {
     #[inline]
     #[stable(feature = "slice_first_last_chunk", since = "1.77.0")]
     #[rustc_const_stable(feature = "const_slice_first_last_chunk", since = "1.83.0")]
+    #[ensures(|chunk| chunk.is_some() == (old(self.len()) >= N))]
+    #[ensures(|chunk| chunk.as_ref().is_none_or(|chunk| chunk.as_ptr() == old(self.as_ptr())))]
     pub const fn first_chunk_mut<const N: usize>(&mut self) -> Option<&mut [T; N]> {
         if self.len() < N {
             None
@@ -388,6 +392,10 @@ impl<T> [T] {
     #[inline]
     #[stable(feature = "slice_first_last_chunk", since = "1.77.0")]
     #[rustc_const_stable(feature = "slice_first_last_chunk", since = "1.77.0")]
+    #[ensures(|split| split.is_some() == (self.len() >= N))]
+    #[ensures(|split| split.is_none_or(|(first, tail)| first.as_ptr() == self.as_ptr()
+        && tail.as_ptr() == self.as_ptr().wrapping_add(N)
+        && tail.len() == self.len() - N))]
     pub const fn split_first_chunk<const N: usize>(&self) -> Option<(&[T; N], &[T])> {
         let Some((first, tail)) = self.split_at_checked(N) else { return None };
 
@@ -418,6 +426,10 @@ impl<T> [T] {
     #[inline]
     #[stable(feature = "slice_first_last_chunk", since = "1.77.0")]
     #[rustc_const_stable(feature = "const_slice_first_last_chunk", since = "1.83.0")]
+    #[ensures(|split| split.is_some() == (old(self.len()) >= N))]
+    #[ensures(|split| split.as_ref().is_none_or(|(first, tail)| first.as_ptr() == old(self.as_ptr())
+        && tail.as_ptr() == old(self.as_ptr()).wrapping_add(N)
+        && tail.len() == old(self.len()) - N))]
     pub const fn split_first_chunk_mut<const N: usize>(
         &mut self,
     ) -> Option<(&mut [T; N], &mut [T])> {
@@ -448,6 +460,10 @@ impl<T> [T] {
     #[inline]
     #[stable(feature = "slice_first_last_chunk", since = "1.77.0")]
     #[rustc_const_stable(feature = "slice_first_last_chunk", since = "1.77.0")]
+    #[ensures(|split| split.is_some() == (self.len() >= N))]
+    #[ensures(|split| split.is_none_or(|(init, last)| init.as_ptr() == self.as_ptr()
+        && init.len() == self.len() - N
+        && last.as_ptr() == self.as_ptr().wrapping_add(self.len() - N)))]
     pub const fn split_last_chunk<const N: usize>(&self) -> Option<(&[T], &[T; N])> {
         let Some(index) = self.len().checked_sub(N) else { return None };
         let (init, last) = self.split_at(index);
@@ -479,6 +495,10 @@ impl<T> [T] {
     #[inline]
     #[stable(feature = "slice_first_last_chunk", since = "1.77.0")]
     #[rustc_const_stable(feature = "const_slice_first_last_chunk", since = "1.83.0")]
+    #[ensures(|split| split.is_some() == (old(self.len()) >= N))]
+    #[ensures(|split| split.as_ref().is_none_or(|(init, last)| init.as_ptr() == old(self.as_ptr())
+        && init.len() == old(self.len()) - N
+        && last.as_ptr() == old(self.as_ptr()).wrapping_add(old(self.len()) - N)))]
     pub const fn split_last_chunk_mut<const N: usize>(
         &mut self,
     ) -> Option<(&mut [T], &mut [T; N])> {
@@ -510,6 +530,10 @@ impl<T> [T] {
     #[inline]
     #[stable(feature = "slice_first_last_chunk", since = "1.77.0")]
     #[rustc_const_stable(feature = "const_slice_last_chunk", since = "1.80.0")]
+    #[ensures(|chunk| chunk.is_some() == (self.len() >= N))]
+    #[ensures(|chunk| chunk.is_none_or(|chunk| {
+        chunk.as_ptr() == self.as_ptr().wrapping_add(self.len() - N)
+    }))]
     pub const fn last_chunk<const N: usize>(&self) -> Option<&[T; N]> {
         // FIXME(const-hack): Without const traits, we need this instead of `get`.
         let Some(index) = self.len().checked_sub(N) else { return None };
@@ -540,6 +564,10 @@ impl<T> [T] {
     #[inline]
     #[stable(feature = "slice_first_last_chunk", since = "1.77.0")]
     #[rustc_const_stable(feature = "const_slice_first_last_chunk", since = "1.83.0")]
+    #[ensures(|chunk| chunk.is_some() == (old(self.len()) >= N))]
+    #[ensures(|chunk| chunk.as_ref().is_none_or(|chunk| {
+        chunk.as_ptr() == old(self.as_ptr()).wrapping_add(old(self.len()) - N)
+    }))]
     pub const fn last_chunk_mut<const N: usize>(&mut self) -> Option<&mut [T; N]> {
         // FIXME(const-hack): Without const traits, we need this instead of `get`.
         let Some(index) = self.len().checked_sub(N) else { return None };
@@ -1008,6 +1036,7 @@ impl<T> [T] {
             let (b, _) = b.split_at_mut(n);
 
             let mut i = 0;
+            #[safety::loop_invariant(i <= n)]
             while i < n {
                 mem::swap(&mut a[i], &mut b[n - 1 - i]);
                 i += 1;
@@ -1332,6 +1361,9 @@ impl<T> [T] {
     #[inline]
     #[must_use]
     #[track_caller]
+    #[requires(N != 0 && self.len() % N == 0)]
+    #[ensures(|chunks| chunks.len() * N == self.len())]
+    #[ensures(|chunks| chunks.as_ptr().cast::<T>() == self.as_ptr())]
     pub const unsafe fn as_chunks_unchecked<const N: usize>(&self) -> &[[T; N]] {
         assert_unsafe_precondition!(
             check_language_ub,
@@ -1390,6 +1422,9 @@ impl<T> [T] {
     #[inline]
     #[track_caller]
     #[must_use]
+    #[ensures(|(chunks, rem)| chunks.len() == self.len() / N && rem.len() == self.len() % N)]
+    #[ensures(|(chunks, _)| chunks.as_ptr().cast::<T>() == self.as_ptr())]
+    #[ensures(|(chunks, rem)| rem.as_ptr() == self.as_ptr().wrapping_add(chunks.len() * N))]
     pub const fn as_chunks<const N: usize>(&self) -> (&[[T; N]], &[T]) {
         assert!(N != 0, "chunk size must be non-zero");
         let len_rounded_down = self.len() / N * N;
@@ -1528,6 +1563,9 @@ impl<T> [T] {
     #[inline]
     #[must_use]
     #[track_caller]
+    #[requires(N != 0 && self.len() % N == 0)]
+    #[ensures(|chunks| chunks.len() * N == old(self.len()))]
+    #[ensures(|chunks| chunks.as_ptr().cast::<T>() == old(self.as_ptr()))]
     pub const unsafe fn as_chunks_unchecked_mut<const N: usize>(&mut self) -> &mut [[T; N]] {
         assert_unsafe_precondition!(
             check_language_ub,
@@ -4550,6 +4588,7 @@ impl<T> [T] {
     /// ```
     #[stable(feature = "partition_point", since = "1.52.0")]
     #[must_use]
+    #[ensures(|&i| i <= self.len())]
     pub fn partition_point<P>(&self, mut pred: P) -> usize
     where
         P: FnMut(&T) -> bool,
@@ -4838,6 +4877,9 @@ impl<T> [T] {
     #[stable(feature = "get_many_mut", since = "1.86.0")]
     #[inline]
     #[track_caller]
+    #[requires(get_disjoint_check_valid(&indices, self.len()).is_ok())]
+    #[cfg_attr(kani, ensures(|refs| are_disjoint(refs)))]
+    #[cfg_attr(kani, ensures(|refs| aliases(refs, old(index_ptrs(ptr::from_mut(self), &indices)))))]
     pub unsafe fn get_disjoint_unchecked_mut<I, const N: usize>(
         &mut self,
         indices: [I; N],
@@ -4905,6 +4947,9 @@ impl<T> [T] {
     /// ```
     #[stable(feature = "get_many_mut", since = "1.86.0")]
     #[inline]
+    #[cfg_attr(kani, ensures(|result| {
+        result.as_ref().is_ok_and(|refs| are_disjoint(refs)) || result.is_err()
+    }))]
     pub fn get_disjoint_mut<I, const N: usize>(
         &mut self,
         indices: [I; N],
@@ -5316,6 +5361,10 @@ impl<T, const N: usize> SlicePattern for [T; N] {
 /// This will do `binomial(N + 1, 2) = N * (N + 1) / 2 = 0, 1, 3, 6, 10, ..`
 /// comparison operations.
 #[inline]
+#[ensures(|result| result.is_ok() == (
+    indices.iter().all(|idx| idx.is_in_bounds(len))
+        && (0..N).all(|i| (0..i).all(|j| !indices[i].is_overlapping(&indices[j])))
+))]
 fn get_disjoint_check_valid<I: GetDisjointMutIndex, const N: usize>(
     indices: &[I; N],
     len: usize,
@@ -5335,6 +5384,33 @@ fn get_disjoint_check_valid<I: GetDisjointMutIndex, const N: usize>(
     Ok(())
 }
 
+/// Returns `true` if no two of the references overlap in memory.
+#[cfg(kani)]
+fn are_disjoint<T: ?Sized>(refs: &[&mut T]) -> bool {
+    (0..refs.len()).all(|i| {
+        (0..i).all(|j| {
+            let (a, b) = (ptr::from_ref::<T>(refs[i]).addr(), ptr::from_ref::<T>(refs[j]).addr());
+            let (a_end, b_end) = (a + mem::size_of_val(refs[i]), b + mem::size_of_val(refs[j]));
+            a_end <= b || b_end <= a
+        })
+    })
+}
+
+/// Returns the place each of `indices` selects in `slice`.
+#[cfg(kani)]
+fn index_ptrs<T, I: SliceIndex<[T]> + Clone, const N: usize>(
+    slice: *mut [T],
+    indices: &[I; N],
+) -> [*mut I::Output; N] {
+    crate::array::from_fn(|i| unsafe { slice.get_unchecked_mut(indices[i].clone()) })
+}
+
+/// Returns `true` if each reference points to exactly the matching place in `ptrs`.
+#[cfg(kani)]
+fn aliases<T: ?Sized, const N: usize>(refs: &[&mut T; N], ptrs: [*mut T; N]) -> bool {
+    (0..N).all(|i| ptr::eq(&*refs[i], ptrs[i]))
+}
+
 /// The error type returned by [`get_disjoint_mut`][`slice::get_disjoint_mut`].
 ///
 /// It indicates one of two possible errors:
@@ -5485,6 +5561,7 @@ unsafe impl GetDisjointMutIndex for range::RangeInclusive<usize> {
 #[unstable(feature = "kani", issue = "none")]
 mod verify {
     use super::*;
+    use crate::cell::Cell;
 
     //generates proof_of_contract harness for align_to given the T (src) and U (dst) types
     macro_rules! proof_of_contract_for_align_to {
@@ -5565,4 +5642,404 @@ mod verify {
     gen_align_to_mut_harnesses!(align_to_mut_from_bool, bool);
     gen_align_to_mut_harnesses!(align_to_mut_from_char, char);
     gen_align_to_mut_harnesses!(align_to_mut_from_unit, ());
+
+    // `get_disjoint_mut` proofs
+
+    const DISJOINT_LEN: usize = 8;
+
+    trait AnyIndex: Sized {
+        fn any_index() -> Self;
+    }
+
+    impl AnyIndex for usize {
+        fn any_index() -> Self {
+            kani::any_where(|&i: &usize| i <= DISJOINT_LEN)
+        }
+    }
+
+    impl AnyIndex for Range<usize> {
+        fn any_index() -> Self {
+            usize::any_index()..usize::any_index()
+        }
+    }
+
+    impl AnyIndex for RangeInclusive<usize> {
+        fn any_index() -> Self {
+            usize::any_index()..=usize::any_index()
+        }
+    }
+
+    macro_rules! check_get_disjoint_mut {
+        ($($name:ident, $check:ident: $idx:ty, $n:literal;)+) => {$(
+            #[kani::proof_for_contract(<[u8]>::get_disjoint_mut)]
+            #[kani::unwind(9)]
+            fn $name() {
+                let mut arr: [u8; DISJOINT_LEN] = kani::any();
+                let slice = kani::slice::any_slice_of_array_mut(&mut arr);
+                let indices: [$idx; $n] = core::array::from_fn(|_| <$idx>::any_index());
+                let _ = slice.get_disjoint_mut(indices);
+            }
+
+            #[kani::proof_for_contract(get_disjoint_check_valid)]
+            #[kani::unwind(9)]
+            fn $check() {
+                let indices: [$idx; $n] = core::array::from_fn(|_| <$idx>::any_index());
+                let _ = get_disjoint_check_valid(&indices, kani::any_where(|&len| len <= DISJOINT_LEN));
+            }
+        )+};
+    }
+
+    check_get_disjoint_mut! {
+        check_get_disjoint_mut_usize_2, check_disjoint_valid_usize_2: usize, 2;
+        check_get_disjoint_mut_usize_3, check_disjoint_valid_usize_3: usize, 3;
+        check_get_disjoint_mut_range_2, check_disjoint_valid_range_2: Range<usize>, 2;
+        check_get_disjoint_mut_range_3, check_disjoint_valid_range_3: Range<usize>, 3;
+        check_get_disjoint_mut_range_inclusive_2, check_disjoint_valid_range_inclusive_2:
+            RangeInclusive<usize>, 2;
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_get_disjoint_mut_duplicate_index() {
+        let mut arr: [u8; DISJOINT_LEN] = kani::any();
+        let i = kani::any_where(|&i: &usize| i < DISJOINT_LEN);
+        let _ = arr.get_disjoint_mut([i, i]).unwrap();
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_get_disjoint_mut_overlapping_ranges() {
+        let mut arr: [u8; DISJOINT_LEN] = kani::any();
+        let a = Range::<usize>::any_index();
+        let b = Range::<usize>::any_index();
+        kani::assume(a.start < b.end && b.start < a.end);
+        let _ = arr.get_disjoint_mut([a, b]).unwrap();
+    }
+
+    // `as_chunks` proofs
+
+    const CHUNKS_LEN: usize = 12;
+
+    macro_rules! check_as_chunks {
+        ($($module:ident: $n:literal;)+) => {$(
+            mod $module {
+                use super::*;
+
+                #[kani::proof_for_contract(<[u8]>::as_chunks_unchecked)]
+                fn check_as_chunks_unchecked() {
+                    let array: [u8; CHUNKS_LEN] = kani::any();
+                    let slice = kani::slice::any_slice_of_array(&array);
+                    let _ = unsafe { slice.as_chunks_unchecked::<$n>() };
+                }
+
+                #[kani::proof_for_contract(<[u8]>::as_chunks_unchecked_mut)]
+                fn check_as_chunks_unchecked_mut() {
+                    let mut array: [u8; CHUNKS_LEN] = kani::any();
+                    let slice = kani::slice::any_slice_of_array_mut(&mut array);
+                    let _ = unsafe { slice.as_chunks_unchecked_mut::<$n>() };
+                }
+
+                #[kani::proof_for_contract(<[u8]>::as_chunks)]
+                fn check_as_chunks() {
+                    let array: [u8; CHUNKS_LEN] = kani::any();
+                    let slice = kani::slice::any_slice_of_array(&array);
+                    let _ = slice.as_chunks::<$n>();
+                }
+            }
+        )+};
+    }
+
+    check_as_chunks! {
+        as_chunks_1: 1;
+        as_chunks_2: 2;
+        as_chunks_3: 3;
+        as_chunks_5: 5;
+    }
+
+    // `get_disjoint_unchecked_mut` proofs
+
+    macro_rules! check_get_disjoint_unchecked_mut {
+        ($($name:ident: $idx:ty, $n:literal;)+) => {$(
+            #[kani::proof_for_contract(<[u8]>::get_disjoint_unchecked_mut)]
+            #[kani::unwind(9)]
+            fn $name() {
+                let mut arr: [u8; DISJOINT_LEN] = kani::any();
+                let slice = kani::slice::any_slice_of_array_mut(&mut arr);
+                let indices: [$idx; $n] = core::array::from_fn(|_| <$idx>::any_index());
+                let _ = unsafe { slice.get_disjoint_unchecked_mut(indices) };
+            }
+        )+};
+    }
+
+    check_get_disjoint_unchecked_mut! {
+        check_get_disjoint_unchecked_mut_usize_2: usize, 2;
+        check_get_disjoint_unchecked_mut_usize_3: usize, 3;
+        check_get_disjoint_unchecked_mut_range_2: Range<usize>, 2;
+        check_get_disjoint_unchecked_mut_range_inclusive_2: RangeInclusive<usize>, 2;
+    }
+
+    // Writing through one returned reference never shows up in the others.
+    #[kani::proof]
+    #[kani::unwind(9)]
+    #[kani::stub_verified(<[u8]>::get_disjoint_unchecked_mut)]
+    fn check_get_disjoint_unchecked_mut_writes() {
+        let mut arr: [u8; DISJOINT_LEN] = kani::any();
+        let (i, j) = (usize::any_index(), usize::any_index());
+        kani::assume(i != j && i < DISJOINT_LEN && j < DISJOINT_LEN);
+        let [a, b] = unsafe { arr.get_disjoint_unchecked_mut([i, j]) };
+        let before = *b;
+        *a = a.wrapping_add(1);
+        assert_eq!(*b, before);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(9)]
+    #[kani::should_panic]
+    #[kani::stub_verified(<[u8]>::get_disjoint_unchecked_mut)]
+    fn check_get_disjoint_unchecked_mut_overlapping() {
+        let mut arr: [u8; DISJOINT_LEN] = kani::any();
+        let a = Range::<usize>::any_index();
+        let b = Range::<usize>::any_index();
+        kani::assume(a.start < b.end && b.start < a.end);
+        let _ = unsafe { arr.get_disjoint_unchecked_mut([a, b]) };
+    }
+
+    #[kani::proof]
+    #[kani::unwind(9)]
+    #[kani::should_panic]
+    #[kani::stub_verified(<[u8]>::get_disjoint_unchecked_mut)]
+    fn check_get_disjoint_unchecked_mut_out_of_bounds() {
+        let mut arr: [u8; DISJOINT_LEN] = kani::any();
+        let i = kani::any_where(|&i: &usize| i >= DISJOINT_LEN);
+        let _ = unsafe { arr.get_disjoint_unchecked_mut([i]) };
+    }
+
+    // `first_chunk` / `last_chunk` proofs
+
+    const FIRST_LAST_LEN: usize = 8;
+
+    macro_rules! check_first_last_chunk {
+        ($($module:ident: $n:literal;)+) => {$(
+            mod $module {
+                use super::*;
+
+                #[kani::proof_for_contract(<[u8]>::first_chunk)]
+                fn check_first_chunk() {
+                    let array: [u8; FIRST_LAST_LEN] = kani::any();
+                    let slice = kani::slice::any_slice_of_array(&array);
+                    let _ = slice.first_chunk::<$n>();
+                }
+
+                #[kani::proof_for_contract(<[u8]>::first_chunk_mut)]
+                fn check_first_chunk_mut() {
+                    let mut array: [u8; FIRST_LAST_LEN] = kani::any();
+                    let slice = kani::slice::any_slice_of_array_mut(&mut array);
+                    let _ = slice.first_chunk_mut::<$n>();
+                }
+
+                #[kani::proof_for_contract(<[u8]>::split_first_chunk)]
+                fn check_split_first_chunk() {
+                    let array: [u8; FIRST_LAST_LEN] = kani::any();
+                    let slice = kani::slice::any_slice_of_array(&array);
+                    let _ = slice.split_first_chunk::<$n>();
+                }
+
+                #[kani::proof_for_contract(<[u8]>::split_first_chunk_mut)]
+                fn check_split_first_chunk_mut() {
+                    let mut array: [u8; FIRST_LAST_LEN] = kani::any();
+                    let slice = kani::slice::any_slice_of_array_mut(&mut array);
+                    let _ = slice.split_first_chunk_mut::<$n>();
+                }
+
+                #[kani::proof_for_contract(<[u8]>::split_last_chunk)]
+                fn check_split_last_chunk() {
+                    let array: [u8; FIRST_LAST_LEN] = kani::any();
+                    let slice = kani::slice::any_slice_of_array(&array);
+                    let _ = slice.split_last_chunk::<$n>();
+                }
+
+                #[kani::proof_for_contract(<[u8]>::split_last_chunk_mut)]
+                fn check_split_last_chunk_mut() {
+                    let mut array: [u8; FIRST_LAST_LEN] = kani::any();
+                    let slice = kani::slice::any_slice_of_array_mut(&mut array);
+                    let _ = slice.split_last_chunk_mut::<$n>();
+                }
+
+                #[kani::proof_for_contract(<[u8]>::last_chunk)]
+                fn check_last_chunk() {
+                    let array: [u8; FIRST_LAST_LEN] = kani::any();
+                    let slice = kani::slice::any_slice_of_array(&array);
+                    let _ = slice.last_chunk::<$n>();
+                }
+
+                #[kani::proof_for_contract(<[u8]>::last_chunk_mut)]
+                fn check_last_chunk_mut() {
+                    let mut array: [u8; FIRST_LAST_LEN] = kani::any();
+                    let slice = kani::slice::any_slice_of_array_mut(&mut array);
+                    let _ = slice.last_chunk_mut::<$n>();
+                }
+
+                // The chunk and the rest of the slice partition it in order.
+                #[kani::proof]
+                fn check_split_chunks_elements() {
+                    let array: [u8; FIRST_LAST_LEN] = kani::any();
+                    let slice = kani::slice::any_slice_of_array(&array);
+                    if let Some((first, tail)) = slice.split_first_chunk::<$n>() {
+                        assert_eq!(first[..], slice[..$n]);
+                        assert_eq!(tail, &slice[$n..]);
+                    }
+                    if let Some((init, last)) = slice.split_last_chunk::<$n>() {
+                        assert_eq!(init, &slice[..slice.len() - $n]);
+                        assert_eq!(last[..], slice[slice.len() - $n..]);
+                    }
+                }
+            }
+        )+};
+    }
+
+    check_first_last_chunk! {
+        first_last_chunk_0: 0;
+        first_last_chunk_1: 1;
+        first_last_chunk_2: 2;
+        first_last_chunk_3: 3;
+        first_last_chunk_5: 5;
+    }
+
+    // `partition_point` proofs
+
+    const PARTITION_POINT_LEN: usize = 8;
+
+    fn table_pred<'a>(slice: &'a [u8], table: &'a [bool]) -> impl FnMut(&u8) -> bool + 'a {
+        move |x| table[(x as *const u8).addr() - slice.as_ptr().addr()]
+    }
+
+    #[kani::proof_for_contract(<[u8]>::partition_point)]
+    #[kani::unwind(9)]
+    fn check_partition_point() {
+        let array: [u8; PARTITION_POINT_LEN] = kani::any();
+        let table: [bool; PARTITION_POINT_LEN] = kani::any();
+        let slice = kani::slice::any_slice_of_array(&array);
+        let _ = slice.partition_point(table_pred(slice, &table));
+    }
+
+    // On a partitioned input every element before the returned index satisfies `pred` and none
+    // after it does.
+    #[kani::proof]
+    #[kani::unwind(9)]
+    fn check_partition_point_partitioned() {
+        let array: [u8; PARTITION_POINT_LEN] = kani::any();
+        let table: [bool; PARTITION_POINT_LEN] = kani::any();
+        let slice = kani::slice::any_slice_of_array(&array);
+        let table = &table[..slice.len()];
+        kani::assume(table.is_sorted_by(|a, b| a >= b));
+        let i = slice.partition_point(table_pred(slice, table));
+        assert!(table[..i].iter().all(|&p| p));
+        assert!(table[i..].iter().all(|&p| !p));
+    }
+
+    // Without a partitioned input the result is unspecified: still in bounds, but it need not
+    // split the slice.
+    #[kani::proof]
+    #[kani::unwind(9)]
+    #[kani::should_panic]
+    fn check_partition_point_unpartitioned() {
+        let array: [u8; PARTITION_POINT_LEN] = kani::any();
+        let table: [bool; PARTITION_POINT_LEN] = kani::any();
+        let slice = kani::slice::any_slice_of_array(&array);
+        let table = &table[..slice.len()];
+        let i = slice.partition_point(table_pred(slice, table));
+        assert!(i <= slice.len());
+        assert!(table[..i].iter().all(|&p| p));
+        assert!(table[i..].iter().all(|&p| !p));
+    }
+
+    // `reverse`, `fill` and `fill_with` proofs
+    // Their results are stated in terms of element values, which the generic signatures cannot
+    // compare, so these are checked on concrete element types instead of through contracts.
+
+    const FILL_LEN: usize = 8;
+
+    #[kani::proof]
+    #[kani::unwind(9)]
+    fn check_reverse() {
+        let mut array: [u8; FILL_LEN] = kani::any();
+        let original = array;
+        let len = kani::any_where(|&len: &usize| len <= FILL_LEN);
+        array[..len].reverse();
+        for i in 0..len {
+            assert_eq!(array[i], original[len - 1 - i]);
+        }
+        assert_eq!(array[len..], original[len..]);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_reverse_wide() {
+        let mut array: [[u64; 3]; 4] = kani::any();
+        let original = array;
+        array.reverse();
+        array.reverse();
+        assert_eq!(array, original);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(9)]
+    fn check_fill() {
+        let mut array: [u8; FILL_LEN] = kani::any();
+        let slice = kani::slice::any_slice_of_array_mut(&mut array);
+        let value: u8 = kani::any();
+        slice.fill(value);
+        assert!(slice.iter().all(|&x| x == value));
+    }
+
+    #[kani::proof]
+    #[kani::unwind(9)]
+    fn check_fill_with() {
+        let mut array: [usize; FILL_LEN] = kani::any();
+        let slice = kani::slice::any_slice_of_array_mut(&mut array);
+        let mut next = 0;
+        slice.fill_with(|| {
+            next += 1;
+            next
+        });
+        assert_eq!(next, slice.len());
+        assert!(slice.iter().enumerate().all(|(i, &x)| x == i + 1));
+    }
+
+    struct Counted<'a> {
+        clones: &'a Cell<usize>,
+        drops: &'a Cell<usize>,
+    }
+
+    impl Clone for Counted<'_> {
+        fn clone(&self) -> Self {
+            self.clones.set(self.clones.get() + 1);
+            Counted { clones: self.clones, drops: self.drops }
+        }
+    }
+
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    // Every overwritten element is dropped exactly once and `value` itself is moved into the last
+    // slot, so it is cloned one time fewer than the length and only dropped if the slice is empty.
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_fill_drops() {
+        let (old_clones, old_drops) = (Cell::new(0), Cell::new(0));
+        let (clones, drops) = (Cell::new(0), Cell::new(0));
+        let mut array: [Counted<'_>; 4] =
+            crate::array::from_fn(|_| Counted { clones: &old_clones, drops: &old_drops });
+        let len = kani::any_where(|&len: &usize| len <= 4);
+        array[..len].fill(Counted { clones: &clones, drops: &drops });
+        assert_eq!(old_drops.get(), len);
+        assert_eq!(clones.get(), len.saturating_sub(1));
+        assert_eq!(drops.get(), if len == 0 { 1 } else { 0 });
+        drop(array);
+        assert_eq!(old_drops.get(), 4);
+        assert_eq!(drops.get(), len.max(1));
+    }
 }
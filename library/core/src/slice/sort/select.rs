@@ -6,7 +6,11 @@
 //! for pivot selection. Using this as a fallback ensures O(n) worst case running time with
 //! better performance than one would get using heapsort as fallback.
 
+use safety::{ensures, requires};
+
 use crate::cfg_select;
+#[cfg(kani)]
+use crate::kani;
 use crate::mem::{self, SizedTypeProperties};
 #[cfg(not(feature = "optimize_for_size"))]
 use crate::slice::sort::shared::pivot::choose_pivot;
@@ -14,6 +18,7 @@ use crate::slice::sort::shared::smallsort::insertion_sort_shift_left;
 use crate::slice::sort::unstable::quicksort::partition;
 
 /// Reorders the slice such that the element at `index` is at its final sorted position.
+#[ensures(|(left, _, right)| left.len() == index && right.len() == old(v.len()) - index - 1)]
 pub(crate) fn partition_at_index<T, F>(
     v: &mut [T],
     index: usize,
@@ -146,6 +151,8 @@ fn partition_at_index_loop<'a, T, F>(
 
 /// Helper function that returns the index of the minimum element in the slice using the given
 /// comparator function
+#[ensures(|result| result.is_some() == !slice.is_empty())]
+#[ensures(|result| result.is_none_or(|i| i < slice.len()))]
 fn min_index<T, F: FnMut(&T, &T) -> bool>(slice: &[T], is_less: &mut F) -> Option<usize> {
     slice
         .iter()
@@ -156,6 +163,8 @@ fn min_index<T, F: FnMut(&T, &T) -> bool>(slice: &[T], is_less: &mut F) -> Optio
 
 /// Helper function that returns the index of the maximum element in the slice using the given
 /// comparator function
+#[ensures(|result| result.is_some() == !slice.is_empty())]
+#[ensures(|result| result.is_none_or(|i| i < slice.len()))]
 fn max_index<T, F: FnMut(&T, &T) -> bool>(slice: &[T], is_less: &mut F) -> Option<usize> {
     slice
         .iter()
@@ -166,6 +175,8 @@ fn max_index<T, F: FnMut(&T, &T) -> bool>(slice: &[T], is_less: &mut F) -> Optio
 
 /// Selection algorithm to select the k-th element from the slice in guaranteed O(n) time.
 /// This is essentially a quickselect that uses Tukey's Ninther for pivot selection
+#[requires(k < v.len())]
+#[cfg_attr(kani, kani::modifies(v))]
 fn median_of_medians<T, F: FnMut(&T, &T) -> bool>(mut v: &mut [T], is_less: &mut F, mut k: usize) {
     // Since this function isn't public, it should never be called with an out-of-bounds index.
     debug_assert!(k < v.len());
@@ -247,6 +258,8 @@ fn median_of_ninthers<T, F: FnMut(&T, &T) -> bool>(v: &mut [T], is_less: &mut F)
 /// Moves around the 9 elements at the indices a..i, such that
 /// `v[d]` contains the median of the 9 elements and the other
 /// elements are partitioned around it.
+#[requires([a, b, c, d, e, f, g, h, i].iter().all(|&idx| idx < v.len()))]
+#[cfg_attr(kani, kani::modifies(v))]
 fn ninther<T, F: FnMut(&T, &T) -> bool>(
     v: &mut [T],
     is_less: &mut F,
@@ -291,6 +304,8 @@ fn ninther<T, F: FnMut(&T, &T) -> bool>(
 
 /// returns the index pointing to the median of the 3
 /// elements `v[a]`, `v[b]` and `v[c]`
+#[requires(a < v.len() && b < v.len() && c < v.len())]
+#[ensures(|&m| m == a || m == b || m == c)]
 fn median_idx<T, F: FnMut(&T, &T) -> bool>(
     v: &[T],
     is_less: &mut F,
@@ -309,3 +324,105 @@ fn median_idx<T, F: FnMut(&T, &T) -> bool>(
     }
     b
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // `select_nth_unstable` proofs
+
+    const SELECT_LEN: usize = 8;
+
+    fn is_less(a: &u8, b: &u8) -> bool {
+        a < b
+    }
+
+    fn any_index(len: usize) -> usize {
+        kani::any_where(|&i: &usize| i < len)
+    }
+
+    #[kani::proof_for_contract(partition_at_index)]
+    #[kani::unwind(9)]
+    fn check_partition_at_index() {
+        let mut array: [u8; SELECT_LEN] = kani::any();
+        let v = kani::slice::any_slice_of_array_mut(&mut array);
+        let index = any_index(v.len());
+        let _ = partition_at_index(v, index, is_less);
+    }
+
+    #[kani::proof_for_contract(min_index)]
+    #[kani::unwind(9)]
+    fn check_min_index() {
+        let array: [u8; SELECT_LEN] = kani::any();
+        let _ = min_index(kani::slice::any_slice_of_array(&array), &mut is_less);
+    }
+
+    #[kani::proof_for_contract(max_index)]
+    #[kani::unwind(9)]
+    fn check_max_index() {
+        let array: [u8; SELECT_LEN] = kani::any();
+        let _ = max_index(kani::slice::any_slice_of_array(&array), &mut is_less);
+    }
+
+    #[kani::proof_for_contract(median_idx)]
+    fn check_median_idx() {
+        let array: [u8; SELECT_LEN] = kani::any();
+        let v = kani::slice::any_slice_of_array(&array);
+        let (a, b, c) = (any_index(v.len()), any_index(v.len()), any_index(v.len()));
+        let _ = median_idx(v, &mut is_less, a, b, c);
+    }
+
+    #[kani::proof_for_contract(median_of_medians)]
+    #[kani::unwind(9)]
+    fn check_median_of_medians() {
+        let mut array: [u8; SELECT_LEN] = kani::any();
+        let v = kani::slice::any_slice_of_array_mut(&mut array);
+        let k = any_index(v.len());
+        median_of_medians(v, &mut is_less, k);
+    }
+
+    #[kani::proof_for_contract(ninther)]
+    fn check_ninther() {
+        let mut v: [u8; 9] = kani::any();
+        ninther(&mut v, &mut is_less, 0, 1, 2, 3, 4, 5, 6, 7, 8);
+    }
+
+    // `v[4]` ends up holding the median of the nine elements.
+    #[kani::proof]
+    fn check_ninther_median() {
+        let mut v: [u8; 9] = kani::any();
+        ninther(&mut v, &mut is_less, 0, 1, 2, 3, 4, 5, 6, 7, 8);
+        let m = v[4];
+        assert!(v.iter().filter(|&&x| x < m).count() <= 4);
+        assert!(v.iter().filter(|&&x| x > m).count() <= 4);
+    }
+
+    // The selected element is the one sorting would place at `index`: at most `index` elements
+    // are smaller and at most `len - index - 1` are greater, and the two sides are partitioned
+    // around it.
+    #[kani::proof]
+    #[kani::unwind(9)]
+    fn check_select_nth_unstable() {
+        let mut array: [u8; SELECT_LEN] = kani::any();
+        let original = array;
+        let len: usize = kani::any_where(|&len: &usize| 0 < len && len <= SELECT_LEN);
+        let index = any_index(len);
+        let (left, nth, right) = array[..len].select_nth_unstable(index);
+        let nth = *nth;
+        assert!(left.iter().all(|&x| x <= nth));
+        assert!(right.iter().all(|&x| x >= nth));
+        assert!(original[..len].iter().filter(|&&x| x < nth).count() <= index);
+        assert!(original[..len].iter().filter(|&&x| x > nth).count() < len - index);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(9)]
+    #[kani::should_panic]
+    fn check_select_nth_unstable_out_of_bounds() {
+        let mut array: [u8; SELECT_LEN] = kani::any();
+        let v = kani::slice::any_slice_of_array_mut(&mut array);
+        let index = kani::any_where(|&i: &usize| i >= v.len());
+        let _ = v.select_nth_unstable(index);
+    }
+}
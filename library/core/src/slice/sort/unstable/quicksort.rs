@@ -1,5 +1,9 @@
 //! This module contains an unstable quicksort and two partition implementations.
 
+use safety::{ensures, requires};
+
+#[cfg(kani)]
+use crate::kani;
 #[cfg(not(feature = "optimize_for_size"))]
 use crate::mem;
 use crate::mem::ManuallyDrop;
@@ -91,6 +95,9 @@ pub(crate) fn quicksort<'a, T, F>(
 /// unspecified. All original elements will remain in `v` and any possible modifications via
 /// interior mutability will be observable. Same is true if `is_less` panics or `v.len()`
 /// exceeds `scratch.len()`.
+#[requires(v.is_empty() || pivot < v.len())]
+#[ensures(|&num_lt| num_lt == 0 || num_lt < old(v.len()))]
+#[cfg_attr(kani, kani::modifies(v))]
 pub(crate) fn partition<T, F>(v: &mut [T], pivot: usize, is_less: &mut F) -> usize
 where
     F: FnMut(&T, &T) -> bool,
@@ -392,3 +399,37 @@ impl<T> Drop for GapGuardRaw<T> {
         }
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // `partition` proofs
+
+    const PARTITION_LEN: usize = 8;
+
+    #[kani::proof_for_contract(partition)]
+    #[kani::unwind(9)]
+    fn check_partition() {
+        let mut array: [u8; PARTITION_LEN] = kani::any();
+        let v = kani::slice::any_slice_of_array_mut(&mut array);
+        let pivot = kani::any_where(|&pivot: &usize| pivot < v.len());
+        partition(v, pivot, &mut |a: &u8, b: &u8| a < b);
+    }
+
+    // The pivot ends up at `num_lt`, with every smaller element before it and
+    // every other element after it.
+    #[kani::proof]
+    #[kani::unwind(9)]
+    fn check_partition_splits_around_pivot() {
+        let mut array: [u8; PARTITION_LEN] = kani::any();
+        let v = kani::slice::any_slice_of_array_mut(&mut array);
+        let pivot = kani::any_where(|&pivot: &usize| pivot < v.len());
+        let pivot_value = v[pivot];
+        let num_lt = partition(v, pivot, &mut |a: &u8, b: &u8| a < b);
+        assert_eq!(v[num_lt], pivot_value);
+        assert!(v[..num_lt].iter().all(|&x| x < pivot_value));
+        assert!(v[num_lt + 1..].iter().all(|&x| x >= pivot_value));
+    }
+}
@@ -1,5 +1,7 @@
 //! Defines utf8 error type.
 
+use safety::invariant;
+
 use crate::error::Error;
 use crate::fmt;
 
@@ -44,6 +46,7 @@ use crate::fmt;
 /// ```
 #[derive(Copy, Eq, PartialEq, Clone, Debug)]
 #[stable(feature = "rust1", since = "1.0.0")]
+#[invariant(matches!(self.error_len, None | Some(1..=3)))]
 pub struct Utf8Error {
     pub(super) valid_up_to: usize,
     pub(super) error_len: Option<u8>,
@@ -1,5 +1,7 @@
 //! Iterators for `str` methods.
 
+use safety::ensures;
+
 use super::pattern::{DoubleEndedSearcher, Pattern, ReverseSearcher, Searcher};
 use super::validations::{next_code_point, next_code_point_reverse};
 use super::{
@@ -11,9 +13,12 @@ use crate::iter::{
     Chain, Copied, Filter, FlatMap, Flatten, FusedIterator, Map, TrustedLen, TrustedRandomAccess,
     TrustedRandomAccessNoCoerce,
 };
+#[cfg(kani)]
+use crate::kani;
 use crate::num::NonZero;
 use crate::ops::Try;
 use crate::slice::{self, Split as SliceSplit};
+use crate::ub_checks::Invariant;
 use crate::{char as char_mod, option};
 
 /// An iterator over the [`char`]s of a string slice.
@@ -31,10 +36,26 @@ pub struct Chars<'a> {
     pub(super) iter: slice::Iter<'a, u8>,
 }
 
+#[unstable(feature = "ub_checks", issue = "none")]
+impl Invariant for Chars<'_> {
+    /// The remaining bytes must be valid UTF-8, which is what lets `next_code_point` read
+    /// continuation bytes without checking for them.
+    fn is_safe(&self) -> bool {
+        super::from_utf8(self.iter.as_slice()).is_ok()
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<'a> Iterator for Chars<'a> {
     type Item = char;
 
+    #[cfg_attr(kani, kani::modifies(self))]
+    #[ensures(|_| self.is_safe())]
+    #[ensures(|ch| ch.is_none_or(|ch| {
+        let bytes = old(self.iter.as_slice());
+        ch.encode_utf8(&mut [0; 4]).as_bytes() == &bytes[..ch.len_utf8()]
+            && self.iter.as_slice() == &bytes[ch.len_utf8()..]
+    }))]
     #[inline]
     fn next(&mut self) -> Option<char> {
         // SAFETY: `str` invariant says `self.iter` is a valid UTF-8 string and
@@ -124,6 +145,14 @@ impl fmt::Debug for Chars<'_> {
 
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<'a> DoubleEndedIterator for Chars<'a> {
+    #[cfg_attr(kani, kani::modifies(self))]
+    #[ensures(|_| self.is_safe())]
+    #[ensures(|ch| ch.is_none_or(|ch| {
+        let bytes = old(self.iter.as_slice());
+        let rest = bytes.len() - ch.len_utf8();
+        ch.encode_utf8(&mut [0; 4]).as_bytes() == &bytes[rest..]
+            && self.iter.as_slice() == &bytes[..rest]
+    }))]
     #[inline]
     fn next_back(&mut self) -> Option<char> {
         // SAFETY: `str` invariant says `self.iter` is a valid UTF-8 string and
@@ -1608,3 +1637,53 @@ macro_rules! escape_types_impls {
 }
 
 escape_types_impls!(EscapeDebug, EscapeDefault, EscapeUnicode);
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // `Chars` proofs
+
+    const STR_BYTES: usize = 16;
+
+    #[kani::proof_for_contract(<Chars<'_> as Iterator>::next)]
+    #[kani::unwind(17)]
+    fn check_chars_next() {
+        let mut buf = [0; STR_BYTES];
+        let mut chars = crate::str::any_str(&mut buf).chars();
+        let _ = chars.next();
+    }
+
+    #[kani::proof_for_contract(<Chars<'_> as DoubleEndedIterator>::next_back)]
+    #[kani::unwind(17)]
+    fn check_chars_next_back() {
+        let mut buf = [0; STR_BYTES];
+        let mut chars = crate::str::any_str(&mut buf).chars();
+        let _ = chars.next_back();
+    }
+
+    // Decoding from both ends meets in the middle without skipping or repeating a char.
+    #[kani::proof]
+    #[kani::unwind(17)]
+    fn check_chars_round_trip() {
+        let mut buf = [0; STR_BYTES];
+        let s = crate::str::any_str(&mut buf);
+        let mut chars = s.chars();
+        let mut out = [0; STR_BYTES];
+        let (mut front, mut back) = (0, s.len());
+        for _ in 0..STR_BYTES / 4 {
+            if kani::any() {
+                if let Some(c) = chars.next() {
+                    front += c.encode_utf8(&mut out[front..]).len();
+                }
+            } else if let Some(c) = chars.next_back() {
+                back -= c.len_utf8();
+                c.encode_utf8(&mut out[back..]);
+            }
+        }
+        assert_eq!(chars.as_str().len(), back - front);
+        out[front..back].copy_from_slice(chars.as_str().as_bytes());
+        assert_eq!(&out[..s.len()], s.as_bytes());
+    }
+}
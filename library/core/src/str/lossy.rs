@@ -311,6 +311,38 @@ impl fmt::Debug for Utf8Chunks<'_> {
 #[unstable(feature = "kani", issue = "none")]
 pub mod verify {
     use super::*;
+    use crate::str::from_utf8;
+
+    // `Utf8Chunks` proofs
+    // Each chunk is the longest valid prefix of the remaining input followed by the
+    // invalid sequence `from_utf8` reports after it, so the chunks partition the input.
+
+    const CHUNK_BYTES: usize = 8;
+
+    #[kani::proof]
+    #[kani::unwind(10)]
+    fn check_utf8_chunks_partition() {
+        let bytes: [u8; CHUNK_BYTES] = kani::any();
+        let input = kani::slice::any_slice_of_array(&bytes);
+        let mut rest = input;
+        for chunk in input.utf8_chunks() {
+            let (valid, invalid) = (chunk.valid().as_bytes(), chunk.invalid());
+            assert!(!valid.is_empty() || !invalid.is_empty());
+            assert!(invalid.len() <= 3);
+            assert!(from_utf8(valid).is_ok());
+            match from_utf8(rest) {
+                Ok(_) => assert!(invalid.is_empty() && valid.len() == rest.len()),
+                Err(e) => {
+                    assert_eq!(e.valid_up_to(), valid.len());
+                    assert_eq!(invalid.len(), e.error_len().unwrap_or(rest.len() - valid.len()));
+                }
+            }
+            assert_eq!(valid.as_ptr(), rest.as_ptr());
+            assert_eq!(invalid.as_ptr(), rest[valid.len()..].as_ptr());
+            rest = &rest[valid.len() + invalid.len()..];
+        }
+        assert!(rest.is_empty());
+    }
 
     // TODO: This proof became  too slow with the toolchain update to 2025-04-07 for reasons yet to
     // be understood
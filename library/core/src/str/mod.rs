@@ -13,8 +13,12 @@ mod iter;
 mod traits;
 mod validations;
 
+use safety::ensures;
+
 use self::pattern::{DoubleEndedSearcher, Pattern, ReverseSearcher, Searcher};
 use crate::char::{self, EscapeDebugExtArgs};
+#[cfg(kani)]
+use crate::kani;
 use crate::ops::Range;
 use crate::slice::{self, SliceIndex};
 use crate::ub_checks::assert_unsafe_precondition;
@@ -357,6 +361,8 @@ impl str {
     #[must_use]
     #[stable(feature = "is_char_boundary", since = "1.9.0")]
     #[rustc_const_stable(feature = "const_is_char_boundary", since = "1.86.0")]
+    #[ensures(|&is_boundary| !is_boundary || index <= self.len())]
+    #[ensures(|&is_boundary| is_boundary || (index != 0 && index != self.len()))]
     #[inline]
     pub const fn is_char_boundary(&self, index: usize) -> bool {
         // 0 is always ok.
@@ -406,6 +412,8 @@ impl str {
     /// assert_eq!(&s[..closest], "❤️🧡");
     /// ```
     #[unstable(feature = "round_char_boundary", issue = "93743")]
+    #[ensures(|&floor| floor <= index.min(self.len()) && self.is_char_boundary(floor))]
+    #[ensures(|&floor| (floor + 1..=index.min(self.len())).all(|i| !self.is_char_boundary(i)))]
     #[inline]
     pub fn floor_char_boundary(&self, index: usize) -> usize {
         if index >= self.len() {
@@ -444,6 +452,9 @@ impl str {
     /// assert_eq!(&s[..closest], "❤️🧡💛");
     /// ```
     #[unstable(feature = "round_char_boundary", issue = "93743")]
+    #[ensures(|&ceil| index.min(self.len()) <= ceil && ceil <= self.len())]
+    #[ensures(|&ceil| self.is_char_boundary(ceil))]
+    #[ensures(|&ceil| (index.min(self.len())..ceil).all(|i| !self.is_char_boundary(i)))]
     #[inline]
     pub fn ceil_char_boundary(&self, index: usize) -> usize {
         if index >= self.len() {
@@ -3146,6 +3157,82 @@ impl_fn_for_zst! {
     };
 }
 
+/// Writes up to `N / 4` symbolic chars into `buf` and returns them as a string.
+///
+/// The result is valid UTF-8 by construction, so `str` harnesses can start from it instead of
+/// assuming `from_utf8` on arbitrary bytes.
+#[cfg(kani)]
+pub(crate) fn any_str<const N: usize>(buf: &mut [u8; N]) -> &str {
+    let chars = kani::any_where(|&chars: &usize| chars <= N / 4);
+    let mut len = 0;
+    for _ in 0..chars {
+        let c: char = kani::any();
+        len += c.encode_utf8(&mut buf[len..]).len();
+    }
+    // SAFETY: `buf[..len]` is a concatenation of UTF-8 encoded chars.
+    unsafe { from_utf8_unchecked(&buf[..len]) }
+}
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // Char boundary proofs
+
+    const STR_BYTES: usize = 16;
+
+    #[kani::proof_for_contract(<str>::is_char_boundary)]
+    #[kani::unwind(5)]
+    fn check_is_char_boundary() {
+        let mut buf = [0; STR_BYTES];
+        let s = any_str(&mut buf);
+        let _ = s.is_char_boundary(kani::any());
+    }
+
+    #[kani::proof_for_contract(<str>::floor_char_boundary)]
+    #[kani::unwind(5)]
+    fn check_floor_char_boundary() {
+        let mut buf = [0; STR_BYTES];
+        let s = any_str(&mut buf);
+        let _ = s.floor_char_boundary(kani::any());
+    }
+
+    #[kani::proof_for_contract(<str>::ceil_char_boundary)]
+    #[kani::unwind(5)]
+    fn check_ceil_char_boundary() {
+        let mut buf = [0; STR_BYTES];
+        let s = any_str(&mut buf);
+        let _ = s.ceil_char_boundary(kani::any());
+    }
+
+    // The boundaries are exactly the offsets `char_indices` yields, plus the end of the string.
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_char_boundaries_are_char_indices() {
+        let mut buf = [0; STR_BYTES];
+        let s = any_str(&mut buf);
+        let index = kani::any_where(|&index: &usize| index <= s.len());
+        let at_char = s.char_indices().any(|(i, _)| i == index);
+        assert_eq!(s.is_char_boundary(index), at_char || index == s.len());
+    }
+
+    // Boundary lemma: a UTF-8 sequence is at most four bytes long, so every index of the string
+    // has a char boundary at most three bytes before it and at most three bytes after it.
+    // `floor_char_boundary` relies on this when it unwraps its search unchecked.
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_char_boundary_lemma() {
+        let mut buf = [0; STR_BYTES];
+        let s = any_str(&mut buf);
+        let index = kani::any_where(|&index: &usize| index <= s.len());
+        let before = index.saturating_sub(3)..=index;
+        let after = index..=(index + 3).min(s.len());
+        assert!(before.into_iter().any(|i| s.is_char_boundary(i)));
+        assert!(after.into_iter().any(|i| s.is_char_boundary(i)));
+    }
+}
+
 // This is required to make `impl From<&str> for Box<dyn Error>` and `impl<E> From<E> for Box<dyn Error>` not overlap.
 #[stable(feature = "error_in_core_neg_impl", since = "1.65.0")]
 impl !crate::error::Error for &str {}
@@ -309,4 +309,48 @@ pub mod verify {
             }
         }
     }
+
+    // `next_code_point` proofs
+    // The decoder takes an arbitrary byte iterator, so its precondition (the bytes are valid
+    // UTF-8) is established here by building the input from a symbolic `str`.
+
+    const STR_BYTES: usize = 16;
+
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_next_code_point() {
+        let mut buf = [0; STR_BYTES];
+        let s = crate::str::any_str(&mut buf);
+        let mut bytes = s.as_bytes().iter();
+        let ch = unsafe { next_code_point(&mut bytes) };
+        let expected = s.chars().next();
+        assert_eq!(ch, expected.map(|c| c as u32));
+        if let Some(c) = expected {
+            // Exactly the bytes of the first char were consumed.
+            assert_eq!(bytes.as_slice(), &s.as_bytes()[c.len_utf8()..]);
+        }
+    }
+
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_next_code_point_reverse() {
+        let mut buf = [0; STR_BYTES];
+        let s = crate::str::any_str(&mut buf);
+        let mut bytes = s.as_bytes().iter();
+        let ch = unsafe { next_code_point_reverse(&mut bytes) };
+        let expected = s.chars().next_back();
+        assert_eq!(ch, expected.map(|c| c as u32));
+        if let Some(c) = expected {
+            assert_eq!(bytes.as_slice(), &s.as_bytes()[..s.len() - c.len_utf8()]);
+        }
+    }
+
+    // A lead byte announcing continuation bytes that are not there makes the decoder unwrap
+    // `None`.
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_next_code_point_truncated() {
+        let lead = kani::any_where(|&b: &u8| b >= 0xC0);
+        let _ = unsafe { next_code_point(&mut [lead].iter()) };
+    }
 }
@@ -2,11 +2,17 @@
 
 use core::fmt;
 use core::future::Future;
+#[cfg(kani)]
+use core::kani;
 use core::marker::Tuple;
 use core::ops::{Coroutine, CoroutineState};
 use core::pin::Pin;
+#[cfg(kani)]
+use core::ptr;
 use core::task::{Context, Poll};
 
+use safety::ensures;
+
 /// `Exclusive` provides only _mutable_ access, also referred to as _exclusive_
 /// access to the underlying value. It provides no _immutable_, or _shared_
 /// access to the underlying value.
@@ -119,6 +125,7 @@ impl<T: ?Sized> Exclusive<T> {
     #[unstable(feature = "exclusive_wrapper", issue = "98407")]
     #[must_use]
     #[inline]
+    #[ensures(|inner| ptr::addr_eq(&**inner, old(self as *const Self)))]
     pub const fn get_mut(&mut self) -> &mut T {
         &mut self.inner
     }
@@ -132,6 +139,7 @@ impl<T: ?Sized> Exclusive<T> {
     #[unstable(feature = "exclusive_wrapper", issue = "98407")]
     #[must_use]
     #[inline]
+    #[ensures(|inner| ptr::addr_eq(&**inner, old(&*self as *const Self)))]
     pub const fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut T> {
         // SAFETY: `Exclusive` can only produce `&mut T` if itself is unpinned
         // `Pin::map_unchecked_mut` is not const, so we do this conversion manually
@@ -144,6 +152,7 @@ impl<T: ?Sized> Exclusive<T> {
     #[unstable(feature = "exclusive_wrapper", issue = "98407")]
     #[must_use]
     #[inline]
+    #[ensures(|exclusive| ptr::addr_eq(&**exclusive, old(r as *const T)))]
     pub const fn from_mut(r: &'_ mut T) -> &'_ mut Exclusive<T> {
         // SAFETY: repr is ≥ C, so refs have the same layout; and `Exclusive` properties are `&mut`-agnostic
         unsafe { &mut *(r as *mut T as *mut Exclusive<T>) }
@@ -155,6 +164,7 @@ impl<T: ?Sized> Exclusive<T> {
     #[unstable(feature = "exclusive_wrapper", issue = "98407")]
     #[must_use]
     #[inline]
+    #[ensures(|exclusive| ptr::addr_eq(&**exclusive, old(&*r as *const T)))]
     pub const fn from_pin_mut(r: Pin<&'_ mut T>) -> Pin<&'_ mut Exclusive<T>> {
         // SAFETY: `Exclusive` can only produce `&mut T` if itself is unpinned
         // `Pin::map_unchecked_mut` is not const, so we do this conversion manually
@@ -220,3 +230,61 @@ where
         G::resume(self.get_pin_mut(), arg)
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use core::cell::Cell;
+
+    use super::*;
+
+    // `Exclusive` access proofs
+
+    const fn assert_sync<T: ?Sized + Sync>() {}
+    const _: () = assert_sync::<Exclusive<Cell<u8>>>();
+    const _: () = assert_sync::<Exclusive<[Cell<u8>]>>();
+
+    #[kani::proof_for_contract(Exclusive::<Cell<u8>>::get_mut)]
+    fn check_get_mut() {
+        let mut exclusive = Exclusive::new(Cell::new(kani::any::<u8>()));
+        let _ = exclusive.get_mut();
+    }
+
+    #[kani::proof_for_contract(Exclusive::<Cell<u8>>::get_pin_mut)]
+    fn check_get_pin_mut() {
+        let mut exclusive = Exclusive::new(Cell::new(kani::any::<u8>()));
+        let _ = Pin::new(&mut exclusive).get_pin_mut();
+    }
+
+    #[kani::proof_for_contract(Exclusive::<Cell<u8>>::from_mut)]
+    fn check_from_mut() {
+        let mut cell = Cell::new(kani::any::<u8>());
+        let _ = Exclusive::from_mut(&mut cell);
+    }
+
+    #[kani::proof_for_contract(Exclusive::<Cell<u8>>::from_pin_mut)]
+    fn check_from_pin_mut() {
+        let mut cell = Cell::new(kani::any::<u8>());
+        let _ = Exclusive::from_pin_mut(Pin::new(&mut cell));
+    }
+
+    #[kani::proof_for_contract(Exclusive::<[Cell<u8>]>::from_mut)]
+    fn check_from_mut_unsized() {
+        let mut cells: [Cell<u8>; 2] = [Cell::new(kani::any()), Cell::new(kani::any())];
+        let _ = Exclusive::from_mut(&mut cells[..]);
+    }
+
+    // Writes through the wrapper land in the wrapped place, and the value can only be read
+    // back once the exclusive borrow has ended.
+    #[kani::proof]
+    fn check_round_trip() {
+        let (old, new): (u8, u8) = kani::any();
+        let mut cell = Cell::new(old);
+        {
+            let exclusive = Exclusive::from_mut(&mut cell);
+            let inner = Pin::new(exclusive).get_pin_mut().get_mut();
+            assert_eq!(inner.replace(new), old);
+        }
+        assert_eq!(cell.into_inner(), new);
+    }
+}
@@ -1,5 +1,7 @@
 #![stable(feature = "futures_api", since = "1.36.0")]
 
+use safety::ensures;
+
 use crate::any::Any;
 use crate::marker::PhantomData;
 use crate::mem::{ManuallyDrop, transmute};
@@ -53,6 +55,7 @@ impl RawWaker {
     #[stable(feature = "futures_api", since = "1.36.0")]
     #[rustc_const_stable(feature = "futures_api", since = "1.36.0")]
     #[must_use]
+    #[ensures(|result: &RawWaker| result.data == data && ptr::eq(result.vtable, vtable))]
     pub const fn new(data: *const (), vtable: &'static RawWakerVTable) -> RawWaker {
         RawWaker { data, vtable }
     }
@@ -510,6 +513,7 @@ impl Waker {
     #[must_use]
     #[stable(feature = "waker_getters", since = "1.83.0")]
     #[rustc_const_stable(feature = "waker_getters", since = "1.83.0")]
+    #[ensures(|result: &Waker| result.data() == data && ptr::eq(result.vtable(), vtable))]
     pub const unsafe fn new(data: *const (), vtable: &'static RawWakerVTable) -> Self {
         Waker { waker: RawWaker { data, vtable } }
     }
@@ -529,6 +533,9 @@ impl Waker {
     #[must_use]
     #[stable(feature = "futures_api", since = "1.36.0")]
     #[rustc_const_stable(feature = "const_waker", since = "1.82.0")]
+    #[ensures(|result: &Waker| {
+        result.data() == old(waker.data) && ptr::eq(result.vtable(), old(waker.vtable))
+    })]
     pub const unsafe fn from_raw(waker: RawWaker) -> Waker {
         Waker { waker }
     }
@@ -934,3 +941,121 @@ impl fmt::Debug for LocalWaker {
 impl !Send for LocalWaker {}
 #[unstable(feature = "local_waker", issue = "118959")]
 impl !Sync for LocalWaker {}
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::kani;
+    use crate::sync::atomic::AtomicUsize;
+    use crate::sync::atomic::Ordering::Relaxed;
+
+    // A reference-counted model of an executor's waker. The `data` pointer of every waker refers
+    // to `LIVE`, the number of wakers currently sharing the task. Each vtable entry asserts that
+    // it is only reached while the task is still referenced: this is the `RawWakerVTable`
+    // contract that callers of `Waker::from_raw` promise to uphold, and that the `Clone`,
+    // `Drop` and `wake` implementations of `Waker` must in turn respect.
+    static LIVE: AtomicUsize = AtomicUsize::new(0);
+
+    static MODEL_VTABLE: RawWakerVTable =
+        RawWakerVTable::new(model_clone, model_wake, model_wake_by_ref, model_drop);
+
+    fn model_data() -> *const () {
+        (&raw const LIVE).cast()
+    }
+
+    fn live(data: *const ()) -> &'static AtomicUsize {
+        assert_eq!(data, model_data());
+        assert!(LIVE.load(Relaxed) > 0, "vtable entry called on a dropped waker");
+        &LIVE
+    }
+
+    unsafe fn model_clone(data: *const ()) -> RawWaker {
+        live(data).fetch_add(1, Relaxed);
+        RawWaker::new(data, &MODEL_VTABLE)
+    }
+
+    unsafe fn model_wake(data: *const ()) {
+        live(data).fetch_sub(1, Relaxed);
+    }
+
+    unsafe fn model_wake_by_ref(data: *const ()) {
+        live(data);
+    }
+
+    unsafe fn model_drop(data: *const ()) {
+        live(data).fetch_sub(1, Relaxed);
+    }
+
+    #[kani::proof_for_contract(RawWaker::new)]
+    fn check_raw_waker_new() {
+        let data = kani::any::<usize>() as *const ();
+        let _ = RawWaker::new(data, &MODEL_VTABLE);
+    }
+
+    #[kani::proof_for_contract(Waker::new)]
+    fn check_waker_new() {
+        let data = kani::any::<usize>() as *const ();
+        // The data pointer is arbitrary, so the waker must not reach the model vtable.
+        crate::mem::forget(unsafe { Waker::new(data, &MODEL_VTABLE) });
+    }
+
+    #[kani::proof_for_contract(Waker::from_raw)]
+    fn check_waker_from_raw() {
+        let data = kani::any::<usize>() as *const ();
+        let raw = RawWaker::new(data, &MODEL_VTABLE);
+        crate::mem::forget(unsafe { Waker::from_raw(raw) });
+    }
+
+    // Any interleaving of clones, wakes and drops keeps the task referenced exactly as long as
+    // some waker for it is alive, and releases it once the last one is consumed.
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_from_raw_reference_counting() {
+        LIVE.store(1, Relaxed);
+        let waker = unsafe { Waker::from_raw(RawWaker::new(model_data(), &MODEL_VTABLE)) };
+        let mut extra: Option<Waker> = None;
+        for _ in 0..4 {
+            match kani::any::<u8>() % 5 {
+                0 => extra = Some(waker.clone()),
+                1 => waker.wake_by_ref(),
+                2 => {
+                    if let Some(w) = extra.take() {
+                        w.wake();
+                    }
+                }
+                3 => {
+                    if let Some(w) = &mut extra {
+                        // Both wakers share the task, so this must not clone.
+                        w.clone_from(&waker);
+                    }
+                }
+                _ => drop(extra.take()),
+            }
+            assert_eq!(LIVE.load(Relaxed), 1 + extra.is_some() as usize);
+        }
+        drop(extra);
+        waker.wake();
+        assert_eq!(LIVE.load(Relaxed), 0);
+    }
+
+    // The no-op waker can be cloned, woken and dropped in any order.
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_noop() {
+        let noop = Waker::noop();
+        assert!(noop.data().is_null());
+        let mut owned = noop.clone();
+        for _ in 0..4 {
+            match kani::any::<u8>() % 4 {
+                0 => owned = owned.clone(),
+                1 => owned.wake_by_ref(),
+                2 => owned.clone().wake(),
+                _ => owned.clone_from(noop),
+            }
+            assert!(owned.data().is_null());
+        }
+        drop(owned);
+        noop.wake_by_ref();
+    }
+}
@@ -124,13 +124,19 @@ pub(crate) const fn maybe_is_aligned_and_not_null(
     const_eval_select!(
         @capture { ptr: *const (), align: usize, is_zst: bool } -> bool:
         if const {
-            is_zst || !ptr.is_null()
+            maybe_is_not_null(ptr, align, is_zst)
         } else {
             ptr.is_aligned_to(align) && (is_zst || !ptr.is_null())
         }
     )
 }
 
+/// The compile-time arm of [`maybe_is_aligned_and_not_null`], which cannot check alignment.
+#[inline]
+const fn maybe_is_not_null(ptr: *const (), _align: usize, is_zst: bool) -> bool {
+    is_zst || !ptr.is_null()
+}
+
 #[inline]
 pub(crate) const fn is_valid_allocation_size(size: usize, len: usize) -> bool {
     let max_len = if size == 0 { usize::MAX } else { isize::MAX as usize / size };
@@ -140,6 +146,10 @@ pub(crate) const fn is_valid_allocation_size(size: usize, len: usize) -> bool {
 /// Checks whether the regions of memory starting at `src` and `dst` of size
 /// `count * size` do *not* overlap.
 ///
+/// Zero-sized regions (i.e., `size == 0` or `count == 0`) never overlap with any other
+/// region, independently of their addresses. This lets contracts use this check uniformly
+/// without special-casing zero-sized types.
+///
 /// Note that in const-eval this function just returns `true` and therefore must
 /// only be used with `assert_unsafe_precondition!`, similar to `is_aligned_and_not_null`.
 #[inline]
@@ -156,6 +166,9 @@ pub(crate) const fn maybe_is_nonoverlapping(
         if const {
             true
         } else {
+            if size == 0 || count == 0 {
+                return true;
+            }
             let src_usize = src.addr();
             let dst_usize = dst.addr();
             let Some(size) = size.checked_mul(count) else {
@@ -233,9 +246,38 @@ mod predicates {
 #[cfg(kani)]
 mod predicates {
     pub use crate::kani::float::float_to_int_in_range;
-    pub use crate::kani::mem::{
-        can_dereference, can_read_unaligned, can_write, can_write_unaligned, same_allocation,
-    };
+    pub use crate::kani::mem::{can_read_unaligned, can_write_unaligned, same_allocation};
+
+    /// Checks if a pointer can be dereferenced, ensuring:
+    ///   * `src` is valid for reads (see [`crate::ptr`] documentation).
+    ///   * `src` is properly aligned (use `read_unaligned` if not).
+    ///   * `src` points to a properly initialized value of type `T`.
+    ///
+    /// Accesses of size zero are valid for any properly aligned pointer, so zero-sized types
+    /// and empty slices only need to satisfy the alignment requirement.
+    ///
+    /// [`crate::ptr`]: https://doc.rust-lang.org/std/ptr/index.html
+    pub fn can_dereference<T: ?Sized>(src: *const T) -> bool {
+        is_aligned_zero_sized(src) || crate::kani::mem::can_dereference(src)
+    }
+
+    /// Check if a pointer can be written to:
+    /// * `dst` must be valid for writes.
+    /// * `dst` must be properly aligned. Use `write_unaligned` if this is not the
+    ///    case.
+    ///
+    /// Like [`can_dereference`], zero-sized writes only require `dst` to be properly aligned.
+    pub fn can_write<T: ?Sized>(dst: *mut T) -> bool {
+        is_aligned_zero_sized(dst as *const T) || crate::kani::mem::can_write(dst)
+    }
+
+    /// Whether `ptr` points to a zero-sized value and is properly aligned for it.
+    fn is_aligned_zero_sized<T: ?Sized>(ptr: *const T) -> bool {
+        // SAFETY: The pointers given to the contract predicates carry valid metadata.
+        let (size, align) =
+            unsafe { (crate::mem::size_of_val_raw(ptr), crate::mem::align_of_val_raw(ptr)) };
+        size == 0 && super::maybe_is_aligned_and_not_null(ptr.cast(), align, true)
+    }
 }
 
 /// This trait should be used to specify and check type safety invariants for a
@@ -292,3 +334,61 @@ trivial_invariant!(f16);
 trivial_invariant!(f32);
 trivial_invariant!(f64);
 trivial_invariant!(f128);
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::intrinsics::check_const_eval_select_arms;
+    use crate::kani;
+
+    // Both arms agree on pointers that are aligned, which is where the compile-time check is used.
+    check_const_eval_select_arms!(
+        check_maybe_is_aligned_and_not_null_arms,
+        maybe_is_not_null,
+        maybe_is_aligned_and_not_null,
+        {
+            ptr: *const () = kani::any::<usize>() as *const (),
+            align: usize = 1 << kani::any_where(|shift: &u32| *shift < usize::BITS),
+            is_zst: bool = kani::any(),
+        } where ptr.is_aligned_to(align)
+    );
+
+    #[kani::proof]
+    fn check_maybe_is_nonoverlapping_zero_size() {
+        let src: *const () = kani::any::<usize>() as *const ();
+        let dst: *const () = kani::any::<usize>() as *const ();
+        assert!(maybe_is_nonoverlapping(src, dst, 0, kani::any()));
+        assert!(maybe_is_nonoverlapping(src, dst, kani::any(), 0));
+    }
+
+    #[kani::proof]
+    fn check_maybe_is_nonoverlapping_same_addr() {
+        let ptr: *const () = kani::any::<usize>() as *const ();
+        let size: usize = kani::any();
+        let count: usize = kani::any();
+        kani::assume(size.checked_mul(count).is_some());
+        assert_eq!(maybe_is_nonoverlapping(ptr, ptr, size, count), size == 0 || count == 0);
+    }
+
+    #[kani::proof]
+    fn check_maybe_is_nonoverlapping_distance() {
+        let src: usize = kani::any();
+        let dst: usize = kani::any();
+        let size: usize = kani::any();
+        let count: usize = kani::any();
+        kani::assume(size.checked_mul(count).is_some());
+        let result = maybe_is_nonoverlapping(src as *const (), dst as *const (), size, count);
+        assert_eq!(result, src.abs_diff(dst) >= size * count);
+    }
+
+    #[kani::proof]
+    fn check_can_dereference_zst() {
+        let addr: usize = kani::any();
+        let ptr = addr as *const ();
+        assert!(can_dereference(ptr));
+        assert!(can_write(ptr as *mut ()));
+        let empty = crate::ptr::slice_from_raw_parts(addr as *const u32, 0);
+        assert_eq!(can_dereference(empty), addr % align_of::<u32>() == 0);
+    }
+}
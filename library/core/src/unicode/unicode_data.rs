@@ -736,8 +736,15 @@ pub mod white_space {
 
 #[rustfmt::skip]
 pub mod conversions {
+    use safety::ensures;
+
+    #[cfg(kani)]
+    use crate::kani;
+
     const INDEX_MASK: u32 = 0x400000;
 
+    #[ensures(|r| r[1] != '\0' || r[2] == '\0')]
+    #[ensures(|r| !c.is_ascii() || *r == [c.to_ascii_lowercase(), '\0', '\0'])]
     pub fn to_lower(c: char) -> [char; 3] {
         if c.is_ascii() {
             [(c as u8).to_ascii_lowercase() as char, '\0', '\0']
@@ -755,6 +762,8 @@ pub mod conversions {
         }
     }
 
+    #[ensures(|r| r[1] != '\0' || r[2] == '\0')]
+    #[ensures(|r| !c.is_ascii() || *r == [c.to_ascii_uppercase(), '\0', '\0'])]
     pub fn to_upper(c: char) -> [char; 3] {
         if c.is_ascii() {
             [(c as u8).to_ascii_uppercase() as char, '\0', '\0']
@@ -1549,4 +1558,44 @@ pub mod conversions {
         ['\u{544}', '\u{53b}', '\u{0}'], ['\u{54e}', '\u{546}', '\u{0}'],
         ['\u{544}', '\u{53d}', '\u{0}'],
     ];
+
+    #[cfg(kani)]
+    #[unstable(feature = "kani", issue = "none")]
+    mod verify {
+        use super::*;
+
+        // Case conversion proofs
+
+        /// Every entry of `table` either maps to a single `char`, or holds an index into
+        /// `multi` that is in bounds for the `get_unchecked` in `to_lower`/`to_upper`.
+        fn check_table_entry(table: &[(char, u32)], multi: &[[char; 3]]) {
+            let i = kani::any_where(|&i: &usize| i < table.len());
+            let u = table[i].1;
+            assert!(char::from_u32(u).is_some() || ((u & (INDEX_MASK - 1)) as usize) < multi.len());
+        }
+
+        #[kani::proof]
+        fn check_lowercase_table() {
+            check_table_entry(LOWERCASE_TABLE, LOWERCASE_TABLE_MULTI);
+        }
+
+        #[kani::proof]
+        fn check_uppercase_table() {
+            check_table_entry(UPPERCASE_TABLE, UPPERCASE_TABLE_MULTI);
+        }
+
+        #[kani::proof_for_contract(to_lower)]
+        #[kani::unwind(12)]
+        fn check_to_lower() {
+            let c = kani::any_where(|c: &char| (*c as u32) < 0x20000);
+            let _ = to_lower(c);
+        }
+
+        #[kani::proof_for_contract(to_upper)]
+        #[kani::unwind(12)]
+        fn check_to_upper() {
+            let c = kani::any_where(|c: &char| (*c as u32) < 0x20000);
+            let _ = to_upper(c);
+        }
+    }
 }
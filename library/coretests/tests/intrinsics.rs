@@ -100,6 +100,21 @@ fn test_const_deallocate_at_runtime() {
     }
 }
 
+#[test]
+fn test_const_allocate_round_trip_at_compile_time() {
+    use core::intrinsics::{const_allocate, const_deallocate};
+    let value = const {
+        unsafe {
+            let ptr = const_allocate(8, 8) as *mut u64;
+            ptr.write(0x0123_4567_89ab_cdef);
+            let value = ptr.read();
+            const_deallocate(ptr as *mut u8, 8, 8);
+            value
+        }
+    };
+    assert_eq!(value, 0x0123_4567_89ab_cdef);
+}
+
 #[test]
 fn test_three_way_compare_in_const_contexts() {
     use core::cmp::Ordering::{self, *};
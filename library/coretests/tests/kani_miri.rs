@@ -0,0 +1,131 @@
+//! Runs Kani harnesses of `core` as Miri tests.
+//!
+//! Harness files written against this adapter are included both by a `verify` module in `core`
+//! and here. Under Kani, `kani::any()` is a symbolic value and every harness is a proof; here it is
+//! a random value, `kani::assume()` discards the current sample, and every harness is a `#[test]`
+//! that runs a few samples. Running the same bodies under Miri adds dynamic UB detection on
+//! concrete values to the proofs.
+//!
+//! Only harnesses that stick to the public API can be shared: `core`'s verify modules are compiled
+//! with `cfg(kani)` only and may use private items and function contracts.
+
+use std::cell::RefCell;
+use std::cmp::Reverse;
+use std::panic::{self, AssertUnwindSafe};
+
+use rand::Rng;
+use rand_xorshift::XorShiftRng;
+
+/// Number of samples per harness. Miri is slow, so this is kept small; the seed varies between
+/// runs.
+const SAMPLES: usize = 8;
+
+thread_local! {
+    static RNG: RefCell<Option<XorShiftRng>> = const { RefCell::new(None) };
+}
+
+/// Payload of the unwind started by a failed `kani::assume`.
+struct Rejected;
+
+pub(crate) mod kani {
+    use super::*;
+
+    /// Types that `any` can produce, mirroring `kani::Arbitrary`.
+    pub(crate) trait Arbitrary: Sized {
+        fn any_with(rng: &mut XorShiftRng) -> Self;
+    }
+
+    macro_rules! impl_arbitrary {
+        ($($ty:ty),+ $(,)?) => {$(
+            impl Arbitrary for $ty {
+                fn any_with(rng: &mut XorShiftRng) -> Self {
+                    rng.random()
+                }
+            }
+        )+};
+    }
+
+    impl_arbitrary!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bool, char);
+
+    macro_rules! impl_arbitrary_tuple {
+        ($($name:ident)+) => {
+            impl<$($name: Arbitrary),+> Arbitrary for ($($name,)+) {
+                fn any_with(rng: &mut XorShiftRng) -> Self {
+                    ($($name::any_with(rng),)+)
+                }
+            }
+        };
+    }
+
+    impl_arbitrary_tuple!(A);
+    impl_arbitrary_tuple!(A B);
+    impl_arbitrary_tuple!(A B C);
+    impl_arbitrary_tuple!(A B C D);
+
+    impl<T: Arbitrary, const N: usize> Arbitrary for [T; N] {
+        fn any_with(rng: &mut XorShiftRng) -> Self {
+            core::array::from_fn(|_| T::any_with(rng))
+        }
+    }
+
+    impl<T: Arbitrary> Arbitrary for Reverse<T> {
+        fn any_with(rng: &mut XorShiftRng) -> Self {
+            Reverse(T::any_with(rng))
+        }
+    }
+
+    /// Returns a random value. Only valid while a harness is run by `run`.
+    pub(crate) fn any<T: Arbitrary>() -> T {
+        RNG.with_borrow_mut(|rng| {
+            T::any_with(rng.as_mut().expect("`any` called outside a harness"))
+        })
+    }
+
+    /// Discards the current sample if `cond` does not hold.
+    pub(crate) fn assume(cond: bool) {
+        if !cond {
+            // `resume_unwind` does not run the panic hook, so rejected samples stay quiet.
+            panic::resume_unwind(Box::new(Rejected));
+        }
+    }
+}
+
+/// Runs `harness` on [`SAMPLES`] random samples, skipping those rejected by `kani::assume`.
+#[track_caller]
+pub(crate) fn run(harness: fn()) {
+    RNG.set(Some(crate::test_rng()));
+    for _ in 0..SAMPLES {
+        match panic::catch_unwind(AssertUnwindSafe(harness)) {
+            Ok(()) => {}
+            Err(payload) if payload.is::<Rejected>() => {}
+            Err(payload) => panic::resume_unwind(payload),
+        }
+    }
+    RNG.set(None);
+}
+
+/// Maps a shared harness to a randomized test. A `#[should_panic]` harness passes as soon as one
+/// sample panics, like a Kani proof with `#[kani::should_panic]` that has a panicking path.
+macro_rules! dual_proof {
+    (#[should_panic] fn $harness:ident() $body:block) => {
+        #[test]
+        #[should_panic]
+        fn $harness() {
+            fn harness() $body
+            $crate::kani_miri::run(harness);
+        }
+    };
+    (fn $harness:ident() $body:block) => {
+        #[test]
+        fn $harness() {
+            fn harness() $body
+            $crate::kani_miri::run(harness);
+        }
+    };
+}
+
+mod cmp {
+    use super::kani;
+
+    include!("../../core/src/cmp/laws.rs");
+}
@@ -20,6 +20,7 @@
 #![feature(const_destruct)]
 #![feature(const_eval_select)]
 #![feature(const_float_round_methods)]
+#![feature(const_heap)]
 #![feature(const_ref_cell)]
 #![feature(const_trait_impl)]
 #![feature(core_float_math)]
@@ -166,6 +167,8 @@ mod hint;
 mod intrinsics;
 mod io;
 mod iter;
+#[cfg(miri)]
+mod kani_miri;
 mod lazy;
 mod macros;
 mod manually_drop;
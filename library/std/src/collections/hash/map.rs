@@ -1,7 +1,12 @@
 #[cfg(test)]
 mod tests;
 
+#[cfg(kani)]
+use core::kani;
+
 use hashbrown::hash_map as base;
+#[cfg(kani)]
+use safety::{ensures, requires};
 
 use self::Entry::*;
 use crate::borrow::Borrow;
@@ -243,6 +248,26 @@ pub struct HashMap<K, V, S = RandomState> {
     base: base::HashMap<K, V, S>,
 }
 
+/// Returns `true` if the keys are pairwise distinct.
+#[cfg(kani)]
+fn are_distinct<Q: ?Sized + Eq, const N: usize>(ks: &[&Q; N]) -> bool {
+    (0..N).all(|i| (0..i).all(|j| ks[i] != ks[j]))
+}
+
+/// Returns `true` if no two of the found values overlap in memory.
+#[cfg(kani)]
+fn are_disjoint<V>(refs: &[Option<&mut V>]) -> bool {
+    (0..refs.len()).all(|i| {
+        (0..i).all(|j| match (&refs[i], &refs[j]) {
+            (Some(a), Some(b)) => {
+                let (a, b) = (crate::ptr::from_ref::<V>(a), crate::ptr::from_ref::<V>(b));
+                a.addr().abs_diff(b.addr()) >= size_of::<V>()
+            }
+            _ => true,
+        })
+    })
+}
+
 impl<K, V> HashMap<K, V, RandomState> {
     /// Creates an empty `HashMap`.
     ///
@@ -1039,6 +1064,7 @@ where
     #[inline]
     #[doc(alias = "get_many_mut")]
     #[stable(feature = "map_many_mut", since = "1.86.0")]
+    #[cfg_attr(kani, ensures(|refs| are_disjoint(refs)))]
     pub fn get_disjoint_mut<Q: ?Sized, const N: usize>(
         &mut self,
         ks: [&Q; N],
@@ -1106,6 +1132,8 @@ where
     #[inline]
     #[doc(alias = "get_many_unchecked_mut")]
     #[stable(feature = "map_many_mut", since = "1.86.0")]
+    #[cfg_attr(kani, requires(are_distinct(&ks)))]
+    #[cfg_attr(kani, ensures(|refs| are_disjoint(refs)))]
     pub unsafe fn get_disjoint_unchecked_mut<Q: ?Sized, const N: usize>(
         &mut self,
         ks: [&Q; N],
@@ -2888,3 +2916,90 @@ fn assert_covariance() {
         d
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::hash::{BuildHasherDefault, Hasher};
+
+    // `get_disjoint_mut` proofs
+
+    /// Hashes a key to its own bytes, which keeps the table layout cheap to
+    /// model while still spreading the keys over distinct buckets.
+    #[derive(Default)]
+    struct KeyHasher(u64);
+
+    impl Hasher for KeyHasher {
+        fn finish(&self) -> u64 {
+            self.0
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for &byte in bytes {
+                self.0 = self.0 << 8 | u64::from(byte);
+            }
+        }
+    }
+
+    const LEN: u8 = 3;
+
+    /// Maps the keys `0..LEN` to symbolic values.
+    fn any_map() -> HashMap<u8, u32, BuildHasherDefault<KeyHasher>> {
+        let mut map = HashMap::default();
+        for key in 0..LEN {
+            map.insert(key, kani::any());
+        }
+        map
+    }
+
+    /// Returns a symbolic key that may or may not be in the map.
+    fn any_key() -> u8 {
+        kani::any_where(|&key: &u8| key <= LEN)
+    }
+
+    #[kani::proof_for_contract(HashMap::get_disjoint_mut)]
+    #[kani::unwind(5)]
+    fn check_get_disjoint_mut() {
+        let mut map = any_map();
+        let (a, b, c) = (any_key(), any_key(), any_key());
+        kani::assume(a != b && b != c && a != c);
+        let _ = map.get_disjoint_mut([&a, &b, &c]);
+    }
+
+    #[kani::proof_for_contract(HashMap::get_disjoint_unchecked_mut)]
+    #[kani::unwind(5)]
+    fn check_get_disjoint_unchecked_mut() {
+        let mut map = any_map();
+        let (a, b) = (any_key(), any_key());
+        let _ = unsafe { map.get_disjoint_unchecked_mut([&a, &b]) };
+    }
+
+    // Each key finds its own value, and writes through one reference are not
+    // observed through the other.
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_get_disjoint_mut_values() {
+        let mut map = any_map();
+        let (a, b) = (any_key(), any_key());
+        kani::assume(a != b);
+        let (va, vb) = (map.get(&a).copied(), map.get(&b).copied());
+
+        let [ra, rb] = map.get_disjoint_mut([&a, &b]);
+        assert_eq!(ra.as_deref().copied(), va);
+        assert_eq!(rb.as_deref().copied(), vb);
+        if let (Some(ra), Some(rb)) = (ra, rb) {
+            *ra = ra.wrapping_add(1);
+            assert_eq!(Some(*rb), vb);
+        }
+    }
+
+    #[kani::proof]
+    #[kani::unwind(5)]
+    #[kani::should_panic]
+    fn check_get_disjoint_mut_duplicate() {
+        let mut map = any_map();
+        let key = any_key();
+        let _ = map.get_disjoint_mut([&key, &key]);
+    }
+}
@@ -1,6 +1,11 @@
 #[cfg(test)]
 mod tests;
 
+#[cfg(kani)]
+use core::kani;
+
+use safety::{ensures, requires};
+
 use crate::alloc::Allocator;
 use crate::cmp;
 use crate::io::prelude::*;
@@ -230,6 +235,10 @@ where
     /// assert_eq!(buff.split(), ([1, 2, 3, 4, 5].as_slice(), [].as_slice()));
     /// ```
     #[unstable(feature = "cursor_split", issue = "86369")]
+    #[ensures(|&(before, after): &(&[u8], &[u8])| {
+        let len = self.inner.as_ref().len();
+        before.len() as u64 == self.pos.min(len as u64) && before.len() + after.len() == len
+    })]
     pub fn split(&self) -> (&[u8], &[u8]) {
         let slice = self.inner.as_ref();
         let pos = self.pos.min(slice.len() as u64);
@@ -290,6 +299,11 @@ impl<T> io::Seek for Cursor<T>
 where
     T: AsRef<[u8]>,
 {
+    #[cfg_attr(kani, kani::modifies(self))]
+    #[ensures(|result: &io::Result<u64>| match result {
+        Ok(pos) => self.pos == *pos,
+        Err(_) => self.pos == old(self.pos),
+    })]
     fn seek(&mut self, style: SeekFrom) -> io::Result<u64> {
         let (base_pos, offset) = match style {
             SeekFrom::Start(n) => {
@@ -325,6 +339,12 @@ impl<T> Read for Cursor<T>
 where
     T: AsRef<[u8]>,
 {
+    #[cfg_attr(kani, kani::modifies(self, buf))]
+    #[ensures(|result: &io::Result<usize>| result.as_ref().is_ok_and(|&n| {
+        n <= buf.len()
+            && n <= old(Cursor::split(self).1.len())
+            && old(self.pos).checked_add(n as u64) == Some(self.pos)
+    }))]
     fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
         let n = Read::read(&mut Cursor::split(self).1, buf)?;
         self.pos += n as u64;
@@ -357,6 +377,17 @@ where
         true
     }
 
+    #[cfg_attr(kani, kani::modifies(self, buf))]
+    #[ensures(|result: &io::Result<()>| {
+        let remaining = old(Cursor::split(self).1.len());
+        match result {
+            Ok(()) => {
+                buf.len() <= remaining
+                    && old(self.pos).checked_add(buf.len() as u64) == Some(self.pos)
+            }
+            Err(_) => buf.len() > remaining && self.pos == self.inner.as_ref().len() as u64,
+        }
+    })]
     fn read_exact(&mut self, buf: &mut [u8]) -> io::Result<()> {
         let result = Read::read_exact(&mut Cursor::split(self).1, buf);
 
@@ -408,6 +439,8 @@ where
     fn fill_buf(&mut self) -> io::Result<&[u8]> {
         Ok(Cursor::split(self).1)
     }
+    #[cfg_attr(kani, kani::modifies(self))]
+    #[ensures(|_| old(self.pos).checked_add(amt as u64) == Some(self.pos))]
     fn consume(&mut self, amt: usize) {
         self.pos += amt as u64;
     }
@@ -415,6 +448,11 @@ where
 
 // Non-resizing write implementation
 #[inline]
+#[cfg_attr(kani, kani::modifies(pos_mut, slice))]
+#[ensures(|result: &io::Result<usize>| result.as_ref().is_ok_and(|&n| {
+    n == buf.len().min(slice.len() - old(cmp::min(*pos_mut, slice.len() as u64)) as usize)
+        && old(*pos_mut).checked_add(n as u64) == Some(*pos_mut)
+}))]
 fn slice_write(pos_mut: &mut u64, slice: &mut [u8], buf: &[u8]) -> io::Result<usize> {
     let pos = cmp::min(*pos_mut, slice.len() as u64);
     let amt = (&mut slice[(pos as usize)..]).write(buf)?;
@@ -461,6 +499,14 @@ fn slice_write_all_vectored(
 }
 
 /// Reserves the required space, and pads the vec with 0s if necessary.
+#[ensures(|result: &io::Result<usize>| match result {
+    Ok(pos) => {
+        *pos as u64 == *pos_mut
+            && *pos <= vec.len()
+            && pos.saturating_add(buf_len) <= vec.capacity()
+    }
+    Err(_) => usize::try_from(*pos_mut).is_err(),
+})]
 fn reserve_and_pad<A: Allocator>(
     pos_mut: &mut u64,
     vec: &mut Vec<u8, A>,
@@ -507,6 +553,8 @@ fn reserve_and_pad<A: Allocator>(
 /// # Safety
 ///
 /// `vec` must have `buf.len()` spare capacity.
+#[requires(pos.checked_add(buf.len()).is_some_and(|end| end <= vec.capacity()))]
+#[ensures(|&end| end == pos + buf.len())]
 unsafe fn vec_write_all_unchecked<A>(pos: usize, vec: &mut Vec<u8, A>, buf: &[u8]) -> usize
 where
     A: Allocator,
@@ -755,3 +803,122 @@ impl<const N: usize> Write for Cursor<[u8; N]> {
         Ok(())
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::alloc::Global;
+
+    // `Cursor` position proofs
+
+    const LEN: usize = 8;
+
+    fn any_cursor(bytes: &[u8; LEN]) -> Cursor<&[u8]> {
+        let mut cursor = Cursor::new(kani::slice::any_slice_of_array(bytes));
+        cursor.set_position(kani::any());
+        cursor
+    }
+
+    fn any_seek_from() -> SeekFrom {
+        match kani::any::<u8>() % 3 {
+            0 => SeekFrom::Start(kani::any()),
+            1 => SeekFrom::End(kani::any()),
+            _ => SeekFrom::Current(kani::any()),
+        }
+    }
+
+    #[kani::proof_for_contract(Cursor::<&[u8]>::split)]
+    fn check_split() {
+        let bytes: [u8; LEN] = kani::any();
+        let cursor = any_cursor(&bytes);
+        let _ = cursor.split();
+    }
+
+    #[kani::proof_for_contract(<Cursor<&[u8]> as io::Seek>::seek)]
+    fn check_seek() {
+        let bytes: [u8; LEN] = kani::any();
+        let mut cursor = any_cursor(&bytes);
+        let _ = cursor.seek(any_seek_from());
+    }
+
+    #[kani::proof_for_contract(<Cursor<&[u8]> as Read>::read)]
+    #[kani::unwind(9)]
+    fn check_read() {
+        let bytes: [u8; LEN] = kani::any();
+        let mut cursor = any_cursor(&bytes);
+        let mut buf = [0u8; LEN];
+        let _ = cursor.read(kani::slice::any_slice_of_array_mut(&mut buf));
+    }
+
+    #[kani::proof_for_contract(<Cursor<&[u8]> as Read>::read_exact)]
+    #[kani::unwind(9)]
+    fn check_read_exact() {
+        let bytes: [u8; LEN] = kani::any();
+        let mut cursor = any_cursor(&bytes);
+        let mut buf = [0u8; LEN];
+        let _ = cursor.read_exact(kani::slice::any_slice_of_array_mut(&mut buf));
+    }
+
+    #[kani::proof_for_contract(<Cursor<&[u8]> as BufRead>::consume)]
+    fn check_consume() {
+        let bytes: [u8; LEN] = kani::any();
+        let mut cursor = any_cursor(&bytes);
+        let amt = kani::any_where(|&amt: &usize| amt <= Cursor::split(&cursor).1.len());
+        cursor.consume(amt);
+    }
+
+    #[kani::proof_for_contract(slice_write)]
+    #[kani::unwind(9)]
+    fn check_slice_write() {
+        let mut pos: u64 = kani::any();
+        let mut slice: [u8; LEN] = kani::any();
+        let buf: [u8; LEN] = kani::any();
+        let _ = slice_write(
+            &mut pos,
+            kani::slice::any_slice_of_array_mut(&mut slice),
+            kani::slice::any_slice_of_array(&buf),
+        );
+    }
+
+    #[kani::proof_for_contract(reserve_and_pad::<Global>)]
+    #[kani::unwind(9)]
+    fn check_reserve_and_pad() {
+        let mut pos: u64 = kani::any_where(|pos: &u64| *pos <= LEN as u64);
+        let len: usize = kani::any_where(|len: &usize| *len <= LEN);
+        let mut vec = vec![0u8; len];
+        let _ = reserve_and_pad(&mut pos, &mut vec, kani::any_where(|n: &usize| *n <= LEN));
+    }
+
+    #[kani::proof_for_contract(vec_write_all_unchecked::<Global>)]
+    fn check_vec_write_all_unchecked() {
+        let mut vec: Vec<u8> = Vec::with_capacity(2 * LEN);
+        let buf: [u8; LEN] = kani::any();
+        unsafe {
+            vec_write_all_unchecked(kani::any(), &mut vec, kani::slice::any_slice_of_array(&buf));
+        }
+    }
+
+    /// Writing through a `Cursor<Vec<u8>>` zero-pads any gap between the old end of the
+    /// vector and the position, then lands the bytes at the position.
+    #[kani::proof]
+    #[kani::unwind(17)]
+    fn check_vec_write() {
+        let pos: u64 = kani::any_where(|pos: &u64| *pos <= LEN as u64);
+        let len: usize = kani::any_where(|len: &usize| *len <= LEN);
+        let mut cursor = Cursor::new(vec![1u8; len]);
+        cursor.set_position(pos);
+        let bytes: [u8; LEN] = kani::any();
+        let buf = kani::slice::any_slice_of_array(&bytes);
+
+        assert_eq!(cursor.write(buf).unwrap(), buf.len());
+        assert_eq!(cursor.position(), pos + buf.len() as u64);
+        let vec = cursor.into_inner();
+        let pos = pos as usize;
+        assert_eq!(vec.len(), len.max(pos + buf.len()));
+        if len < pos {
+            assert!(vec[len..pos].iter().all(|&b| b == 0));
+        }
+        assert_eq!(&vec[pos..pos + buf.len()], buf);
+    }
+}
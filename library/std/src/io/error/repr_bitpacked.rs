@@ -102,10 +102,16 @@
 //! to use a pointer type to store something that may hold an integer, some of
 //! the time.
 
+#[cfg(kani)]
+use core::kani;
 use core::marker::PhantomData;
 use core::num::NonZeroUsize;
 use core::ptr::NonNull;
 
+use safety::ensures;
+#[cfg(kani)]
+use safety::requires;
+
 use super::{Custom, ErrorData, ErrorKind, RawOsError, SimpleMessage};
 
 // The 2 least-significant bits are used as tag.
@@ -142,6 +148,11 @@ impl Repr {
         }
     }
 
+    #[ensures(|repr| repr.0.as_ptr().addr() & TAG_MASK == TAG_CUSTOM)]
+    #[ensures(|repr| matches!(
+        repr.data(),
+        ErrorData::Custom(c) if core::ptr::eq(c, old(&*b as *const Custom))
+    ))]
     pub(super) fn new_custom(b: Box<Custom>) -> Self {
         let p = Box::into_raw(b).cast::<u8>();
         // Should only be possible if an allocator handed out a pointer with
@@ -172,6 +183,8 @@ impl Repr {
         res
     }
 
+    #[ensures(|repr| repr.0.as_ptr().addr() & TAG_MASK == TAG_OS)]
+    #[ensures(|repr| matches!(repr.data(), ErrorData::Os(c) if c == code))]
     #[inline]
     pub(super) fn new_os(code: RawOsError) -> Self {
         let utagged = ((code as usize) << 32) | TAG_OS;
@@ -189,6 +202,8 @@ impl Repr {
         res
     }
 
+    #[ensures(|repr| repr.0.as_ptr().addr() & TAG_MASK == TAG_SIMPLE)]
+    #[ensures(|repr| matches!(repr.data(), ErrorData::Simple(k) if k == kind))]
     #[inline]
     pub(super) fn new_simple(kind: ErrorKind) -> Self {
         let utagged = ((kind as usize) << 32) | TAG_SIMPLE;
@@ -207,6 +222,8 @@ impl Repr {
         res
     }
 
+    #[ensures(|repr| repr.0.as_ptr().addr() & TAG_MASK == TAG_SIMPLE_MESSAGE)]
+    #[ensures(|repr| matches!(repr.data(), ErrorData::SimpleMessage(s) if core::ptr::eq(s, m)))]
     #[inline]
     pub(super) const fn new_simple_message(m: &'static SimpleMessage) -> Self {
         // Safety: References are never null.
@@ -249,6 +266,7 @@ impl Drop for Repr {
 //
 // Safety: `ptr`'s bits should be encoded as described in the document at the
 // top (it should `some_repr.0`)
+#[cfg_attr(kani, requires(is_valid_repr(ptr)))]
 #[inline]
 unsafe fn decode_repr<C, F>(ptr: NonNull<()>, make_custom: F) -> ErrorData<C>
 where
@@ -292,6 +310,20 @@ where
     }
 }
 
+/// The safety precondition of `decode_repr`: the tag selects a payload that
+/// `decode_repr` can turn back into an `ErrorData`.
+#[cfg(kani)]
+fn is_valid_repr(ptr: NonNull<()>) -> bool {
+    let bits = ptr.as_ptr().addr();
+    match bits & TAG_MASK {
+        TAG_SIMPLE => kind_from_prim((bits >> 32) as u32).is_some(),
+        TAG_SIMPLE_MESSAGE => {
+            core::ub_checks::can_dereference(ptr.cast::<SimpleMessage>().as_ptr())
+        }
+        _ => true,
+    }
+}
+
 // This compiles to the same code as the check+transmute, but doesn't require
 // unsafe, or to hard-code max ErrorKind or its size in a way the compiler
 // couldn't verify.
@@ -418,3 +450,100 @@ static_assert!(@usize_eq: size_of::<Repr>(), 8);
 static_assert!(@usize_eq: size_of::<Option<Repr>>(), 8);
 static_assert!(@usize_eq: size_of::<Result<(), Repr>>(), 8);
 static_assert!(@usize_eq: size_of::<Result<usize, Repr>>(), 16);
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::{error, fmt};
+
+    // `Repr` encoding proofs
+
+    #[derive(Debug)]
+    struct DummyError;
+
+    impl fmt::Display for DummyError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("dummy")
+        }
+    }
+
+    impl error::Error for DummyError {}
+
+    static MESSAGE: SimpleMessage =
+        SimpleMessage { kind: ErrorKind::InvalidInput, message: "invalid input" };
+
+    fn any_kind() -> ErrorKind {
+        let kind = kind_from_prim(kani::any());
+        kani::assume(kind.is_some());
+        kind.unwrap()
+    }
+
+    fn any_custom() -> Box<Custom> {
+        Box::new(Custom { kind: any_kind(), error: Box::new(DummyError) })
+    }
+
+    #[kani::proof_for_contract(Repr::new_os)]
+    fn check_new_os() {
+        let _ = Repr::new_os(kani::any());
+    }
+
+    #[kani::proof_for_contract(Repr::new_simple)]
+    fn check_new_simple() {
+        let _ = Repr::new_simple(any_kind());
+    }
+
+    #[kani::proof_for_contract(Repr::new_simple_message)]
+    fn check_new_simple_message() {
+        let _ = Repr::new_simple_message(&MESSAGE);
+    }
+
+    #[kani::proof_for_contract(Repr::new_custom)]
+    fn check_new_custom() {
+        let _ = Repr::new_custom(any_custom());
+    }
+
+    #[kani::proof_for_contract(decode_repr)]
+    fn check_decode_repr() {
+        let bits: NonZeroUsize = kani::any();
+        let ptr = NonNull::without_provenance(bits);
+        let _ = unsafe { decode_repr(ptr, |c| c) };
+    }
+
+    #[kani::proof]
+    fn check_kind_from_prim() {
+        let kind = any_kind();
+        assert!(kind_from_prim(kind as u32) == Some(kind));
+    }
+
+    #[kani::proof]
+    fn check_into_data_round_trip() {
+        match kani::any::<u8>() % 4 {
+            0 => {
+                let code: RawOsError = kani::any();
+                let repr = Repr::new(ErrorData::Os(code));
+                assert!(matches!(repr.into_data(), ErrorData::Os(c) if c == code));
+            }
+            1 => {
+                let kind = any_kind();
+                let repr = Repr::new(ErrorData::Simple(kind));
+                assert!(matches!(repr.into_data(), ErrorData::Simple(k) if k == kind));
+            }
+            2 => {
+                let repr = Repr::new(ErrorData::SimpleMessage(&MESSAGE));
+                assert!(matches!(
+                    repr.into_data(),
+                    ErrorData::SimpleMessage(m) if core::ptr::eq(m, &MESSAGE)
+                ));
+            }
+            _ => {
+                let custom = any_custom();
+                let (kind, addr) = (custom.kind, &*custom as *const Custom);
+                let mut repr = Repr::new(ErrorData::Custom(custom));
+                assert!(matches!(repr.data_mut(), ErrorData::Custom(c) if c.kind == kind));
+                let ErrorData::Custom(c) = repr.into_data() else { panic!("tag changed") };
+                assert!(core::ptr::eq(&*c, addr));
+            }
+        }
+    }
+}
@@ -3,6 +3,11 @@
 #![stable(feature = "io_safety", since = "1.63.0")]
 #![deny(unsafe_op_in_unsafe_fn)]
 
+#[cfg(kani)]
+use core::kani;
+
+use safety::{ensures, requires};
+
 use super::raw::{AsRawFd, FromRawFd, IntoRawFd, RawFd};
 #[cfg(not(target_os = "trusty"))]
 use crate::fs;
@@ -78,6 +83,8 @@ impl BorrowedFd<'_> {
     #[track_caller]
     #[rustc_const_stable(feature = "io_safety", since = "1.63.0")]
     #[stable(feature = "io_safety", since = "1.63.0")]
+    #[requires(fd != -1)]
+    #[ensures(|result: &Self| result.fd.as_inner() == fd)]
     pub const unsafe fn borrow_raw(fd: RawFd) -> Self {
         Self { fd: ValidRawFd::new(fd).expect("fd != -1"), _phantom: PhantomData }
     }
@@ -161,6 +168,8 @@ impl FromRawFd for OwnedFd {
     /// [io-safety]: io#io-safety
     #[inline]
     #[track_caller]
+    #[requires(fd >= 0)]
+    #[ensures(|result: &Self| result.fd.as_inner() == fd)]
     unsafe fn from_raw_fd(fd: RawFd) -> Self {
         Self { fd: ValidRawFd::new(fd).expect("fd != -1") }
     }
@@ -552,3 +561,57 @@ impl From<OwnedFd> for io::PipeWriter {
         Self(FromInner::from_inner(owned_fd))
     }
 }
+
+#[cfg(all(kani, unix))]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::sys::kani_libc;
+
+    // `BorrowedFd`/`OwnedFd` construction proofs
+
+    fn any_owned_fd() -> OwnedFd {
+        let fd: RawFd = kani::any_where(|fd: &RawFd| *fd >= 0);
+        unsafe { OwnedFd::from_raw_fd(fd) }
+    }
+
+    #[kani::proof_for_contract(BorrowedFd::borrow_raw)]
+    fn check_borrow_raw() {
+        let fd: RawFd = kani::any();
+        let _ = unsafe { BorrowedFd::borrow_raw(fd) };
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    #[kani::stub_verified(BorrowedFd::borrow_raw)]
+    fn check_borrow_raw_all_ones() {
+        let _ = unsafe { BorrowedFd::borrow_raw(u32::MAX as RawFd) };
+    }
+
+    #[kani::proof_for_contract(<OwnedFd as FromRawFd>::from_raw_fd)]
+    #[kani::stub(libc::fcntl, kani_libc::fcntl)]
+    #[kani::stub(libc::close, kani_libc::close)]
+    fn check_from_raw_fd() {
+        let fd: RawFd = kani::any();
+        let _ = unsafe { OwnedFd::from_raw_fd(fd) };
+    }
+
+    #[kani::proof]
+    #[kani::stub(libc::fcntl, kani_libc::fcntl)]
+    #[kani::stub(libc::close, kani_libc::close)]
+    fn check_drop() {
+        drop(any_owned_fd());
+    }
+
+    #[kani::proof]
+    #[kani::stub(libc::fcntl, kani_libc::fcntl)]
+    #[kani::stub(libc::close, kani_libc::close)]
+    #[kani::stub(crate::sys::os::errno_location, kani_libc::errno_location)]
+    fn check_try_clone_to_owned() {
+        let owned = any_owned_fd();
+        match owned.as_fd().try_clone_to_owned() {
+            Ok(clone) => assert!(clone.as_raw_fd() >= 3),
+            Err(e) => assert!(e.raw_os_error().is_some_and(|errno| errno > 0)),
+        }
+    }
+}
@@ -1,3 +1,10 @@
+#[cfg(kani)]
+use core::kani;
+
+use safety::ensures;
+#[cfg(kani)]
+use safety::requires;
+
 use crate::bstr::ByteStr;
 use crate::ffi::OsStr;
 #[cfg(any(doc, target_os = "android", target_os = "linux"))]
@@ -23,6 +30,22 @@ mod libc {
 
 const SUN_PATH_OFFSET: usize = mem::offset_of!(libc::sockaddr_un, sun_path);
 
+/// Checks that `len` describes a prefix of `addr.sun_path`, as `SocketAddr::address` assumes.
+#[cfg(kani)]
+fn is_valid_len(addr: &libc::sockaddr_un, len: libc::socklen_t) -> bool {
+    let len = len as usize;
+    len >= SUN_PATH_OFFSET && len - SUN_PATH_OFFSET <= addr.sun_path.len()
+}
+
+#[ensures(|result| {
+    let bytes = path.as_os_str().as_bytes();
+    let sun_path_len = size_of::<libc::sockaddr_un>() - SUN_PATH_OFFSET;
+    result.is_ok() == (!bytes.contains(&0) && bytes.len() < sun_path_len)
+})]
+#[cfg_attr(kani, ensures(|result| result.as_ref().map_or(true, |(addr, len)| {
+    let path_len = *len as usize - SUN_PATH_OFFSET;
+    is_valid_len(addr, *len) && (path_len == 0 || addr.sun_path[path_len - 1] == 0)
+})))]
 pub(super) fn sockaddr_un(path: &Path) -> io::Result<(libc::sockaddr_un, libc::socklen_t)> {
     // SAFETY: All zeros is a valid representation for `sockaddr_un`.
     let mut addr: libc::sockaddr_un = unsafe { mem::zeroed() };
@@ -101,6 +124,10 @@ impl SocketAddr {
         }
     }
 
+    #[cfg_attr(kani, requires(len == 0 || is_valid_len(&addr, len)))]
+    #[cfg_attr(kani, ensures(|result| {
+        result.as_ref().map_or(true, |addr| is_valid_len(&addr.addr, addr.len))
+    }))]
     pub(super) fn from_parts(
         addr: libc::sockaddr_un,
         mut len: libc::socklen_t,
@@ -301,3 +328,75 @@ impl fmt::Debug for SocketAddr {
         }
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // `sockaddr_un` / `SocketAddr::from_parts` proofs
+
+    /// Longer than `sun_path` on every supported target, so both the accepted
+    /// and the rejected lengths are covered.
+    const MAX_PATH_LEN: usize = 110;
+
+    fn any_sockaddr_un() -> libc::sockaddr_un {
+        let mut addr: libc::sockaddr_un = unsafe { mem::zeroed() };
+        addr.sun_family = kani::any();
+        addr.sun_path = kani::any();
+        addr
+    }
+
+    #[kani::proof_for_contract(sockaddr_un)]
+    #[kani::unwind(111)]
+    fn check_sockaddr_un() {
+        let bytes: [u8; MAX_PATH_LEN] = kani::any();
+        let path = Path::new(OsStr::from_bytes(kani::slice::any_slice_of_array(&bytes)));
+        let _ = sockaddr_un(path);
+    }
+
+    #[kani::proof_for_contract(SocketAddr::from_parts)]
+    fn check_from_parts() {
+        let _ = SocketAddr::from_parts(any_sockaddr_un(), kani::any());
+    }
+
+    #[kani::proof]
+    #[kani::unwind(111)]
+    fn check_from_pathname_round_trip() {
+        let bytes: [u8; MAX_PATH_LEN] = kani::any();
+        let path = Path::new(OsStr::from_bytes(kani::slice::any_slice_of_array(&bytes)));
+        if let Ok(addr) = SocketAddr::from_pathname(path) {
+            if path.as_os_str().is_empty() {
+                assert!(addr.is_unnamed());
+            } else {
+                assert_eq!(addr.as_pathname(), Some(path));
+            }
+        }
+    }
+
+    #[kani::proof]
+    #[kani::unwind(111)]
+    fn check_address_from_parts() {
+        let len: libc::socklen_t = kani::any();
+        let addr = any_sockaddr_un();
+        kani::assume(len == 0 || is_valid_len(&addr, len));
+        if let Ok(addr) = SocketAddr::from_parts(addr, len) {
+            let _ = addr.is_unnamed();
+            let _ = addr.as_pathname();
+        }
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[kani::proof]
+    #[kani::unwind(111)]
+    fn check_from_abstract_name_round_trip() {
+        use crate::os::net::linux_ext::addr::SocketAddrExt;
+
+        let bytes: [u8; MAX_PATH_LEN] = kani::any();
+        let name = kani::slice::any_slice_of_array(&bytes);
+        match SocketAddr::from_abstract_name(name) {
+            Ok(addr) => assert_eq!(addr.as_abstract_name(), Some(name)),
+            Err(_) => assert!(name.len() + 1 > size_of::<libc::sockaddr_un>() - SUN_PATH_OFFSET),
+        }
+    }
+}
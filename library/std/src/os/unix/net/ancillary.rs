@@ -1,5 +1,10 @@
 // FIXME: This is currently disabled on *BSD.
 
+#[cfg(kani)]
+use core::kani;
+
+use safety::{ensures, requires};
+
 use super::{SocketAddr, sockaddr_un};
 use crate::io::{self, IoSlice, IoSliceMut};
 use crate::marker::PhantomData;
@@ -86,6 +91,11 @@ pub(super) fn send_vectored_with_ancillary_to(
     }
 }
 
+#[requires(*length <= buffer.len())]
+#[ensures(|_| *length <= buffer.len())]
+#[ensures(|added| if *added { *length > old(*length) } else { *length == old(*length) })]
+#[cfg_attr(kani, kani::modifies(buffer))]
+#[cfg_attr(kani, kani::modifies(length))]
 fn add_to_ancillary_data<T>(
     buffer: &mut [u8],
     length: &mut usize,
@@ -177,6 +187,10 @@ impl<'a, T> AncillaryDataIter<'a, T> {
 impl<'a, T> Iterator for AncillaryDataIter<'a, T> {
     type Item = T;
 
+    #[ensures(|unit| unit.is_some() == (old(self.data.len()) >= size_of::<T>()))]
+    #[ensures(|unit| unit.is_none() || self.data.len() == old(self.data.len()) - size_of::<T>())]
+    #[ensures(|unit| unit.is_some() || self.data.len() == old(self.data.len()))]
+    #[cfg_attr(kani, kani::modifies(self))]
     fn next(&mut self) -> Option<T> {
         if size_of::<T>() <= self.data.len() {
             unsafe {
@@ -497,6 +511,7 @@ impl<'a> AncillaryData<'a> {
         AncillaryData::ScmCredentials(scm_credentials)
     }
 
+    #[cfg_attr(kani, requires(cmsg_data_in_bounds(cmsg)))]
     fn try_from_cmsghdr(cmsg: &'a libc::cmsghdr) -> Result<Self, AncillaryError> {
         unsafe {
             let cmsg_len_zero = libc::CMSG_LEN(0) as usize;
@@ -525,6 +540,19 @@ impl<'a> AncillaryData<'a> {
     }
 }
 
+/// Checks that the payload described by `cmsg.cmsg_len` is readable, which
+/// `try_from_cmsghdr` trusts the kernel to guarantee.
+#[cfg(kani)]
+fn cmsg_data_in_bounds(cmsg: &libc::cmsghdr) -> bool {
+    let cmsg_len_zero = unsafe { libc::CMSG_LEN(0) } as usize;
+    let cmsg_len = cmsg.cmsg_len as usize;
+    cmsg_len >= cmsg_len_zero
+        && core::ub_checks::can_dereference(crate::ptr::slice_from_raw_parts(
+            unsafe { libc::CMSG_DATA(cmsg) },
+            cmsg_len - cmsg_len_zero,
+        ))
+}
+
 /// This struct is used to iterate through the control messages.
 #[must_use = "iterators are lazy and do nothing unless consumed"]
 #[unstable(feature = "unix_socket_ancillary_data", issue = "76915")]
@@ -796,3 +824,173 @@ impl<'a> SocketAncillary<'a> {
         self.truncated = false;
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // `add_to_ancillary_data` proofs
+
+    #[kani::proof_for_contract(add_to_ancillary_data)]
+    #[kani::unwind(5)]
+    fn check_add_to_ancillary_data_fds() {
+        let mut buffer: [u8; 64] = kani::any();
+        let buffer = kani::slice::any_slice_of_array_mut(&mut buffer);
+        let mut length: usize = kani::any();
+        let fds: [RawFd; 4] = kani::any();
+        let fds = kani::slice::any_slice_of_array(&fds);
+        add_to_ancillary_data(buffer, &mut length, fds, libc::SOL_SOCKET, libc::SCM_RIGHTS);
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[kani::proof_for_contract(add_to_ancillary_data)]
+    #[kani::unwind(5)]
+    fn check_add_to_ancillary_data_creds() {
+        let mut buffer: [u8; 64] = kani::any();
+        let buffer = kani::slice::any_slice_of_array_mut(&mut buffer);
+        let mut length: usize = kani::any();
+        let creds = [SocketCred::new(), SocketCred::new()];
+        let creds = kani::slice::any_slice_of_array(&creds);
+        add_to_ancillary_data(buffer, &mut length, creds, libc::SOL_SOCKET, libc::SCM_CREDENTIALS);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_add_fds_round_trip() {
+        let mut buffer: [u8; 64] = kani::any();
+        let buffer = kani::slice::any_slice_of_array_mut(&mut buffer);
+        let mut ancillary = SocketAncillary::new(buffer);
+        let fds: [RawFd; 2] = kani::any();
+        let fds = kani::slice::any_slice_of_array(&fds);
+        if ancillary.add_fds(fds) {
+            assert!(!ancillary.is_empty());
+            assert!(ancillary.len() <= ancillary.capacity());
+            let mut messages = ancillary.messages();
+            let Some(Ok(AncillaryData::ScmRights(rights))) = messages.next() else {
+                panic!("expected a single SCM_RIGHTS message");
+            };
+            assert!(rights.eq(fds.iter().copied()));
+            assert!(messages.next().is_none());
+        } else {
+            assert!(ancillary.is_empty());
+        }
+    }
+
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_add_fds_repeated() {
+        let mut buffer: [u8; 64] = kani::any();
+        let buffer = kani::slice::any_slice_of_array_mut(&mut buffer);
+        let mut ancillary = SocketAncillary::new(buffer);
+        let fds: [RawFd; 2] = kani::any();
+        let first = kani::slice::any_slice_of_array(&fds);
+        let second = kani::slice::any_slice_of_array(&fds);
+        ancillary.add_fds(first);
+        let length = ancillary.len();
+        if !ancillary.add_fds(second) {
+            assert_eq!(ancillary.len(), length);
+        }
+        assert!(ancillary.len() <= ancillary.capacity());
+        assert!(!ancillary.truncated());
+    }
+
+    // `Messages` / `AncillaryDataIter` parsing proofs
+
+    const CONTROL_LEN: usize = 64;
+
+    #[repr(C, align(8))]
+    struct ControlBuffer([u8; CONTROL_LEN]);
+
+    fn cmsg_len_zero() -> usize {
+        unsafe { libc::CMSG_LEN(0) as usize }
+    }
+
+    fn cmsg_align(len: usize) -> usize {
+        let align = align_of::<libc::cmsghdr>();
+        (len + align - 1) & !(align - 1)
+    }
+
+    /// Whether every header reachable from the start of `buffer` describes a
+    /// payload that lies within `buffer`.
+    fn is_well_formed(buffer: &[u8]) -> bool {
+        let mut offset = 0;
+        while offset + size_of::<libc::cmsghdr>() <= buffer.len() {
+            let cmsg: libc::cmsghdr = unsafe { read_unaligned(buffer[offset..].as_ptr().cast()) };
+            let cmsg_len = cmsg.cmsg_len as usize;
+            if cmsg_len < cmsg_len_zero() || cmsg_len > buffer.len() - offset {
+                return false;
+            }
+            offset += cmsg_align(cmsg_len);
+        }
+        true
+    }
+
+    fn write_header(buffer: &mut [u8], offset: usize, cmsg_len: usize, cmsg_type: libc::c_int) {
+        let cmsg =
+            libc::cmsghdr { cmsg_len: cmsg_len as _, cmsg_level: libc::SOL_SOCKET, cmsg_type };
+        unsafe { crate::ptr::write_unaligned(buffer[offset..].as_mut_ptr().cast(), cmsg) };
+    }
+
+    #[kani::proof_for_contract(AncillaryData::try_from_cmsghdr)]
+    fn check_try_from_cmsghdr() {
+        let control = ControlBuffer(kani::any());
+        let cmsg = unsafe { &*control.0.as_ptr().cast::<libc::cmsghdr>() };
+        let _ = AncillaryData::try_from_cmsghdr(cmsg);
+    }
+
+    #[kani::proof_for_contract(<AncillaryDataIter<'_, RawFd> as Iterator>::next)]
+    fn check_ancillary_data_iter_next_fds() {
+        let data: [u8; 16] = kani::any();
+        let mut iter =
+            unsafe { AncillaryDataIter::<RawFd>::new(kani::slice::any_slice_of_array(&data)) };
+        let _ = iter.next();
+    }
+
+    #[cfg(any(target_os = "android", target_os = "linux"))]
+    #[kani::proof_for_contract(<AncillaryDataIter<'_, libc::ucred> as Iterator>::next)]
+    fn check_ancillary_data_iter_next_creds() {
+        let data: [u8; 16] = kani::any();
+        let mut iter = unsafe {
+            AncillaryDataIter::<libc::ucred>::new(kani::slice::any_slice_of_array(&data))
+        };
+        let _ = iter.next();
+    }
+
+    #[kani::proof]
+    #[kani::unwind(17)]
+    fn check_messages_well_formed() {
+        let control = ControlBuffer(kani::any());
+        let buffer = kani::slice::any_slice_of_array(&control.0);
+        kani::assume(is_well_formed(buffer));
+        let messages = Messages { buffer, current: None };
+        for message in messages.flatten() {
+            match message {
+                AncillaryData::ScmRights(rights) => rights.for_each(drop),
+                #[cfg(any(target_os = "android", target_os = "linux"))]
+                AncillaryData::ScmCredentials(creds) => creds.for_each(drop),
+            }
+        }
+    }
+
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_messages_truncated_payload() {
+        let mut control = ControlBuffer(kani::any());
+        let fds_len = size_of::<RawFd>();
+        let first_len = cmsg_len_zero() + fds_len;
+        write_header(&mut control.0, 0, first_len, libc::SCM_RIGHTS);
+        // The second header claims a payload that extends past the end of the buffer.
+        let second = cmsg_align(first_len);
+        let second_len: usize =
+            kani::any_where(|len| *len > CONTROL_LEN - second && *len <= 2 * CONTROL_LEN);
+        write_header(&mut control.0, second, second_len, libc::SCM_RIGHTS);
+
+        let mut messages = Messages { buffer: &control.0, current: None };
+        let Some(Ok(AncillaryData::ScmRights(rights))) = messages.next() else {
+            panic!("expected the first SCM_RIGHTS message");
+        };
+        assert_eq!(rights.count(), 1);
+        assert!(messages.next().is_none());
+    }
+}
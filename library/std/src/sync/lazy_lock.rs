@@ -1,3 +1,8 @@
+#[cfg(kani)]
+use core::kani;
+
+use safety::ensures;
+
 use super::poison::once::ExclusiveState;
 use crate::cell::UnsafeCell;
 use crate::mem::ManuallyDrop;
@@ -117,6 +122,7 @@ impl<T, F: FnOnce() -> T> LazyLock<T, F> {
     /// assert_eq!(LazyLock::into_inner(lazy).ok(), Some("HELLO, WORLD!".to_string()));
     /// ```
     #[unstable(feature = "lazy_cell_into_inner", issue = "125623")]
+    #[ensures(|result| result.is_ok() == old(LazyLock::get(&this).is_some()))]
     pub fn into_inner(mut this: Self) -> Result<T, F> {
         let state = this.once.state();
         match state {
@@ -205,6 +211,7 @@ impl<T, F: FnOnce() -> T> LazyLock<T, F> {
     /// ```
     #[inline]
     #[stable(feature = "lazy_cell", since = "1.80.0")]
+    #[ensures(|result| LazyLock::get(this).is_some_and(|value| ptr::eq(value, *result)))]
     pub fn force(this: &LazyLock<T, F>) -> &T {
         this.once.call_once(|| {
             // SAFETY: `call_once` only runs this closure once, ever.
@@ -358,3 +365,95 @@ unsafe impl<T: Sync + Send, F: Send> Sync for LazyLock<T, F> {}
 impl<T: RefUnwindSafe + UnwindSafe, F: UnwindSafe> RefUnwindSafe for LazyLock<T, F> {}
 #[stable(feature = "lazy_cell", since = "1.80.0")]
 impl<T: UnwindSafe, F: UnwindSafe> UnwindSafe for LazyLock<T, F> {}
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::cell::Cell;
+
+    // The `Once` state decides which field of `Data` is initialized: `f` while it
+    // is incomplete, `value` once it is complete, and neither once it is poisoned.
+    // Kani aborts on panics, so poisoned locks are built by setting the state
+    // directly, as `force_mut` does when its initializer panics.
+
+    fn poisoned<T, F>(f: F) -> LazyLock<T, F> {
+        let mut lock = LazyLock::new(f);
+        // SAFETY: `f` is dropped here and the state says it is gone.
+        unsafe { ManuallyDrop::drop(&mut lock.data.get_mut().f) };
+        lock.once.set_state(ExclusiveState::Poisoned);
+        lock
+    }
+
+    #[kani::proof_for_contract(LazyLock::force)]
+    fn check_force() {
+        let value: u64 = kani::any();
+        let lock = LazyLock::new(move || value);
+        if kani::any() {
+            LazyLock::force(&lock);
+        }
+        assert_eq!(*LazyLock::force(&lock), value);
+    }
+
+    #[kani::proof_for_contract(LazyLock::into_inner)]
+    fn check_into_inner() {
+        let value: u32 = kani::any();
+        let lock = LazyLock::new(move || value);
+        let forced: bool = kani::any();
+        if forced {
+            LazyLock::force(&lock);
+        }
+        match LazyLock::into_inner(lock) {
+            Ok(v) => assert!(forced && v == value),
+            Err(f) => assert!(!forced && f() == value),
+        }
+    }
+
+    #[kani::proof]
+    fn check_force_runs_once() {
+        let runs = Cell::new(0);
+        let value: [u32; 2] = kani::any();
+        let lock = LazyLock::new(|| {
+            runs.set(runs.get() + 1);
+            value
+        });
+        assert!(LazyLock::get(&lock).is_none());
+        assert_eq!(*lock, value);
+        assert_eq!(*lock, value);
+        assert_eq!(LazyLock::get(&lock), Some(&value));
+        assert_eq!(runs.get(), 1);
+    }
+
+    #[kani::proof]
+    fn check_force_mut() {
+        let value: u32 = kani::any();
+        let mut lock = LazyLock::new(move || value);
+        if kani::any() {
+            LazyLock::force(&lock);
+        }
+        *LazyLock::force_mut(&mut lock) = value.wrapping_add(1);
+        assert_eq!(LazyLock::get_mut(&mut lock), Some(&mut value.wrapping_add(1)));
+        assert!(lock.once.is_completed());
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_force_poisoned() {
+        let lock = poisoned(|| 1u32);
+        assert!(LazyLock::get(&lock).is_none());
+        LazyLock::force(&lock);
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_force_mut_poisoned() {
+        let mut lock = poisoned(|| 1u32);
+        LazyLock::force_mut(&mut lock);
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_into_inner_poisoned() {
+        let _ = LazyLock::into_inner(poisoned(|| 1u32));
+    }
+}
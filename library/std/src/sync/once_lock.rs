@@ -1,3 +1,6 @@
+#[cfg(kani)]
+use core::kani;
+
 use crate::cell::UnsafeCell;
 use crate::fmt;
 use crate::marker::PhantomData;
@@ -689,3 +692,74 @@ unsafe impl<#[may_dangle] T> Drop for OnceLock<T> {
         }
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::cell::Cell;
+
+    #[kani::proof]
+    #[kani::unwind(4)]
+    fn check_get_or_try_init_runs_once() {
+        let cell: OnceLock<[u32; 2]> = OnceLock::new();
+        let value: [u32; 2] = kani::any();
+        let inits = Cell::new(0);
+        let mut initialized = false;
+        for _ in 0..3 {
+            // `get` sees either nothing or the complete value.
+            match cell.get() {
+                Some(v) => assert!(initialized && *v == value),
+                None => assert!(!initialized),
+            }
+            let fail: bool = kani::any();
+            let result = cell.get_or_try_init(|| {
+                assert!(!initialized);
+                if fail {
+                    Err(())
+                } else {
+                    inits.set(inits.get() + 1);
+                    Ok(value)
+                }
+            });
+            match result {
+                Ok(v) => {
+                    assert_eq!(*v, value);
+                    initialized = true;
+                }
+                Err(()) => assert!(!initialized && cell.get().is_none()),
+            }
+        }
+        assert_eq!(inits.get(), initialized as usize);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(4)]
+    fn check_get_or_init_runs_once() {
+        let cell: OnceLock<u64> = OnceLock::new();
+        let values: [u64; 3] = kani::any();
+        let runs = Cell::new(0);
+        for i in 0..3 {
+            let v = cell.get_or_init(|| {
+                runs.set(runs.get() + 1);
+                values[i]
+            });
+            assert_eq!(*v, values[0]);
+        }
+        assert_eq!(runs.get(), 1);
+        assert_eq!(cell.into_inner(), Some(values[0]));
+    }
+
+    #[kani::proof]
+    fn check_set_and_take() {
+        let mut cell: OnceLock<u32> = OnceLock::new();
+        let (a, b, c): (u32, u32, u32) = kani::any();
+        assert_eq!(cell.set(a), Ok(()));
+        assert_eq!(cell.set(b), Err(b));
+        assert_eq!(cell.try_insert(c), Err((&a, c)));
+        assert_eq!(cell.get(), Some(&a));
+        assert_eq!(cell.take(), Some(a));
+        assert!(cell.get().is_none());
+        assert_eq!(cell.try_insert(b), Ok(&b));
+    }
+}
@@ -73,6 +73,11 @@ pub use self::once::{Once, OnceState};
 pub use self::rwlock::{MappedRwLockReadGuard, MappedRwLockWriteGuard};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use self::rwlock::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+#[cfg(kani)]
+use core::kani;
+
+use safety::ensures;
+
 use crate::error::Error;
 use crate::fmt;
 #[cfg(panic = "unwind")]
@@ -112,12 +117,14 @@ impl Flag {
     }
 
     /// Checks the flag for an unguarded borrow, where we only care about existing poison.
+    #[ensures(|result| result.is_err() == self.get())]
     #[inline]
     pub fn borrow(&self) -> LockResult<()> {
         if self.get() { Err(PoisonError::new(())) } else { Ok(()) }
     }
 
     /// Checks the flag for a guarded borrow, where we may also set poison when `done`.
+    #[ensures(|result| result.is_err() == self.get())]
     #[inline]
     pub fn guard(&self) -> LockResult<Guard> {
         let ret = Guard {
@@ -127,6 +134,8 @@ impl Flag {
         if self.get() { Err(PoisonError::new(ret)) } else { Ok(ret) }
     }
 
+    #[ensures(|_| self.get() == (old(self.get()) || (!guard.panicking && thread::panicking())))]
+    #[cfg_attr(kani, kani::modifies(&self.failed))]
     #[inline]
     #[cfg(panic = "unwind")]
     pub fn done(&self, guard: &Guard) {
@@ -151,6 +160,8 @@ impl Flag {
         false
     }
 
+    #[ensures(|_| !self.get())]
+    #[cfg_attr(all(kani, panic = "unwind"), kani::modifies(&self.failed))]
     #[inline]
     pub fn clear(&self) {
         #[cfg(panic = "unwind")]
@@ -407,3 +418,69 @@ where
         Err(PoisonError { data }) => Err(PoisonError::new(f(data))),
     }
 }
+
+/// Ghost model of `thread::panicking`.
+///
+/// Kani does not support unwinding, so harnesses stub `thread::panicking` with
+/// [`panicking`](kani_model::panicking) and flip [`set_panicking`](kani_model::set_panicking)
+/// to simulate a guard being dropped while its thread unwinds.
+#[cfg(kani)]
+pub(crate) mod kani_model {
+    static mut PANICKING: bool = false;
+
+    pub(crate) fn panicking() -> bool {
+        unsafe { PANICKING }
+    }
+
+    pub(crate) fn set_panicking(panicking: bool) {
+        unsafe { PANICKING = panicking }
+    }
+}
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // `Flag` proofs
+
+    /// Returns a flag that may or may not be poisoned already.
+    fn any_flag() -> Flag {
+        let flag = Flag::new();
+        if kani::any() {
+            kani_model::set_panicking(true);
+            flag.done(&Guard {
+                #[cfg(panic = "unwind")]
+                panicking: false,
+            });
+        }
+        kani_model::set_panicking(kani::any());
+        flag
+    }
+
+    #[kani::proof_for_contract(Flag::borrow)]
+    #[kani::stub(crate::thread::panicking, kani_model::panicking)]
+    fn check_borrow() {
+        let _ = any_flag().borrow();
+    }
+
+    #[kani::proof_for_contract(Flag::guard)]
+    #[kani::stub(crate::thread::panicking, kani_model::panicking)]
+    fn check_guard() {
+        let _ = any_flag().guard();
+    }
+
+    #[cfg(panic = "unwind")]
+    #[kani::proof_for_contract(Flag::done)]
+    #[kani::stub(crate::thread::panicking, kani_model::panicking)]
+    fn check_done() {
+        let flag = any_flag();
+        flag.done(&Guard { panicking: kani::any() });
+    }
+
+    #[kani::proof_for_contract(Flag::clear)]
+    #[kani::stub(crate::thread::panicking, kani_model::panicking)]
+    fn check_clear() {
+        any_flag().clear();
+    }
+}
@@ -885,3 +885,93 @@ impl<'a, T: ?Sized> MappedMutexGuard<'a, T> {
         }
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use core::kani;
+
+    use super::*;
+    use crate::sync::poison::kani_model;
+
+    // `Mutex` poisoning proofs, run against the single-threaded `sys::sync::Mutex` model
+    // with `thread::panicking` stubbed by `poison::kani_model`.
+
+    #[kani::proof]
+    #[kani::stub(crate::thread::panicking, kani_model::panicking)]
+    fn check_poison_on_unwind() {
+        let mutex = Mutex::new(kani::any::<u8>());
+        let panicking_at_lock: bool = kani::any();
+        let panicking_at_drop: bool = kani::any();
+        kani::assume(!panicking_at_lock || panicking_at_drop);
+
+        kani_model::set_panicking(panicking_at_lock);
+        let guard = mutex.lock();
+        assert!(guard.is_ok());
+        kani_model::set_panicking(panicking_at_drop);
+        drop(guard);
+
+        // Poisoned exactly when a panic started while the guard was held.
+        let expected = cfg!(panic = "unwind") && !panicking_at_lock && panicking_at_drop;
+        assert_eq!(mutex.is_poisoned(), expected);
+        assert_eq!(mutex.lock().is_err(), expected);
+
+        mutex.clear_poison();
+        assert!(!mutex.is_poisoned());
+    }
+
+    #[kani::proof]
+    #[kani::stub(crate::thread::panicking, kani_model::panicking)]
+    fn check_try_lock_exclusive() {
+        kani_model::set_panicking(false);
+        let mutex = Mutex::new(kani::any::<u8>());
+        let guard = if kani::any() { mutex.lock().unwrap() } else { mutex.try_lock().unwrap() };
+        assert!(matches!(mutex.try_lock(), Err(TryLockError::WouldBlock)));
+        drop(guard);
+        assert!(mutex.try_lock().is_ok());
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    #[kani::stub(crate::thread::panicking, kani_model::panicking)]
+    fn check_relock_fails() {
+        kani_model::set_panicking(false);
+        let mutex = Mutex::new(kani::any::<u8>());
+        let _guard = mutex.lock();
+        // In the single-threaded model, this would deadlock.
+        let _ = mutex.lock();
+    }
+
+    #[kani::proof]
+    #[kani::stub(crate::thread::panicking, kani_model::panicking)]
+    fn check_get_mut_and_into_inner() {
+        let value: u8 = kani::any();
+        let mut mutex = Mutex::new(value);
+        kani_model::set_panicking(false);
+        let guard = mutex.lock();
+        let poisoned: bool = kani::any();
+        kani_model::set_panicking(poisoned);
+        drop(guard);
+        kani_model::set_panicking(false);
+
+        let expected = cfg!(panic = "unwind") && poisoned;
+        let new_value: u8 = kani::any();
+        match mutex.get_mut() {
+            Ok(data) => {
+                assert!(!expected);
+                *data = new_value;
+            }
+            Err(err) => {
+                assert!(expected);
+                *err.into_inner() = new_value;
+            }
+        }
+        // `get_mut` does not take the lock.
+        assert!(mutex.try_lock().is_ok() || expected);
+        let inner = match mutex.into_inner() {
+            Ok(data) => data,
+            Err(err) => err.into_inner(),
+        };
+        assert_eq!(inner, new_value);
+    }
+}
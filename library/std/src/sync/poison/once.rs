@@ -3,6 +3,9 @@
 //! This primitive is meant to be used to run one-time initialization. An
 //! example use case would be for initializing an FFI library.
 
+#[cfg(kani)]
+use core::kani;
+
 use crate::fmt;
 use crate::panic::{RefUnwindSafe, UnwindSafe};
 use crate::sys::sync as sys;
@@ -383,3 +386,89 @@ impl fmt::Debug for OnceState {
         f.debug_struct("OnceState").field("poisoned", &self.is_poisoned()).finish()
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::cell::Cell;
+
+    // Kani compiles with `panic=abort`, so a `Once` can only become poisoned here
+    // through `OnceState::poison`, which is how `OnceLock` reports a failed
+    // initialization.
+
+    #[kani::proof]
+    #[kani::unwind(4)]
+    fn check_call_once_runs_at_most_once() {
+        let once = Once::new();
+        let runs = Cell::new(0);
+        for i in 0..3 {
+            assert_eq!(once.is_completed(), i > 0);
+            if kani::any() {
+                once.call_once(|| runs.set(runs.get() + 1));
+            } else {
+                once.call_once_force(|state| {
+                    assert!(!state.is_poisoned());
+                    runs.set(runs.get() + 1);
+                });
+            }
+            assert!(once.is_completed());
+            assert_eq!(runs.get(), 1);
+        }
+    }
+
+    #[kani::proof]
+    #[kani::unwind(4)]
+    fn check_call_once_force_after_poison() {
+        let once = Once::new();
+        let runs = Cell::new(0);
+        let mut poisoned = false;
+        let mut completed = false;
+        for _ in 0..3 {
+            let before = runs.get();
+            let poison: bool = kani::any();
+            once.call_once_force(|state| {
+                assert_eq!(state.is_poisoned(), poisoned);
+                runs.set(runs.get() + 1);
+                if poison {
+                    state.poison();
+                }
+            });
+            if completed {
+                assert_eq!(runs.get(), before);
+            } else {
+                // A poisoned attempt is retried by the next call.
+                assert_eq!(runs.get(), before + 1);
+                poisoned = poison;
+                completed = !poison;
+            }
+            assert_eq!(once.is_completed(), completed);
+        }
+    }
+
+    #[kani::proof]
+    #[kani::should_panic]
+    fn check_call_once_after_poison() {
+        let once = Once::new();
+        once.call_once_force(|state| state.poison());
+        assert!(!once.is_completed());
+        once.call_once(|| {});
+    }
+
+    #[kani::proof]
+    fn check_state_after_call() {
+        let mut once = Once::new();
+        assert!(matches!(once.state(), ExclusiveState::Incomplete));
+        let poison: bool = kani::any();
+        once.call_once_force(|state| {
+            if poison {
+                state.poison();
+            }
+        });
+        if poison {
+            assert!(matches!(once.state(), ExclusiveState::Poisoned));
+        } else {
+            assert!(matches!(once.state(), ExclusiveState::Complete));
+        }
+    }
+}
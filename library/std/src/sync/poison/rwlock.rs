@@ -1209,3 +1209,92 @@ impl<'a, T: ?Sized> MappedRwLockWriteGuard<'a, T> {
         }
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use core::kani;
+
+    use super::*;
+    use crate::sync::poison::kani_model;
+
+    // `RwLock` poisoning proofs, run against the single-threaded `sys::sync::RwLock` model
+    // with `thread::panicking` stubbed by `poison::kani_model`.
+
+    #[kani::proof]
+    #[kani::stub(crate::thread::panicking, kani_model::panicking)]
+    fn check_poison_on_unwind() {
+        let lock = RwLock::new(kani::any::<u8>());
+        let write: bool = kani::any();
+        let panicking_at_lock: bool = kani::any();
+        let panicking_at_drop: bool = kani::any();
+        kani::assume(!panicking_at_lock || panicking_at_drop);
+
+        kani_model::set_panicking(panicking_at_lock);
+        if write {
+            let guard = lock.write();
+            assert!(guard.is_ok());
+            kani_model::set_panicking(panicking_at_drop);
+        } else {
+            let guard = lock.read();
+            assert!(guard.is_ok());
+            kani_model::set_panicking(panicking_at_drop);
+        }
+
+        // Only a panic that started while a write guard was held poisons the lock.
+        let expected = cfg!(panic = "unwind") && write && !panicking_at_lock && panicking_at_drop;
+        assert_eq!(lock.is_poisoned(), expected);
+        assert_eq!(lock.read().is_err(), expected);
+
+        lock.clear_poison();
+        assert!(!lock.is_poisoned());
+    }
+
+    #[kani::proof]
+    #[kani::stub(crate::thread::panicking, kani_model::panicking)]
+    fn check_try_lock_exclusive() {
+        kani_model::set_panicking(false);
+        let lock = RwLock::new(kani::any::<u8>());
+        {
+            let _read = lock.read().unwrap();
+            assert!(lock.try_read().is_ok());
+            assert!(matches!(lock.try_write(), Err(TryLockError::WouldBlock)));
+        }
+        {
+            let _write = lock.write().unwrap();
+            assert!(matches!(lock.try_read(), Err(TryLockError::WouldBlock)));
+            assert!(matches!(lock.try_write(), Err(TryLockError::WouldBlock)));
+        }
+        assert!(lock.try_write().is_ok());
+    }
+
+    #[kani::proof]
+    #[kani::stub(crate::thread::panicking, kani_model::panicking)]
+    fn check_get_mut_and_into_inner() {
+        let mut lock = RwLock::new(kani::any::<u8>());
+        kani_model::set_panicking(false);
+        let guard = lock.write();
+        let poisoned: bool = kani::any();
+        kani_model::set_panicking(poisoned);
+        drop(guard);
+        kani_model::set_panicking(false);
+
+        let expected = cfg!(panic = "unwind") && poisoned;
+        let new_value: u8 = kani::any();
+        match lock.get_mut() {
+            Ok(data) => {
+                assert!(!expected);
+                *data = new_value;
+            }
+            Err(err) => {
+                assert!(expected);
+                *err.into_inner() = new_value;
+            }
+        }
+        let inner = match lock.into_inner() {
+            Ok(data) => data,
+            Err(err) => err.into_inner(),
+        };
+        assert_eq!(inner, new_value);
+    }
+}
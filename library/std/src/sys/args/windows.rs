@@ -6,6 +6,9 @@
 #[cfg(test)]
 mod tests;
 
+#[cfg(kani)]
+use core::kani;
+
 pub use super::common::Args;
 use crate::ffi::{OsStr, OsString};
 use crate::num::NonZero;
@@ -409,3 +412,156 @@ pub(crate) fn from_wide_to_user_path(mut path: Vec<u16>) -> io::Result<Vec<u16>>
         _ => get_long_path(path, false),
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // `append_arg` quoting proofs
+    //
+    // Arguments are symbolic UTF-16 sequences (including lone surrogates), appended the way
+    // `make_command_line` does and then split again by `parse_reference`, an independent
+    // implementation of the documented `CommandLineToArgvW`/MSVC splitting rules.
+
+    const BACKSLASH: u16 = b'\\' as u16;
+    const QUOTE: u16 = b'"' as u16;
+    const PERCENT: u16 = b'%' as u16;
+    const ARG_LEN: usize = 3;
+
+    /// Splits the argument portion of a command line (everything after `argv[0]`):
+    /// - unquoted spaces and tabs separate arguments;
+    /// - `2n` backslashes before a `"` become `n` backslashes and the `"` toggles quoting;
+    /// - `2n + 1` backslashes before a `"` become `n` backslashes and a literal `"`;
+    /// - backslashes not followed by `"` are literal;
+    /// - `""` inside a quoted region is a literal `"` and quoting continues.
+    fn parse_reference(cmd: &[u16]) -> Vec<Vec<u16>> {
+        let mut args = Vec::new();
+        let mut arg: Option<Vec<u16>> = None;
+        let mut in_quotes = false;
+        let mut i = 0;
+        while i < cmd.len() {
+            let c = cmd[i];
+            if c == BACKSLASH {
+                let start = i;
+                while i < cmd.len() && cmd[i] == BACKSLASH {
+                    i += 1;
+                }
+                let n = i - start;
+                let current = arg.get_or_insert_with(Vec::new);
+                if cmd.get(i) == Some(&QUOTE) {
+                    current.extend(iter::repeat_n(BACKSLASH, n / 2));
+                    if n % 2 == 1 {
+                        current.push(QUOTE);
+                        i += 1;
+                    }
+                } else {
+                    current.extend(iter::repeat_n(BACKSLASH, n));
+                }
+                continue;
+            }
+            if c == QUOTE {
+                let current = arg.get_or_insert_with(Vec::new);
+                if in_quotes && cmd.get(i + 1) == Some(&QUOTE) {
+                    current.push(QUOTE);
+                    i += 1;
+                } else {
+                    in_quotes = !in_quotes;
+                }
+            } else if !in_quotes && (c == b' ' as u16 || c == b'\t' as u16) {
+                args.extend(arg.take());
+            } else {
+                arg.get_or_insert_with(Vec::new).push(c);
+            }
+            i += 1;
+        }
+        args.extend(arg);
+        args
+    }
+
+    fn any_arg(units: &[u16; ARG_LEN]) -> OsString {
+        OsString::from_wide(kani::slice::any_slice_of_array(units))
+    }
+
+    #[kani::proof]
+    #[kani::unwind(30)]
+    fn check_append_arg_round_trip() {
+        let first: [u16; ARG_LEN] = kani::any_where(|u: &[u16; ARG_LEN]| !u.contains(&0));
+        let second: [u16; ARG_LEN] = kani::any_where(|u: &[u16; ARG_LEN]| !u.contains(&0));
+        let args = [any_arg(&first), any_arg(&second)];
+        let force_quotes = kani::any();
+
+        let mut cmd = Vec::new();
+        for arg in &args {
+            cmd.push(b' ' as u16);
+            append_arg(&mut cmd, &Arg::Regular(arg.clone()), force_quotes).unwrap();
+        }
+
+        let parsed = parse_reference(&cmd);
+        assert_eq!(parsed.len(), args.len());
+        for (parsed, arg) in parsed.iter().zip(&args) {
+            assert!(parsed.iter().copied().eq(arg.encode_wide()));
+        }
+    }
+
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_append_raw_arg() {
+        let units: [u16; ARG_LEN] = kani::any_where(|u: &[u16; ARG_LEN]| !u.contains(&0));
+        let arg = any_arg(&units);
+        let mut cmd = Vec::new();
+        append_arg(&mut cmd, &Arg::Raw(arg.clone()), kani::any()).unwrap();
+        assert!(cmd.iter().copied().eq(arg.encode_wide()));
+    }
+
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_append_arg_rejects_nul() {
+        let units: [u16; ARG_LEN] = kani::any_where(|u: &[u16; ARG_LEN]| u.contains(&0));
+        let arg = OsString::from_wide(&units);
+        let arg = if kani::any() { Arg::Regular(arg) } else { Arg::Raw(arg) };
+        let mut cmd = Vec::new();
+        assert!(append_arg(&mut cmd, &arg, kani::any()).is_err());
+    }
+
+    /// Line breaks would let a batch argument end the `cmd.exe /c` command early.
+    #[kani::proof]
+    #[kani::unwind(40)]
+    fn check_bat_rejects_line_breaks() {
+        let units: [u16; ARG_LEN] = kani::any_where(|u: &[u16; ARG_LEN]| {
+            u.contains(&(b'\r' as u16)) || u.contains(&(b'\n' as u16))
+        });
+        let script = [b'a' as u16, b'.' as u16, b'b' as u16, b'a' as u16, b't' as u16];
+        let args = [Arg::Regular(OsString::from_wide(&units))];
+        assert!(make_bat_command_line(&script, &args, kani::any()).is_err());
+    }
+
+    /// Every `%` copied from a batch argument is preceded by the `%%cd:~,` guard, so once
+    /// the guarded sequences are removed no `%` is left for `cmd.exe` to expand.
+    #[kani::proof]
+    #[kani::unwind(30)]
+    fn check_bat_arg_percent_guard() {
+        const GUARDED: [u16; 8] = [
+            PERCENT,
+            PERCENT,
+            b'c' as u16,
+            b'd' as u16,
+            b':' as u16,
+            b'~' as u16,
+            b',' as u16,
+            PERCENT,
+        ];
+        // `make_bat_command_line` rejects `\r` and `\n` before they reach `append_bat_arg`.
+        let units: [u16; ARG_LEN] = kani::any_where(|u: &[u16; ARG_LEN]| {
+            u.iter().all(|&c| c != 0 && c != b'\r' as u16 && c != b'\n' as u16)
+        });
+        let mut cmd = Vec::new();
+        append_bat_arg(&mut cmd, &any_arg(&units), kani::any()).unwrap();
+
+        let mut rest = &cmd[..];
+        while let Some(i) = rest.iter().position(|&c| c == PERCENT) {
+            assert!(rest[i..].starts_with(&GUARDED));
+            rest = &rest[i + GUARDED.len()..];
+        }
+    }
+}
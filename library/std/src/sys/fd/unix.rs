@@ -18,6 +18,11 @@ use libc::off_t as off64_t;
 ))]
 use libc::off64_t;
 
+#[cfg(kani)]
+use core::kani;
+
+use safety::ensures;
+
 use crate::cmp;
 use crate::io::{self, BorrowedCursor, IoSlice, IoSliceMut, Read};
 use crate::os::unix::io::{AsFd, AsRawFd, BorrowedFd, FromRawFd, IntoRawFd, OwnedFd, RawFd};
@@ -93,6 +98,7 @@ impl FileDesc {
         self.duplicate()
     }
 
+    #[ensures(|result| result.as_ref().map_or(true, |n| *n <= buf.len()))]
     pub fn read(&self, buf: &mut [u8]) -> io::Result<usize> {
         let ret = cvt(unsafe {
             libc::read(
@@ -147,6 +153,7 @@ impl FileDesc {
     }
 
     #[cfg_attr(target_os = "vxworks", allow(unused_unsafe))]
+    #[ensures(|result| result.as_ref().map_or(true, |n| *n <= buf.len()))]
     pub fn read_at(&self, buf: &mut [u8], offset: u64) -> io::Result<usize> {
         #[cfg(not(any(
             all(target_os = "linux", not(target_env = "musl")),
@@ -321,6 +328,7 @@ impl FileDesc {
         }
     }
 
+    #[ensures(|result| result.as_ref().map_or(true, |n| *n <= buf.len()))]
     pub fn write(&self, buf: &[u8]) -> io::Result<usize> {
         let ret = cvt(unsafe {
             libc::write(
@@ -370,6 +378,7 @@ impl FileDesc {
     }
 
     #[cfg_attr(target_os = "vxworks", allow(unused_unsafe))]
+    #[ensures(|result| result.as_ref().map_or(true, |n| *n <= buf.len()))]
     pub fn write_at(&self, buf: &[u8], offset: u64) -> io::Result<usize> {
         #[cfg(not(any(
             all(target_os = "linux", not(target_env = "musl")),
@@ -675,3 +684,84 @@ impl FromRawFd for FileDesc {
         Self(unsafe { FromRawFd::from_raw_fd(raw_fd) })
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::io::BorrowedBuf;
+    use crate::sys::kani_libc;
+
+    // `FileDesc` read/write proofs, run against the libc models in `sys::kani_libc`
+
+    const BUF_LEN: usize = 8;
+
+    fn any_file_desc() -> FileDesc {
+        let fd: RawFd = kani::any_where(|fd: &RawFd| *fd >= 0);
+        unsafe { FileDesc::from_raw_fd(fd) }
+    }
+
+    #[kani::proof_for_contract(FileDesc::read)]
+    #[kani::unwind(9)]
+    #[kani::stub(libc::read, kani_libc::read)]
+    #[kani::stub(libc::close, kani_libc::close)]
+    #[kani::stub(crate::sys::os::errno_location, kani_libc::errno_location)]
+    fn check_read() {
+        let fd = any_file_desc();
+        let mut buf = [0u8; BUF_LEN];
+        let _ = fd.read(kani::slice::any_slice_of_array_mut(&mut buf));
+    }
+
+    #[kani::proof_for_contract(FileDesc::write)]
+    #[kani::stub(libc::write, kani_libc::write)]
+    #[kani::stub(libc::close, kani_libc::close)]
+    #[kani::stub(crate::sys::os::errno_location, kani_libc::errno_location)]
+    fn check_write() {
+        let fd = any_file_desc();
+        let buf: [u8; BUF_LEN] = kani::any();
+        let _ = fd.write(kani::slice::any_slice_of_array(&buf));
+    }
+
+    #[cfg(all(target_os = "linux", not(target_env = "musl")))]
+    #[kani::proof_for_contract(FileDesc::read_at)]
+    #[kani::unwind(9)]
+    #[kani::stub(libc::pread64, kani_libc::pread64)]
+    #[kani::stub(libc::close, kani_libc::close)]
+    #[kani::stub(crate::sys::os::errno_location, kani_libc::errno_location)]
+    fn check_read_at() {
+        let fd = any_file_desc();
+        let mut buf = [0u8; BUF_LEN];
+        let _ = fd.read_at(kani::slice::any_slice_of_array_mut(&mut buf), kani::any());
+    }
+
+    #[cfg(all(target_os = "linux", not(target_env = "musl")))]
+    #[kani::proof_for_contract(FileDesc::write_at)]
+    #[kani::stub(libc::pwrite64, kani_libc::pwrite64)]
+    #[kani::stub(libc::close, kani_libc::close)]
+    #[kani::stub(crate::sys::os::errno_location, kani_libc::errno_location)]
+    fn check_write_at() {
+        let fd = any_file_desc();
+        let buf: [u8; BUF_LEN] = kani::any();
+        let _ = fd.write_at(kani::slice::any_slice_of_array(&buf), kani::any());
+    }
+
+    /// `read_buf` hands the syscall result to `BorrowedCursor::advance_unchecked`, so the
+    /// reported length must stay within the cursor for safe readers to be sound.
+    #[kani::proof]
+    #[kani::unwind(9)]
+    #[kani::stub(libc::read, kani_libc::read)]
+    #[kani::stub(libc::close, kani_libc::close)]
+    #[kani::stub(crate::sys::os::errno_location, kani_libc::errno_location)]
+    fn check_read_buf() {
+        let fd = any_file_desc();
+        let mut storage = [0u8; BUF_LEN];
+        let mut buf = BorrowedBuf::from(kani::slice::any_slice_of_array_mut(&mut storage));
+        let capacity = buf.capacity();
+        if (&fd).read_buf(buf.unfilled()).is_ok() {
+            assert!(buf.len() <= capacity);
+            assert!(buf.init_len() <= capacity);
+        } else {
+            assert_eq!(buf.len(), 0);
+        }
+    }
+}
@@ -0,0 +1,292 @@
+//! Nondeterministic models of the libc calls made by `OwnedFd`, `FileDesc`, `Socket` and `net`.
+//!
+//! Kani cannot execute the real syscalls, so harnesses for the wrapper logic replace them
+//! with these models through `#[kani::stub]`. Every model first asserts the caller's side
+//! of the libc contract (a valid fd and buffers valid for the requested lengths) and then
+//! either fails with a symbolic positive `errno`, or succeeds with a symbolic length that
+//! never exceeds the buffers it was given. Successful reads overwrite the filled prefix of
+//! their buffers with symbolic bytes.
+//!
+//! Models that fill buffers loop over the transferred bytes, so harnesses need an unwind
+//! bound larger than the buffers they pass in. Since `cvt` reads `errno` on failure,
+//! `errno_location` must be stubbed alongside the calls themselves:
+//!
+//! ```ignore (requires Kani)
+//! #[kani::proof]
+//! #[kani::stub(libc::read, crate::sys::kani_libc::read)]
+//! #[kani::stub(crate::sys::os::errno_location, crate::sys::kani_libc::errno_location)]
+//! fn check_read() { /* ... */ }
+//! ```
+
+use core::kani;
+use core::ub_checks::{can_dereference, can_write};
+
+use crate::ffi::{c_int, c_void};
+use crate::ptr;
+
+static mut ERRNO: c_int = 0;
+
+/// Model of the thread-local `errno` location.
+pub fn errno_location() -> *mut c_int {
+    &raw mut ERRNO
+}
+
+/// Either fails with a symbolic `errno`, or returns a symbolic length no larger than `max`.
+fn any_result(max: usize) -> libc::ssize_t {
+    if kani::any() {
+        unsafe { ERRNO = kani::any_where(|errno: &c_int| *errno > 0) };
+        -1
+    } else {
+        kani::any_where(|len: &usize| *len <= max) as libc::ssize_t
+    }
+}
+
+/// Overwrites the first `len` bytes of `buf` with symbolic data.
+unsafe fn fill(buf: *mut u8, len: usize) {
+    for i in 0..len {
+        unsafe { *buf.add(i) = kani::any() };
+    }
+}
+
+unsafe fn check_read_buf(buf: *mut c_void, count: libc::size_t) {
+    kani::assert(
+        can_write(ptr::slice_from_raw_parts_mut(buf as *mut u8, count)),
+        "buffer must be valid for writes of `count` bytes",
+    );
+}
+
+unsafe fn check_write_buf(buf: *const c_void, count: libc::size_t) {
+    kani::assert(
+        can_dereference(ptr::slice_from_raw_parts(buf as *const u8, count)),
+        "buffer must be valid for reads of `count` bytes",
+    );
+}
+
+/// Asserts that `iov` holds `iovcnt` valid entries and returns their total length.
+unsafe fn iov_len(iov: *const libc::iovec, iovcnt: c_int) -> usize {
+    kani::assert(iovcnt >= 0, "iovcnt must not be negative");
+    let iovs = ptr::slice_from_raw_parts(iov, iovcnt as usize);
+    kani::assert(can_dereference(iovs), "iov must hold `iovcnt` entries");
+    let mut total: usize = 0;
+    for iov in unsafe { &*iovs } {
+        total = total.saturating_add(iov.iov_len);
+    }
+    total
+}
+
+/// Fills the first `len` bytes spread over the entries of `iov`.
+unsafe fn fill_iov(iov: *const libc::iovec, iovcnt: c_int, mut len: usize) {
+    let iovs = unsafe { &*ptr::slice_from_raw_parts(iov, iovcnt as usize) };
+    for iov in iovs {
+        let n = len.min(iov.iov_len);
+        unsafe {
+            check_read_buf(iov.iov_base, n);
+            fill(iov.iov_base as *mut u8, n);
+        }
+        len -= n;
+    }
+}
+
+pub unsafe extern "C" fn read(fd: c_int, buf: *mut c_void, count: libc::size_t) -> libc::ssize_t {
+    kani::assert(fd >= 0, "fd must be open");
+    unsafe { check_read_buf(buf, count) };
+    let ret = any_result(count);
+    if ret > 0 {
+        unsafe { fill(buf as *mut u8, ret as usize) };
+    }
+    ret
+}
+
+pub unsafe extern "C" fn write(
+    fd: c_int,
+    buf: *const c_void,
+    count: libc::size_t,
+) -> libc::ssize_t {
+    kani::assert(fd >= 0, "fd must be open");
+    unsafe { check_write_buf(buf, count) };
+    any_result(count)
+}
+
+pub unsafe extern "C" fn pread64(
+    fd: c_int,
+    buf: *mut c_void,
+    count: libc::size_t,
+    _offset: libc::off64_t,
+) -> libc::ssize_t {
+    unsafe { read(fd, buf, count) }
+}
+
+pub unsafe extern "C" fn pwrite64(
+    fd: c_int,
+    buf: *const c_void,
+    count: libc::size_t,
+    _offset: libc::off64_t,
+) -> libc::ssize_t {
+    unsafe { write(fd, buf, count) }
+}
+
+pub unsafe extern "C" fn readv(fd: c_int, iov: *const libc::iovec, iovcnt: c_int) -> libc::ssize_t {
+    kani::assert(fd >= 0, "fd must be open");
+    let ret = any_result(unsafe { iov_len(iov, iovcnt) });
+    if ret > 0 {
+        unsafe { fill_iov(iov, iovcnt, ret as usize) };
+    }
+    ret
+}
+
+pub unsafe extern "C" fn writev(
+    fd: c_int,
+    iov: *const libc::iovec,
+    iovcnt: c_int,
+) -> libc::ssize_t {
+    kani::assert(fd >= 0, "fd must be open");
+    let max = unsafe { iov_len(iov, iovcnt) };
+    let iovs = unsafe { &*ptr::slice_from_raw_parts(iov, iovcnt as usize) };
+    for iov in iovs {
+        unsafe { check_write_buf(iov.iov_base, iov.iov_len) };
+    }
+    any_result(max)
+}
+
+pub unsafe extern "C" fn recv(
+    socket: c_int,
+    buf: *mut c_void,
+    len: libc::size_t,
+    _flags: c_int,
+) -> libc::ssize_t {
+    unsafe { read(socket, buf, len) }
+}
+
+pub unsafe extern "C" fn send(
+    socket: c_int,
+    buf: *const c_void,
+    len: libc::size_t,
+    _flags: c_int,
+) -> libc::ssize_t {
+    unsafe { write(socket, buf, len) }
+}
+
+/// Also writes a symbolic address of symbolic length into `addr`, so callers see both
+/// well-formed and malformed addresses.
+pub unsafe extern "C" fn recvfrom(
+    socket: c_int,
+    buf: *mut c_void,
+    len: libc::size_t,
+    flags: c_int,
+    addr: *mut libc::sockaddr,
+    addrlen: *mut libc::socklen_t,
+) -> libc::ssize_t {
+    let ret = unsafe { recv(socket, buf, len, flags) };
+    if ret >= 0 && !addr.is_null() {
+        kani::assert(can_write(addrlen), "addrlen must be valid for writes");
+        let capacity = unsafe { *addrlen } as usize;
+        unsafe { check_read_buf(addr as *mut c_void, capacity) };
+        let written = kani::any_where(|n: &usize| *n <= capacity);
+        unsafe {
+            fill(addr as *mut u8, written);
+            *addrlen = written as libc::socklen_t;
+        }
+    }
+    ret
+}
+
+/// No control messages are delivered: `msg_controllen` is always reset to zero, while
+/// `msg_namelen` and `msg_flags` become symbolic.
+pub unsafe extern "C" fn recvmsg(
+    socket: c_int,
+    msg: *mut libc::msghdr,
+    _flags: c_int,
+) -> libc::ssize_t {
+    kani::assert(socket >= 0, "socket must be open");
+    kani::assert(can_write(msg), "msg must be valid for writes");
+    let msg = unsafe { &mut *msg };
+    let ret = any_result(unsafe { iov_len(msg.msg_iov, msg.msg_iovlen as c_int) });
+    if ret >= 0 {
+        unsafe { fill_iov(msg.msg_iov, msg.msg_iovlen as c_int, ret as usize) };
+        let namelen = msg.msg_namelen;
+        msg.msg_namelen = kani::any_where(|n: &libc::socklen_t| *n <= namelen);
+        msg.msg_controllen = 0;
+        msg.msg_flags = kani::any();
+    }
+    ret
+}
+
+pub unsafe extern "C" fn sendmsg(
+    socket: c_int,
+    msg: *const libc::msghdr,
+    _flags: c_int,
+) -> libc::ssize_t {
+    kani::assert(socket >= 0, "socket must be open");
+    kani::assert(can_dereference(msg), "msg must be valid for reads");
+    let msg = unsafe { &*msg };
+    unsafe { writev(socket, msg.msg_iov, msg.msg_iovlen as c_int) }
+}
+
+/// Only the commands issued by `OwnedFd` are modelled: `F_GETFD` reports every fd as open,
+/// and `F_DUPFD`/`F_DUPFD_CLOEXEC` either fail or return a fresh symbolic fd of at least 3.
+pub unsafe extern "C" fn fcntl(fd: c_int, cmd: c_int, _args: ...) -> c_int {
+    kani::assert(fd >= 0, "fd must be open");
+    match cmd {
+        libc::F_GETFD => 0,
+        libc::F_DUPFD | libc::F_DUPFD_CLOEXEC => {
+            if kani::any() {
+                unsafe { ERRNO = kani::any_where(|errno: &c_int| *errno > 0) };
+                -1
+            } else {
+                kani::any_where(|new: &c_int| *new >= 3)
+            }
+        }
+        _ => panic!("unmodelled fcntl command"),
+    }
+}
+
+pub unsafe extern "C" fn close(fd: c_int) -> c_int {
+    kani::assert(fd >= 0, "fd must be open");
+    0
+}
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::io::{IoSlice, IoSliceMut};
+    use crate::os::unix::io::FromRawFd;
+    use crate::sys::fd::FileDesc;
+
+    fn any_file_desc() -> FileDesc {
+        let fd: c_int = kani::any_where(|fd: &c_int| *fd >= 0);
+        unsafe { FileDesc::from_raw_fd(fd) }
+    }
+
+    #[kani::proof]
+    #[kani::unwind(9)]
+    #[kani::stub(libc::read, read)]
+    #[kani::stub(libc::close, close)]
+    #[kani::stub(crate::sys::os::errno_location, errno_location)]
+    fn check_file_desc_read() {
+        let fd = any_file_desc();
+        let mut buf = [0u8; 8];
+        match fd.read(&mut buf) {
+            Ok(n) => assert!(n <= buf.len()),
+            Err(e) => assert!(e.raw_os_error().is_some_and(|errno| errno > 0)),
+        }
+    }
+
+    #[kani::proof]
+    #[kani::unwind(9)]
+    #[kani::stub(libc::readv, readv)]
+    #[kani::stub(libc::writev, writev)]
+    #[kani::stub(libc::close, close)]
+    #[kani::stub(crate::sys::os::errno_location, errno_location)]
+    fn check_file_desc_vectored() {
+        let fd = any_file_desc();
+        let mut a = [0u8; 4];
+        let mut b = [0u8; 4];
+        if let Ok(n) = fd.read_vectored(&mut [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)]) {
+            assert!(n <= a.len() + b.len());
+        }
+        if let Ok(n) = fd.write_vectored(&[IoSlice::new(&a), IoSlice::new(&b)]) {
+            assert!(n <= a.len() + b.len());
+        }
+    }
+}
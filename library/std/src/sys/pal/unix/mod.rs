@@ -9,6 +9,8 @@ pub mod weak;
 #[cfg(target_os = "fuchsia")]
 pub mod fuchsia;
 pub mod futex;
+#[cfg(kani)]
+pub mod kani_libc;
 #[cfg(any(target_os = "linux", target_os = "android"))]
 pub mod kernel_copy;
 #[cfg(target_os = "linux")]
@@ -1,4 +1,9 @@
+#[cfg(kani)]
+use core::kani;
 use core::num::niche_types::Nanoseconds;
+use core::ub_checks::Invariant;
+
+use safety::{ensures, requires};
 
 use crate::time::Duration;
 use crate::{fmt, io};
@@ -60,7 +65,25 @@ impl fmt::Debug for SystemTime {
     }
 }
 
+#[unstable(feature = "ub_checks", issue = "none")]
+impl Invariant for Timespec {
+    /// Nanoseconds are normalized into `0..NSEC_PER_SEC`.
+    fn is_safe(&self) -> bool {
+        (self.tv_nsec.as_inner() as u64) < NSEC_PER_SEC
+    }
+}
+
 impl Timespec {
+    /// Ghost function: the instant as a signed number of nanoseconds since the epoch.
+    #[cfg(kani)]
+    fn total_nanos(&self) -> i128 {
+        self.tv_sec as i128 * NSEC_PER_SEC as i128 + self.tv_nsec.as_inner() as i128
+    }
+
+    #[requires(tv_nsec >= 0 && tv_nsec < NSEC_PER_SEC as i64)]
+    #[ensures(|t: &Timespec| {
+        t.is_safe() && t.tv_sec == tv_sec && t.tv_nsec.as_inner() as i64 == tv_nsec
+    })]
     const unsafe fn new_unchecked(tv_sec: i64, tv_nsec: i64) -> Timespec {
         Timespec { tv_sec, tv_nsec: unsafe { Nanoseconds::new_unchecked(tv_nsec as u32) } }
     }
@@ -69,6 +92,12 @@ impl Timespec {
         unsafe { Self::new_unchecked(0, 0) }
     }
 
+    #[cfg_attr(kani, ensures(|result: &Result<Timespec, io::Error>| {
+        result.as_ref().map_or(true, |t| {
+            t.is_safe()
+                && t.total_nanos() == tv_sec as i128 * NSEC_PER_SEC as i128 + tv_nsec as i128
+        })
+    }))]
     const fn new(tv_sec: i64, tv_nsec: i64) -> Result<Timespec, io::Error> {
         // On Apple OS, dates before epoch are represented differently than on other
         // Unix platforms: e.g. 1/10th of a second before epoch is represented as `seconds=-1`
@@ -133,6 +162,13 @@ impl Timespec {
         Timespec::new(t.tv_sec as i64, t.tv_nsec as i64).unwrap()
     }
 
+    #[cfg_attr(kani, kani::recursion)]
+    #[cfg_attr(kani, ensures(|result: &Result<Duration, Duration>| match result {
+        Ok(d) => d.as_nanos() as i128 == self.total_nanos() - other.total_nanos(),
+        Err(d) => {
+            d.as_nanos() as i128 == other.total_nanos() - self.total_nanos() && !d.is_zero()
+        }
+    }))]
     pub fn sub_timespec(&self, other: &Timespec) -> Result<Duration, Duration> {
         if self >= other {
             // NOTE(eddyb) two aspects of this `if`-`else` are required for LLVM
@@ -148,14 +184,17 @@ impl Timespec {
             //
             // Ideally this code could be rearranged such that it more
             // directly expresses the lower-cost behavior we want from it.
+            //
+            // The seconds difference always fits in a `u64`, but not necessarily in an
+            // `i64`, so it is computed with wrapping arithmetic before the cast.
             let (secs, nsec) = if self.tv_nsec.as_inner() >= other.tv_nsec.as_inner() {
                 (
-                    (self.tv_sec - other.tv_sec) as u64,
+                    self.tv_sec.wrapping_sub(other.tv_sec) as u64,
                     self.tv_nsec.as_inner() - other.tv_nsec.as_inner(),
                 )
             } else {
                 (
-                    (self.tv_sec - other.tv_sec - 1) as u64,
+                    self.tv_sec.wrapping_sub(other.tv_sec).wrapping_sub(1) as u64,
                     self.tv_nsec.as_inner() + (NSEC_PER_SEC as u32) - other.tv_nsec.as_inner(),
                 )
             };
@@ -169,6 +208,13 @@ impl Timespec {
         }
     }
 
+    #[cfg_attr(kani, ensures(|result: &Option<Timespec>| {
+        let sum = self.total_nanos() + other.as_nanos() as i128;
+        match result {
+            Some(t) => t.is_safe() && t.total_nanos() == sum,
+            None => sum >= (i64::MAX as i128 + 1) * NSEC_PER_SEC as i128,
+        }
+    }))]
     pub fn checked_add_duration(&self, other: &Duration) -> Option<Timespec> {
         let mut secs = self.tv_sec.checked_add_unsigned(other.as_secs())?;
 
@@ -182,6 +228,13 @@ impl Timespec {
         Some(unsafe { Timespec::new_unchecked(secs, nsec.into()) })
     }
 
+    #[cfg_attr(kani, ensures(|result: &Option<Timespec>| {
+        let difference = self.total_nanos() - other.as_nanos() as i128;
+        match result {
+            Some(t) => t.is_safe() && t.total_nanos() == difference,
+            None => difference < i64::MIN as i128 * NSEC_PER_SEC as i128,
+        }
+    }))]
     pub fn checked_sub_duration(&self, other: &Duration) -> Option<Timespec> {
         let mut secs = self.tv_sec.checked_sub_unsigned(other.as_secs())?;
 
@@ -309,3 +362,75 @@ impl fmt::Debug for Instant {
             .finish()
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // `Timespec` arithmetic proofs
+
+    fn any_timespec() -> Timespec {
+        let tv_nsec = kani::any_where(|nsec: &i64| *nsec >= 0 && *nsec < NSEC_PER_SEC as i64);
+        unsafe { Timespec::new_unchecked(kani::any(), tv_nsec) }
+    }
+
+    fn any_duration() -> Duration {
+        Duration::new(kani::any(), kani::any_where(|nanos: &u32| (*nanos as u64) < NSEC_PER_SEC))
+    }
+
+    #[kani::proof_for_contract(Timespec::new_unchecked)]
+    fn check_new_unchecked() {
+        let _ = unsafe { Timespec::new_unchecked(kani::any(), kani::any()) };
+    }
+
+    #[kani::proof_for_contract(Timespec::new)]
+    fn check_new() {
+        let _ = Timespec::new(kani::any(), kani::any());
+    }
+
+    #[kani::proof_for_contract(Timespec::sub_timespec)]
+    fn check_sub_timespec() {
+        let _ = any_timespec().sub_timespec(&any_timespec());
+    }
+
+    /// The sub-second borrow branch: the minuend is later, but has fewer nanoseconds.
+    #[kani::proof]
+    fn check_sub_timespec_borrow() {
+        let (a, b) = (any_timespec(), any_timespec());
+        kani::assume(a.tv_sec > b.tv_sec && a.tv_nsec.as_inner() < b.tv_nsec.as_inner());
+        let d = a.sub_timespec(&b).unwrap();
+        assert_eq!(
+            d.subsec_nanos(),
+            a.tv_nsec.as_inner() + NSEC_PER_SEC as u32 - b.tv_nsec.as_inner()
+        );
+        assert_eq!(d.as_secs(), a.tv_sec.abs_diff(b.tv_sec) - 1);
+    }
+
+    #[kani::proof_for_contract(Timespec::checked_add_duration)]
+    fn check_checked_add_duration() {
+        let _ = any_timespec().checked_add_duration(&any_duration());
+    }
+
+    #[kani::proof_for_contract(Timespec::checked_sub_duration)]
+    fn check_checked_sub_duration() {
+        let _ = any_timespec().checked_sub_duration(&any_duration());
+    }
+
+    /// The derived ordering on `(tv_sec, tv_nsec)` agrees with the total nanosecond count.
+    #[kani::proof]
+    fn check_ordering() {
+        let (a, b) = (any_timespec(), any_timespec());
+        assert_eq!(a.cmp(&b), a.total_nanos().cmp(&b.total_nanos()));
+    }
+
+    #[kani::proof]
+    fn check_add_sub_round_trip() {
+        let t = any_timespec();
+        let d = any_duration();
+        if let Some(later) = t.checked_add_duration(&d) {
+            assert_eq!(later.sub_timespec(&t), Ok(d));
+            assert!(later.checked_sub_duration(&d) == Some(t));
+        }
+    }
+}
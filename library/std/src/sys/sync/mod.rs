@@ -1,3 +1,6 @@
+// Kani verifies a single thread of execution, so under `cfg(kani)` the locks and the condition
+// variable use their thread-less implementations. A lock is modeled by its owned/unowned state, and
+// acquiring a lock that is already held fails instead of blocking forever.
 mod condvar;
 mod mutex;
 mod once;
@@ -1,3 +1,10 @@
+#[cfg(kani)]
+use core::kani;
+
+use safety::ensures;
+#[cfg(kani)]
+use safety::requires;
+
 use crate::cell::Cell;
 use crate::sync as public;
 use crate::sync::atomic::Ordering::{Acquire, Relaxed, Release};
@@ -30,6 +37,14 @@ const QUEUED: Primitive = 4;
 
 const STATE_MASK: Primitive = 0b11;
 
+/// Whether `state_and_queued` is a state in which no initialization is running
+/// and no thread is waiting, i.e. one that can be observed through `&mut Once`
+/// or by a single thread outside of `call`.
+#[cfg(kani)]
+fn is_quiescent(state_and_queued: Primitive) -> bool {
+    matches!(state_and_queued, INCOMPLETE | POISONED | COMPLETE)
+}
+
 pub struct OnceState {
     poisoned: bool,
     set_state_to: Cell<Primitive>,
@@ -81,6 +96,7 @@ impl Once {
     }
 
     #[inline]
+    #[cfg_attr(kani, requires(is_quiescent(self.state_and_queued.load(Relaxed))))]
     pub(crate) fn state(&mut self) -> ExclusiveState {
         match *self.state_and_queued.get_mut() {
             INCOMPLETE => ExclusiveState::Incomplete,
@@ -91,6 +107,8 @@ impl Once {
     }
 
     #[inline]
+    #[cfg_attr(kani, ensures(|_| is_quiescent(self.state_and_queued.load(Relaxed))))]
+    #[cfg_attr(kani, kani::modifies(&self.state_and_queued))]
     pub(crate) fn set_state(&mut self, new_state: ExclusiveState) {
         *self.state_and_queued.get_mut() = match new_state {
             ExclusiveState::Incomplete => INCOMPLETE,
@@ -136,6 +154,8 @@ impl Once {
 
     #[cold]
     #[track_caller]
+    #[ensures(|_| old(self.state_and_queued.load(Relaxed)) != COMPLETE || self.is_completed())]
+    #[cfg_attr(kani, kani::modifies(&self.state_and_queued))]
     pub fn call(&self, ignore_poisoning: bool, f: &mut dyn FnMut(&public::OnceState)) {
         let mut state_and_queued = self.state_and_queued.load(Acquire);
         loop {
@@ -202,3 +222,64 @@ impl Once {
         }
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // `Once` proofs
+
+    fn exclusive_state(n: u8) -> ExclusiveState {
+        match n % 3 {
+            0 => ExclusiveState::Incomplete,
+            1 => ExclusiveState::Poisoned,
+            _ => ExclusiveState::Complete,
+        }
+    }
+
+    fn any_exclusive_state() -> ExclusiveState {
+        exclusive_state(kani::any())
+    }
+
+    fn any_once() -> Once {
+        let mut once = Once::new();
+        once.set_state(any_exclusive_state());
+        once
+    }
+
+    #[kani::proof_for_contract(Once::state)]
+    fn check_state() {
+        let _ = any_once().state();
+    }
+
+    #[kani::proof_for_contract(Once::set_state)]
+    fn check_set_state() {
+        any_once().set_state(any_exclusive_state());
+    }
+
+    #[kani::proof_for_contract(Once::call)]
+    fn check_call() {
+        let once = any_once();
+        // Other threads may run or wait on `once`, but not in a single-threaded proof.
+        kani::assume(is_quiescent(once.state_and_queued.load(Relaxed)));
+        // A poisoned `Once` panics unless poisoning is ignored.
+        let ignore_poisoning: bool = kani::any();
+        kani::assume(ignore_poisoning || once.state_and_queued.load(Relaxed) != POISONED);
+        let poison: bool = kani::any();
+        once.call(ignore_poisoning, &mut |state: &public::OnceState| {
+            if poison {
+                state.poison();
+            }
+        });
+    }
+
+    #[kani::proof]
+    fn check_state_round_trip() {
+        let n = kani::any::<u8>() % 3;
+        let mut once = Once::new();
+        once.set_state(exclusive_state(n));
+        assert_eq!(once.state() as u8, exclusive_state(n) as u8);
+        assert_eq!(once.is_completed(), matches!(exclusive_state(n), ExclusiveState::Complete));
+    }
+}
@@ -1,5 +1,8 @@
 cfg_if::cfg_if! {
-    if #[cfg(any(
+    if #[cfg(kani)] {
+        mod no_threads;
+        pub use no_threads::RwLock;
+    } else if #[cfg(any(
         all(target_os = "windows", not(target_vendor = "win7")),
         target_os = "linux",
         target_os = "android",
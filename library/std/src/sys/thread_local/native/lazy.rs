@@ -1,3 +1,8 @@
+#[cfg(kani)]
+use core::kani;
+
+use safety::ensures;
+
 use crate::cell::{Cell, UnsafeCell};
 use crate::mem::MaybeUninit;
 use crate::ptr;
@@ -43,6 +48,16 @@ where
         }
     }
 
+    #[cfg(kani)]
+    fn is_alive(&self) -> bool {
+        matches!(self.state.get(), State::Alive)
+    }
+
+    #[cfg(kani)]
+    fn is_destroyed(&self) -> bool {
+        matches!(self.state.get(), State::Destroyed(_))
+    }
+
     /// Gets a pointer to the TLS value, potentially initializing it with the
     /// provided parameters. If the TLS variable has been destroyed, a null
     /// pointer is returned.
@@ -53,6 +68,10 @@ where
     /// # Safety
     /// The `self` reference must remain valid until the TLS destructor is run.
     #[inline]
+    #[cfg_attr(kani, ensures(|result| result.is_null() == self.is_destroyed()))]
+    #[cfg_attr(kani, ensures(|result| result.is_null() || self.is_alive()))]
+    #[ensures(|result| result.is_null() || *result == self.value.get().cast_const().cast())]
+    #[cfg_attr(kani, kani::modifies(&self.state, &self.value))]
     pub unsafe fn get_or_init(&self, i: Option<&mut Option<T>>, f: impl FnOnce() -> T) -> *const T {
         if let State::Alive = self.state.get() {
             self.value.get().cast()
@@ -64,6 +83,10 @@ where
     /// # Safety
     /// The `self` reference must remain valid until the TLS destructor is run.
     #[cold]
+    #[cfg_attr(kani, ensures(|result| result.is_null() == self.is_destroyed()))]
+    #[cfg_attr(kani, ensures(|result| result.is_null() || self.is_alive()))]
+    #[ensures(|result| result.is_null() || *result == self.value.get().cast_const().cast())]
+    #[cfg_attr(kani, kani::modifies(&self.state, &self.value))]
     unsafe fn get_or_init_slow(
         &self,
         i: Option<&mut Option<T>>,
@@ -120,3 +143,117 @@ unsafe extern "C" fn destroy<T>(ptr: *mut u8) {
         }
     })
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+    use crate::thread::LocalKey;
+
+    // `Storage` proofs
+
+    static mut DTOR: Option<(*mut u8, unsafe extern "C" fn(*mut u8))> = None;
+
+    unsafe fn register(t: *mut u8, dtor: unsafe extern "C" fn(*mut u8)) {
+        let slot = unsafe { ptr::replace(&raw mut DTOR, Some((t, dtor))) };
+        kani::assert(slot.is_none(), "destructor registered twice");
+    }
+
+    fn run_dtors() {
+        if let Some((t, dtor)) = unsafe { ptr::replace(&raw mut DTOR, None) } {
+            unsafe { dtor(t) };
+        }
+    }
+
+    static mut DROPS: usize = 0;
+
+    struct Tracked(u32);
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            unsafe { DROPS += 1 };
+        }
+    }
+
+    fn drops() -> usize {
+        unsafe { DROPS }
+    }
+
+    #[kani::proof_for_contract(Storage::get_or_init)]
+    fn check_get_or_init() {
+        let storage: Storage<u32, !> = Storage::new();
+        if kani::any() {
+            unsafe { storage.get_or_init(None, kani::any) };
+        }
+        let _ = unsafe { storage.get_or_init(None, kani::any) };
+    }
+
+    #[kani::proof_for_contract(Storage::get_or_init_slow)]
+    fn check_get_or_init_slow() {
+        let storage: Storage<u32, !> = Storage::new();
+        if kani::any() {
+            unsafe { storage.get_or_init_slow(None, kani::any) };
+        }
+        let _ = unsafe { storage.get_or_init_slow(None, kani::any) };
+    }
+
+    #[kani::proof]
+    #[kani::stub(crate::sys::thread_local::destructors::register, register)]
+    fn check_lifecycle() {
+        let storage: Storage<Tracked, ()> = Storage::new();
+        let value: u32 = kani::any();
+        let provided: bool = kani::any();
+        let mut init = provided.then(|| Tracked(value));
+        let ptr =
+            unsafe { storage.get_or_init(Some(&mut init), || Tracked(value.wrapping_add(1))) };
+        assert!(init.is_none());
+        assert_eq!(unsafe { (*ptr).0 }, if provided { value } else { value.wrapping_add(1) });
+
+        // Later accesses see the same slot without running the initializer.
+        let again = unsafe { storage.get_or_init(None, || panic!("initialized twice")) };
+        assert_eq!(again, ptr);
+        assert_eq!(drops(), 0);
+
+        // Thread exit drops the value exactly once, after which the slot is gone.
+        run_dtors();
+        assert_eq!(drops(), 1);
+        let gone = unsafe { storage.get_or_init(None, || panic!("initialized after destruction")) };
+        assert!(gone.is_null());
+        run_dtors();
+        assert_eq!(drops(), 1);
+    }
+
+    #[kani::proof]
+    #[kani::stub(crate::sys::thread_local::destructors::register, register)]
+    fn check_recursive_initialization() {
+        let storage: Storage<Tracked, ()> = Storage::new();
+        let (outer, inner): (u32, u32) = kani::any();
+        let ptr = unsafe {
+            storage.get_or_init(None, || {
+                let ptr = storage.get_or_init(None, || Tracked(inner));
+                assert_eq!((*ptr).0, inner);
+                Tracked(outer)
+            })
+        };
+        // The outer initializer wins and the value of the inner one is dropped.
+        assert_eq!(unsafe { (*ptr).0 }, outer);
+        assert_eq!(drops(), 1);
+        run_dtors();
+        assert_eq!(drops(), 2);
+    }
+
+    #[thread_local]
+    static KEY_STORAGE: Storage<u32, ()> = Storage::new();
+
+    static KEY: LocalKey<u32> =
+        unsafe { LocalKey::new(|init| KEY_STORAGE.get_or_init(init, || 7)) };
+
+    #[kani::proof]
+    #[kani::stub(crate::sys::thread_local::destructors::register, register)]
+    fn check_local_key_after_destroy() {
+        assert_eq!(KEY.with(|v| *v), 7);
+        assert_eq!(KEY.try_with(|v| *v), Ok(7));
+        run_dtors();
+        assert!(KEY.try_with(|_| ()).is_err());
+    }
+}
@@ -20,7 +20,12 @@ mod tests;
 
 use core::char::{MAX_LEN_UTF8, MAX_LEN_UTF16, encode_utf8_raw, encode_utf16_raw};
 use core::clone::CloneToUninit;
+#[cfg(kani)]
+use core::kani;
 use core::str::next_code_point;
+use core::ub_checks::Invariant;
+
+use safety::{ensures, requires};
 
 use crate::borrow::Cow;
 use crate::collections::TryReserveError;
@@ -56,6 +61,8 @@ impl CodePoint {
     /// Unsafely creates a new `CodePoint` without checking the value.
     ///
     /// Only use when `value` is known to be less than or equal to 0x10FFFF.
+    #[requires(value <= 0x10FFFF)]
+    #[ensures(|code_point| code_point.to_u32() == value)]
     #[inline]
     pub unsafe fn from_u32_unchecked(value: u32) -> CodePoint {
         CodePoint { value }
@@ -276,6 +283,7 @@ impl Wtf8Buf {
 
     /// Converts the string to UTF-8 without validation, if it was created from
     /// valid UTF-8.
+    #[ensures(|s| s.is_none() || str::from_utf8(self.as_bytes()).is_ok())]
     #[inline]
     fn as_known_utf8(&self) -> Option<&str> {
         if self.is_known_utf8 {
@@ -916,6 +924,8 @@ fn decode_surrogate(second_byte: u8, third_byte: u8) -> u16 {
     0xD800 | (second_byte as u16 & 0x3F) << 6 | third_byte as u16 & 0x3F
 }
 
+#[requires((0xD800..=0xDBFF).contains(&lead) && (0xDC00..=0xDFFF).contains(&trail))]
+#[ensures(|c| (0x10000..=0x10FFFF).contains(&(*c as u32)))]
 #[inline]
 fn decode_surrogate_pair(lead: u16, trail: u16) -> char {
     let code_point = 0x10000 + ((((lead - 0xD800) as u32) << 10) | (trail - 0xDC00) as u32);
@@ -963,6 +973,9 @@ pub fn check_utf8_boundary(slice: &Wtf8, index: usize) {
 }
 
 /// Copied from core::str::raw::slice_unchecked
+#[requires(begin <= end && end <= s.len())]
+#[requires(is_code_point_boundary(s, begin) && is_code_point_boundary(s, end))]
+#[ensures(|slice| slice.as_bytes() == &s.as_bytes()[begin..end])]
 #[inline]
 pub unsafe fn slice_unchecked(s: &Wtf8, begin: usize, end: usize) -> &Wtf8 {
     // SAFETY: memory layout of a &[u8] and &Wtf8 are the same
@@ -980,6 +993,31 @@ pub fn slice_error_fail(s: &Wtf8, begin: usize, end: usize) -> ! {
     panic!("index {begin} and/or {end} in `{s:?}` do not lie on character boundary");
 }
 
+#[unstable(feature = "ub_checks", issue = "none")]
+impl Invariant for Wtf8 {
+    /// Well-formed WTF-8 never encodes a lead surrogate directly followed by a trail
+    /// surrogate: such pairs must be stored as the supplementary code point they form.
+    fn is_safe(&self) -> bool {
+        let mut code_points = self.code_points().peekable();
+        while let Some(code_point) = code_points.next() {
+            if code_point.to_lead_surrogate().is_some()
+                && code_points.peek().is_some_and(|next| next.to_trail_surrogate().is_some())
+            {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[unstable(feature = "ub_checks", issue = "none")]
+impl Invariant for Wtf8Buf {
+    /// The contents are well-formed, and `is_known_utf8` is only set for valid UTF-8.
+    fn is_safe(&self) -> bool {
+        self.as_slice().is_safe() && (!self.is_known_utf8 || str::from_utf8(&self.bytes).is_ok())
+    }
+}
+
 /// Iterator for the code points of a WTF-8 string.
 ///
 /// Created with the method `.code_points()`.
@@ -1081,3 +1119,108 @@ unsafe impl CloneToUninit for Wtf8 {
         unsafe { self.bytes.clone_to_uninit(dst) }
     }
 }
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // WTF-8 proofs
+
+    /// Maximum number of code points in the strings built by the harnesses.
+    const MAX_CODE_POINTS: usize = 2;
+
+    fn any_code_point() -> CodePoint {
+        CodePoint::from_u32(kani::any_where(|value| *value <= 0x10FFFF)).unwrap()
+    }
+
+    /// Builds a well-formed string from up to `MAX_CODE_POINTS` symbolic code points,
+    /// either from UTF-8 (`is_known_utf8` set) or by pushing code points.
+    fn any_wtf8_buf() -> Wtf8Buf {
+        let len: usize = kani::any_where(|len| *len <= MAX_CODE_POINTS);
+        if kani::any() {
+            let mut string = String::new();
+            for _ in 0..len {
+                string.push(kani::any());
+            }
+            Wtf8Buf::from_string(string)
+        } else {
+            let mut buf = Wtf8Buf::new();
+            for _ in 0..len {
+                buf.push(any_code_point());
+            }
+            buf
+        }
+    }
+
+    #[kani::proof_for_contract(CodePoint::from_u32_unchecked)]
+    fn check_from_u32_unchecked() {
+        let _ = unsafe { CodePoint::from_u32_unchecked(kani::any()) };
+    }
+
+    #[kani::proof_for_contract(decode_surrogate_pair)]
+    fn check_decode_surrogate_pair() {
+        let _ = decode_surrogate_pair(kani::any(), kani::any());
+    }
+
+    #[kani::proof_for_contract(Wtf8Buf::as_known_utf8)]
+    #[kani::unwind(9)]
+    fn check_as_known_utf8() {
+        let buf = any_wtf8_buf();
+        let _ = buf.as_known_utf8();
+    }
+
+    #[kani::proof_for_contract(slice_unchecked)]
+    #[kani::unwind(9)]
+    fn check_slice_unchecked() {
+        let buf = any_wtf8_buf();
+        let _ = unsafe { slice_unchecked(&buf, kani::any(), kani::any()) };
+    }
+
+    #[kani::proof]
+    #[kani::unwind(9)]
+    fn check_push_wtf8() {
+        let mut buf = any_wtf8_buf();
+        let other = any_wtf8_buf();
+        let mut expected: Vec<u16> = buf.encode_wide().collect();
+        expected.extend(other.encode_wide());
+        buf.push_wtf8(&other);
+        assert!(buf.is_safe());
+        // Concatenation behaves like concatenating the (ill-formed) UTF-16 encodings.
+        assert!(buf.encode_wide().eq(expected.iter().copied()));
+        assert_eq!(Wtf8Buf::from_wide(&expected).as_bytes(), buf.as_bytes());
+    }
+
+    #[kani::proof]
+    #[kani::unwind(9)]
+    fn check_push() {
+        let mut buf = any_wtf8_buf();
+        let code_point = any_code_point();
+        buf.push(code_point);
+        assert!(buf.is_safe());
+    }
+
+    #[kani::proof]
+    #[kani::unwind(9)]
+    fn check_to_string_lossy() {
+        let buf = any_wtf8_buf();
+        let wide: Vec<u16> = buf.encode_wide().collect();
+        let lossy = buf.to_string_lossy();
+        assert!(*lossy == *String::from_utf16_lossy(&wide));
+        match buf.as_str() {
+            Ok(s) => assert!(matches!(lossy, Cow::Borrowed(b) if b == s)),
+            Err(_) => assert!(matches!(lossy, Cow::Owned(_))),
+        }
+    }
+
+    #[kani::proof]
+    #[kani::unwind(9)]
+    fn check_as_str_only_for_utf8() {
+        let buf = any_wtf8_buf();
+        let has_surrogate = buf.code_points().any(|c| c.to_char().is_none());
+        assert_eq!(buf.as_str().is_ok(), !has_surrogate);
+        if let Ok(s) = buf.as_str() {
+            assert!(s.chars().map(CodePoint::from_char).eq(buf.code_points()));
+        }
+    }
+}
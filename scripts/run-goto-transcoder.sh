@@ -6,8 +6,10 @@ set -e
 # PARAMETERS #
 ##############
 contract_folder=$1/kani_verify_std/target/x86_64-unknown-linux-gnu/debug/deps
+# Extended regular expression selecting the harnesses to check.
 supported_regex=$2
-unsupported_regex=neg
+# Harnesses that are expected to fail (the equivalent of `#[kani::should_panic]`).
+negative_regex=${3:-neg|should_fail|rejects}
 
 goto_transcoder_git=https://github.com/esbmc/goto-transcoder
 esbmc_url=https://github.com/esbmc/esbmc/releases/download/nightly-39b012f9f7f7dad188708a9eaf4bbbc5faa3b4f7/esbmc-linux.zip
@@ -28,7 +30,9 @@ if [ ! -d "goto-transcoder" ]; then
     cd ..
 fi
 
-ls $contract_folder | grep "$supported_regex" | grep -v .symtab.out > ./goto-transcoder/_contracts.txt
+ls $contract_folder | grep -E "$supported_regex" | grep -v .symtab.out > ./goto-transcoder/_contracts.txt
+
+failed=()
 
 cd goto-transcoder
 while IFS= read -r line; do
@@ -40,13 +44,28 @@ while IFS= read -r line; do
     if [[ -z "$contract" ]]; then
         continue
     fi
-    if echo "$contract" | grep -q "$unsupported_regex"; then
-        continue
-    fi
     echo "Running: goto-transcoder $contract $contract_folder/$line $contract.esbmc.goto"
     cargo run cbmc2esbmc  ../$contract_folder/$line $contract.esbmc.goto
-    ./linux-release/bin/esbmc --cprover --function $contract --binary resources/library.goto $contract.esbmc.goto
+    if ./linux-release/bin/esbmc --cprover --function $contract --binary resources/library.goto $contract.esbmc.goto; then
+        verified=true
+    else
+        verified=false
+    fi
+    if echo "$contract" | grep -qE "$negative_regex"; then
+        # Negative harnesses must find a violation.
+        if [[ "$verified" == "true" ]]; then
+            failed+=("$contract (expected a failure)")
+        fi
+    elif [[ "$verified" == "false" ]]; then
+        failed+=("$contract")
+    fi
 done < "_contracts.txt"
 
 rm "_contracts.txt"
 cd ..
+
+if [[ ${#failed[@]} -ne 0 ]]; then
+    echo "The following contracts did not verify with ESBMC:"
+    printf '  %s\n' "${failed[@]}"
+    exit 1
+fi
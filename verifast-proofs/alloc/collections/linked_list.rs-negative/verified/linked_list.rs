@@ -15,10 +15,18 @@
 use core::cmp::Ordering;
 use core::hash::{Hash, Hasher};
 use core::iter::FusedIterator;
+#[cfg(kani)]
+use core::kani;
 use core::marker::PhantomData;
+#[cfg(kani)]
+use core::ptr;
 use core::ptr::NonNull;
+#[cfg(kani)]
+use core::ub_checks::{Invariant, can_dereference};
 use core::{fmt, mem};
 
+use safety::{ensures, requires};
+
 use super::SpecExtend;
 use crate::alloc::{Allocator, Global};
 use crate::boxed::Box;
@@ -280,16 +288,92 @@ impl<T> Node<T> {
     fn into_element<A: Allocator>(self: Box<Self, A>) -> T {
         self.element
     }
+
+    /// Returns the link before the node behind `link`, if any.
+    #[cfg(kani)]
+    fn prev_of(link: Option<NonNull<Self>>) -> Option<NonNull<Self>> {
+        link.and_then(|node| unsafe { node.as_ref() }.prev)
+    }
+
+    /// Returns the link after the node behind `link`, if any.
+    #[cfg(kani)]
+    fn next_of(link: Option<NonNull<Self>>) -> Option<NonNull<Self>> {
+        link.and_then(|node| unsafe { node.as_ref() }.next)
+    }
+
+    /// Returns the node behind `link` as a write target, which is empty for `None`.
+    #[cfg(kani)]
+    fn target(link: Option<NonNull<Self>>) -> *mut [Self] {
+        match link {
+            Some(node) => ptr::slice_from_raw_parts_mut(node.as_ptr(), 1),
+            None => ptr::slice_from_raw_parts_mut(NonNull::dangling().as_ptr(), 0),
+        }
+    }
+}
+
+/// Returns `true` if following `next` links from `head` visits exactly `len`
+/// valid nodes, each linking back to its predecessor, and ends at `tail`.
+#[cfg(kani)]
+fn is_chain<T>(head: Option<NonNull<Node<T>>>, tail: Option<NonNull<Node<T>>>, len: usize) -> bool {
+    let mut prev = None;
+    let mut cur = head;
+    for _ in 0..len {
+        let Some(node) = cur else { return false };
+        if !can_dereference(node.as_ptr()) || unsafe { node.as_ref() }.prev != prev {
+            return false;
+        }
+        prev = cur;
+        cur = unsafe { node.as_ref() }.next;
+    }
+    cur.is_none() && prev == tail
+}
+
+/// The nodes reachable from `head` form a doubly-linked chain of `len` nodes
+/// ending at `tail`.
+#[cfg(kani)]
+#[unstable(feature = "ub_checks", issue = "none")]
+impl<T, A: Allocator> Invariant for LinkedList<T, A> {
+    fn is_safe(&self) -> bool {
+        is_chain(self.head, self.tail, self.len)
+    }
 }
 
 // private methods
 impl<T, A: Allocator> LinkedList<T, A> {
+    /// Returns the position of `node` in the list, if it is linked into it.
+    #[cfg(kani)]
+    fn position(&self, node: NonNull<Node<T>>) -> Option<usize> {
+        let mut cur = self.head;
+        for idx in 0..self.len {
+            let link = cur?;
+            if link == node {
+                return Some(idx);
+            }
+            cur = unsafe { link.as_ref() }.next;
+        }
+        None
+    }
+
+    /// Returns `true` if `prev` and `next` are adjacent in the list, where `None`
+    /// stands for the respective end of the list.
+    #[cfg(kani)]
+    fn is_gap(&self, prev: Option<NonNull<Node<T>>>, next: Option<NonNull<Node<T>>>) -> bool {
+        match prev {
+            None => self.head == next,
+            Some(node) => self.position(node).is_some() && Node::next_of(prev) == next,
+        }
+    }
+
     /// Adds the given node to the front of the list.
     ///
     /// # Safety
     /// `node` must point to a valid node that was boxed and leaked using the list's allocator.
     /// This method takes ownership of the node, so the pointer should not be used again.
     #[inline]
+    #[requires(self.is_safe() && self.len < usize::MAX)]
+    #[cfg_attr(kani, requires(can_dereference(node.as_ptr()) && self.position(node).is_none()))]
+    #[ensures(|_| self.is_safe() && self.head == Some(node) && self.len == old(self.len) + 1)]
+    #[cfg_attr(kani, kani::modifies(self, node.as_ptr(), Node::target(self.head)))]
     unsafe fn push_front_node(&mut self, node: NonNull<Node<T>>) {
         // This method takes care not to create mutable references to whole nodes,
         // to maintain validity of aliasing pointers into `element`.
@@ -311,6 +395,9 @@ impl<T, A: Allocator> LinkedList<T, A> {
 
     /// Removes and returns the node at the front of the list.
     #[inline]
+    #[requires(self.is_safe())]
+    #[ensures(|node| node.as_deref().map(NonNull::from) == old(self.head))]
+    #[cfg_attr(kani, kani::modifies(self, Node::target(Node::next_of(self.head))))]
     fn pop_front_node(&mut self) -> Option<Box<Node<T>, &A>> {
         // This method takes care not to create mutable references to whole nodes,
         // to maintain validity of aliasing pointers into `element`.
@@ -335,6 +422,10 @@ impl<T, A: Allocator> LinkedList<T, A> {
     /// `node` must point to a valid node that was boxed and leaked using the list's allocator.
     /// This method takes ownership of the node, so the pointer should not be used again.
     #[inline]
+    #[requires(self.is_safe() && self.len < usize::MAX)]
+    #[cfg_attr(kani, requires(can_dereference(node.as_ptr()) && self.position(node).is_none()))]
+    #[ensures(|_| self.is_safe() && self.tail == Some(node) && self.len == old(self.len) + 1)]
+    #[cfg_attr(kani, kani::modifies(self, node.as_ptr(), Node::target(self.tail)))]
     unsafe fn push_back_node(&mut self, node: NonNull<Node<T>>) {
         // This method takes care not to create mutable references to whole nodes,
         // to maintain validity of aliasing pointers into `element`.
@@ -356,6 +447,9 @@ impl<T, A: Allocator> LinkedList<T, A> {
 
     /// Removes and returns the node at the back of the list.
     #[inline]
+    #[requires(self.is_safe())]
+    #[ensures(|node| node.as_deref().map(NonNull::from) == old(self.tail))]
+    #[cfg_attr(kani, kani::modifies(self, Node::target(Node::prev_of(self.tail))))]
     fn pop_back_node(&mut self) -> Option<Box<Node<T>, &A>> {
         // This method takes care not to create mutable references to whole nodes,
         // to maintain validity of aliasing pointers into `element`.
@@ -381,6 +475,13 @@ impl<T, A: Allocator> LinkedList<T, A> {
     /// This method takes care not to create mutable references to `element`, to
     /// maintain validity of aliasing pointers.
     #[inline]
+    #[cfg_attr(kani, requires(self.is_safe() && self.position(node).is_some()))]
+    #[cfg_attr(kani, ensures(|_| {
+        self.is_safe() && self.len == old(self.len) - 1 && self.position(node).is_none()
+    }))]
+    #[cfg_attr(kani, kani::modifies(self))]
+    #[cfg_attr(kani, kani::modifies(Node::target(Node::prev_of(Some(node)))))]
+    #[cfg_attr(kani, kani::modifies(Node::target(Node::next_of(Some(node)))))]
     unsafe fn unlink_node(&mut self, mut node: NonNull<Node<T>>)
     /*@
     req (*self).head |-> ?head &*& (*self).tail |-> ?tail &*&
@@ -449,6 +550,15 @@ impl<T, A: Allocator> LinkedList<T, A> {
     ///
     /// Warning: this will not check that the provided node belongs to the two existing lists.
     #[inline]
+    #[cfg_attr(kani, requires(self.is_safe() && self.is_gap(existing_prev, existing_next)))]
+    #[cfg_attr(kani, requires(is_chain(Some(splice_start), Some(splice_end), splice_length)))]
+    #[cfg_attr(kani, requires(
+        self.position(splice_start).is_none() && self.position(splice_end).is_none()
+    ))]
+    #[requires(self.len.checked_add(splice_length).is_some())]
+    #[ensures(|_| self.is_safe() && self.len == old(self.len) + splice_length)]
+    #[cfg_attr(kani, kani::modifies(self, splice_start.as_ptr(), splice_end.as_ptr()))]
+    #[cfg_attr(kani, kani::modifies(Node::target(existing_prev), Node::target(existing_next)))]
     unsafe fn splice_nodes(
         &mut self,
         existing_prev: Option<NonNull<Node<T>>>,
@@ -2370,3 +2480,223 @@ unsafe impl<T: Send, A: Allocator + Send> Send for CursorMut<'_, T, A> {}
 
 #[unstable(feature = "linked_list_cursors", issue = "58533")]
 unsafe impl<T: Sync, A: Allocator + Sync> Sync for CursorMut<'_, T, A> {}
+
+/// The cursor's list is well-formed and `index` is the position of `current`,
+/// or the length of the list for the "ghost" non-element.
+#[cfg(kani)]
+#[unstable(feature = "ub_checks", issue = "none")]
+impl<T, A: Allocator> Invariant for CursorMut<'_, T, A> {
+    fn is_safe(&self) -> bool {
+        self.list.is_safe()
+            && match self.current {
+                None => self.index == self.list.len,
+                Some(node) => self.list.position(node) == Some(self.index),
+            }
+    }
+}
+
+#[cfg(kani)]
+#[unstable(feature = "kani", issue = "none")]
+mod verify {
+    use super::*;
+
+    // Node linking proofs
+
+    const MAX_LEN: usize = 3;
+
+    /// Builds a list of symbolic length at most `MAX_LEN` holding the
+    /// elements `0, 1, ...` shifted by `base`.
+    fn any_list(base: u8) -> LinkedList<u8> {
+        let len = kani::any_where(|&len: &usize| len <= MAX_LEN);
+        let mut list = LinkedList::new();
+        for i in 0..len {
+            list.push_back(base + i as u8);
+        }
+        list
+    }
+
+    fn new_node() -> NonNull<Node<u8>> {
+        NonNull::from(Box::leak(Box::new(Node::new(kani::any()))))
+    }
+
+    /// Returns the node at a symbolic position of a nonempty list.
+    fn any_node(list: &LinkedList<u8>) -> NonNull<Node<u8>> {
+        let idx = kani::any_where(|&idx: &usize| idx < list.len);
+        let mut node = list.head.unwrap();
+        for _ in 0..idx {
+            node = unsafe { node.as_ref() }.next.unwrap();
+        }
+        node
+    }
+
+    #[kani::proof_for_contract(LinkedList::push_front_node)]
+    #[kani::unwind(5)]
+    fn check_push_front_node() {
+        let mut list = any_list(0);
+        unsafe { list.push_front_node(new_node()) };
+    }
+
+    #[kani::proof_for_contract(LinkedList::push_back_node)]
+    #[kani::unwind(5)]
+    fn check_push_back_node() {
+        let mut list = any_list(0);
+        unsafe { list.push_back_node(new_node()) };
+    }
+
+    #[kani::proof_for_contract(LinkedList::pop_front_node)]
+    #[kani::unwind(5)]
+    fn check_pop_front_node() {
+        let mut list = any_list(0);
+        let _ = list.pop_front_node();
+    }
+
+    #[kani::proof_for_contract(LinkedList::pop_back_node)]
+    #[kani::unwind(5)]
+    fn check_pop_back_node() {
+        let mut list = any_list(0);
+        let _ = list.pop_back_node();
+    }
+
+    #[kani::proof_for_contract(LinkedList::unlink_node)]
+    #[kani::unwind(5)]
+    fn check_unlink_node() {
+        let mut list = any_list(0);
+        kani::assume(list.len > 0);
+        let node = any_node(&list);
+        unsafe {
+            list.unlink_node(node);
+            drop(Box::from_raw(node.as_ptr()));
+        }
+    }
+
+    #[kani::proof_for_contract(LinkedList::splice_nodes)]
+    #[kani::unwind(5)]
+    fn check_splice_nodes() {
+        let mut list = any_list(0);
+        let other = any_list(MAX_LEN as u8);
+        let prev = if list.len > 0 && kani::any() { Some(any_node(&list)) } else { None };
+        let next = match prev {
+            None => list.head,
+            Some(node) => unsafe { node.as_ref() }.next,
+        };
+        if let Some((start, end, len)) = other.detach_all_nodes() {
+            unsafe { list.splice_nodes(prev, next, start, end, len) };
+        }
+    }
+
+    // Popping and unlinking keep every remaining link consistent.
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_pop_and_unlink() {
+        let mut list = any_list(0);
+        let len = list.len;
+        let front = list.pop_front_node().map(|node| node.element);
+        let back = list.pop_back_node().map(|node| node.element);
+        assert!(list.is_safe());
+        assert_eq!(front, (len > 0).then_some(0));
+        assert_eq!(back, (len > 1).then(|| len as u8 - 1));
+        if list.len > 0 {
+            let node = any_node(&list);
+            unsafe {
+                list.unlink_node(node);
+                drop(Box::from_raw(node.as_ptr()));
+            }
+            assert!(list.is_safe());
+        }
+    }
+
+    /// Returns the node at position `idx`, or `None` past the end of the list.
+    fn node_at(list: &LinkedList<u8>, idx: usize) -> Option<NonNull<Node<u8>>> {
+        let mut node = list.head;
+        for _ in 0..idx {
+            node = Node::next_of(node);
+        }
+        node
+    }
+
+    // Splitting before the node at `at` leaves a well-formed list on both
+    // sides, with the first `at` elements in the returned part.
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_split_off_before_node() {
+        let mut list = any_list(0);
+        let len = list.len;
+        let at = kani::any_where(|&at: &usize| at <= len);
+        let first = unsafe { list.split_off_before_node(node_at(&list, at), at) };
+        assert!(first.is_safe() && list.is_safe());
+        assert!(first.iter().copied().eq(0..at as u8));
+        assert!(list.iter().copied().eq(at as u8..len as u8));
+    }
+
+    // Splitting after the node at `at - 1` leaves a well-formed list on both
+    // sides, with the elements from `at` on in the returned part.
+    #[kani::proof]
+    #[kani::unwind(5)]
+    fn check_split_off_after_node() {
+        let mut list = any_list(0);
+        let len = list.len;
+        let at = kani::any_where(|&at: &usize| at <= len);
+        let split_node = if at == 0 { None } else { node_at(&list, at - 1) };
+        let second = unsafe { list.split_off_after_node(split_node, at) };
+        assert!(list.is_safe() && second.is_safe());
+        assert!(list.iter().copied().eq(0..at as u8));
+        assert!(second.iter().copied().eq(at as u8..len as u8));
+    }
+
+    /// Places a cursor at a symbolic position of `list`, including the
+    /// "ghost" non-element.
+    fn any_cursor(list: &mut LinkedList<u8>) -> CursorMut<'_, u8> {
+        let moves = kani::any_where(|&moves: &usize| moves <= list.len);
+        let mut cursor = list.cursor_front_mut();
+        for _ in 0..moves {
+            cursor.move_next();
+        }
+        cursor
+    }
+
+    // Splicing after the cursor leaves its position alone unless it is at the
+    // ghost, and keeps the spliced elements in order right after it.
+    #[kani::proof]
+    #[kani::unwind(8)]
+    fn check_cursor_splice_after() {
+        let mut list = any_list(0);
+        let other = any_list(MAX_LEN as u8);
+        let (len, other_len) = (list.len, other.len);
+        let mut cursor = any_cursor(&mut list);
+        let index = cursor.index;
+        let at_ghost = cursor.current.is_none();
+
+        cursor.splice_after(other);
+
+        assert!(cursor.is_safe());
+        assert_eq!(cursor.index, if at_ghost { len + other_len } else { index });
+        let start = if at_ghost { 0 } else { index + 1 };
+        let mut iter = list.iter().skip(start);
+        for i in 0..other_len {
+            assert_eq!(iter.next(), Some(&(MAX_LEN as u8 + i as u8)));
+        }
+        assert_eq!(list.len, len + other_len);
+    }
+
+    // Splicing before the cursor shifts its index by the spliced length and
+    // keeps the spliced elements in order right before it.
+    #[kani::proof]
+    #[kani::unwind(8)]
+    fn check_cursor_splice_before() {
+        let mut list = any_list(0);
+        let other = any_list(MAX_LEN as u8);
+        let (len, other_len) = (list.len, other.len);
+        let mut cursor = any_cursor(&mut list);
+        let index = cursor.index;
+
+        cursor.splice_before(other);
+
+        assert!(cursor.is_safe());
+        assert_eq!(cursor.index, index + other_len);
+        let mut iter = list.iter().skip(index);
+        for i in 0..other_len {
+            assert_eq!(iter.next(), Some(&(MAX_LEN as u8 + i as u8)));
+        }
+        assert_eq!(list.len, len + other_len);
+    }
+}